@@ -1,14 +1,16 @@
 use std::{
     fs,
-    io::{prelude::*, Read},
+    io::prelude::*,
     path::{Path, PathBuf},
 };
 
 use anyhow::Context;
 use clap::Parser;
-use roxmltree::{Document, Node};
-use wsdl_parser::{generator::generate, parser::definitions::Definitions};
-use xsd_parser::{generator::builder::GeneratorBuilder, parser::schema::parse_schema};
+use wsdl_parser::{
+    generator::{OperationStyle, Options},
+    project::{generate_schemas, Project, SourceFile},
+};
+use xsd_parser::generator::{naming::DefaultNamingStrategy, order::ItemOrder};
 
 #[derive(Parser)]
 #[clap(name = env!("CARGO_PKG_NAME"))]
@@ -22,66 +24,122 @@ struct Opt {
     /// Output file
     #[clap(long, short)]
     output: Option<PathBuf>,
+
+    /// Order of generated items: `document`, `alphabetical` or `dependency`
+    #[clap(long, default_value_t = ItemOrder::Document)]
+    order: ItemOrder,
+
+    /// Generate operations as `functions`, taking the transport, or as `clients`, a struct per
+    /// port type
+    #[clap(long, default_value_t = OperationStyle::Functions)]
+    operations: OperationStyle,
+
+    /// Generate a server trait per port type, dispatching SOAP requests to its operations
+    #[clap(long)]
+    servers: bool,
+
+    /// Also generate methods of document/literal wrapped operations taking the children of
+    /// their request element
+    #[clap(long)]
+    unwrapped: bool,
 }
 
 fn main() -> anyhow::Result<()> {
     let opt: Opt = Opt::parse();
 
+    let options = Options {
+        order: opt.order,
+        operations: opt.operations,
+        servers: opt.servers,
+        unwrapped: opt.unwrapped,
+    };
     let input_path = opt.input.unwrap_or_else(|| PathBuf::from("input/wsdl"));
     let md = fs::metadata(&input_path).unwrap();
+    let mut schemas = vec![];
     if md.is_dir() {
         let output_path = opt.output.unwrap_or_else(|| PathBuf::from("output/wsdl-rs"));
-        process_dir(&input_path, &output_path)?;
+        process_dir(&input_path, &output_path, &options, &mut schemas)?;
+        write_schemas(&schemas, Some(&output_path), &options)?;
     } else {
-        process_single_file(&input_path, opt.output.as_deref())?;
+        process_single_file(&input_path, opt.output.as_deref(), &options, &mut schemas)?;
+        let output_dir = opt.output.as_deref().map(|path| match path.parent() {
+            Some(dir) => dir.to_path_buf(),
+            None => PathBuf::new(),
+        });
+        write_schemas(&schemas, output_dir.as_deref(), &options)?;
     }
 
     Ok(())
 }
 
 //TODO: Add a common mechanism for working with files
-fn process_dir(input_path: &Path, output_path: &Path) -> anyhow::Result<()> {
+fn process_dir(
+    input_path: &Path,
+    output_path: &Path,
+    options: &Options,
+    schemas: &mut Vec<SourceFile>,
+) -> anyhow::Result<()> {
     if !output_path.exists() {
         fs::create_dir_all(output_path)?;
     }
     for entry in fs::read_dir(input_path)? {
         let path = entry?.path();
         if path.is_dir() {
-            process_dir(&path, &output_path.join(path.file_name().unwrap()))?;
+            process_dir(&path, &output_path.join(path.file_name().unwrap()), options, schemas)?;
         } else {
             let output_file_path = PathBuf::from(path.file_name().unwrap()).with_extension("rs");
             let output_file_path = output_path.join(output_file_path);
-            process_single_file(&path, Some(&output_file_path))?;
+            process_single_file(&path, Some(&output_file_path), options, schemas)?;
         }
     }
     Ok(())
 }
 
-fn process_single_file(input_path: &Path, output_path: Option<&Path>) -> anyhow::Result<()> {
-    let text = load_file(input_path)?;
-    let doc = Document::parse(text.as_str()).context("Failed to parse input document")?;
-    let definitions = Definitions::new(&doc.root_element());
-    let gen = GeneratorBuilder::default().build();
-    let schemas =
-        definitions.types().iter().flat_map(|t| t.schemas()).collect::<Vec<Node<'_, '_>>>();
-    let mut code =
-        schemas.iter().map(|f| gen.generate_rs_file(&parse_schema(f))).collect::<Vec<String>>();
-
-    code.push(generate(&definitions));
-    let code = code.join("");
+// Generates the WSDL document `input_path` and adds the schemas it imports to `schemas`, which
+// are generated once for all documents.
+fn process_single_file(
+    input_path: &Path,
+    output_path: Option<&Path>,
+    options: &Options,
+    schemas: &mut Vec<SourceFile>,
+) -> anyhow::Result<()> {
+    let project = Project::load(input_path).context("Failed to load input document")?;
+    let code = project.generate(&DefaultNamingStrategy, options)?;
     if let Some(output_filename) = output_path {
         write_to_file(output_filename, &code).context("Error writing file")?;
     } else {
         println!("{}", code);
     }
+
+    for schema in project.schemas {
+        let path = fs::canonicalize(&schema.path)?;
+        if schemas.iter().all(|s| fs::canonicalize(&s.path).ok().as_ref() != Some(&path)) {
+            schemas.push(schema);
+        }
+    }
     Ok(())
 }
 
-fn load_file(path: &Path) -> std::io::Result<String> {
-    let mut file = fs::File::open(path)?;
-    let mut text = String::new();
-    file.read_to_string(&mut text)?;
-    Ok(text)
+// Writes the modules of the imported schemas to `output_dir`, or prints them as inline modules.
+fn write_schemas(
+    schemas: &[SourceFile],
+    output_dir: Option<&Path>,
+    options: &Options,
+) -> anyhow::Result<()> {
+    for module in generate_schemas(schemas, &DefaultNamingStrategy, options)? {
+        match output_dir {
+            Some(dir) => {
+                let path = dir.join(module.file_name());
+                write_to_file(&path, &module.code).context("Error writing file")?;
+            }
+            None => println!(
+                "pub mod {} {{
+{}}}",
+                module.name, module.code
+            ),
+        }
+    }
+    Ok(())
 }
 
 fn write_to_file(path: &Path, text: &str) -> std::io::Result<()> {
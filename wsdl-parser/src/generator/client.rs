@@ -0,0 +1,172 @@
+use xsd_parser::generator::{default::default_format_comment, naming::NamingStrategy};
+
+use crate::{
+    generator::{
+        fault::{error_type, generate_fault_enum},
+        function::{arguments, return_type, Function},
+        rpc::{generate_wrappers, map_response, request_value},
+        wrapped::{generate_unwrapped_method, SequenceElements, Wrapped},
+    },
+    parser::{binding::Binding, definitions::Definitions, port_type::PortType, soap::SoapVersion},
+};
+
+/// Generates the client struct of a port type, holding a transport and an endpoint, with a
+/// method per operation the client initiates, followed by the wrapper structs and fault enums
+/// of these operations.
+/// Operations are sent as the first binding of the port type describes. With `elements`,
+/// operations in the wrapped convention also have a method taking the children of their
+/// request element.
+pub fn generate_client(
+    port_type: &PortType,
+    definitions: &Definitions,
+    naming: &dyn NamingStrategy,
+    elements: Option<&SequenceElements>,
+) -> String {
+    let binding = definitions.bindings_of(port_type.name()).into_iter().next();
+    let name = client_name(port_type.name(), naming);
+    let target_ns = definitions.target_namespace();
+    let functions = port_type
+        .operations()
+        .iter()
+        .filter(|op| !op.operation_type().initiated_by_service())
+        .map(|op| Function::new(op, definitions, binding))
+        .collect::<Vec<_>>();
+    let methods = functions
+        .iter()
+        .map(|func| {
+            let unwrapped = elements
+                .and_then(|elements| Wrapped::new(func, elements, definitions, naming))
+                .map(|wrapped| generate_unwrapped_method(func, &wrapped, definitions, naming))
+                .unwrap_or_default();
+            generate_method(func, binding, definitions, naming) + &unwrapped
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    let types = functions
+        .iter()
+        .map(|func| {
+            generate_wrappers(func, definitions, naming)
+                + &generate_fault_enum(func, target_ns, naming)
+        })
+        .collect::<String>();
+
+    format!(
+        r#"
+/// Client of the port type `{port_type}`.
+pub struct {name}<T: transport::Transport> {{
+    transport: T,
+    endpoint: String,
+}}
+
+impl<T: transport::Transport> {name}<T> {{
+    pub fn new(transport: T, endpoint: &str) -> Self {{
+        Self {{ transport, endpoint: endpoint.to_string() }}
+    }}
+{default_endpoint}
+    pub fn transport(&self) -> &T {{
+        &self.transport
+    }}
+
+    pub fn endpoint(&self) -> &str {{
+        &self.endpoint
+    }}
+
+{methods}}}
+{types}"#,
+        port_type = port_type.name(),
+        name = name,
+        default_endpoint =
+            binding.map(|b| generate_default_endpoint(b, definitions, naming)).unwrap_or_default(),
+        methods = methods,
+        types = types,
+    )
+}
+
+pub fn client_name(port_type: &str, naming: &dyn NamingStrategy) -> String {
+    format!("{}Client", naming.type_name(port_type))
+}
+
+// Constructor using the address of the first port of a service bound by `binding`.
+fn generate_default_endpoint(
+    binding: &Binding,
+    definitions: &Definitions,
+    naming: &dyn NamingStrategy,
+) -> String {
+    let port = definitions.services().iter().find_map(|service| {
+        service
+            .ports()
+            .iter()
+            .find(|port| {
+                port.address().is_some()
+                    && port.binding().rsplit(':').next() == Some(binding.name())
+            })
+            .map(|port| (service, port))
+    });
+    match port {
+        Some((service, port)) => format!(
+            r#"
+    /// Client of the port `{port}` of the service `{service}`.
+    pub fn with_default_endpoint(transport: T) -> Self {{
+        Self::new(transport, services::{service_mod}::{port_mod}::ADDRESS)
+    }}
+"#,
+            port = port.name(),
+            service = service.name(),
+            service_mod = naming.module_name(service.name()),
+            port_mod = naming.module_name(port.name()),
+        ),
+        None => String::new(),
+    }
+}
+
+/// Statement binding `operation` to the `transport::SoapOperation` of `func`, with the SOAP
+/// version and `soapAction` of `binding`, indented by `indent`.
+pub fn soap_operation(func: &Function<'_>, binding: Option<&Binding>, indent: usize) -> String {
+    let version = match binding.and_then(|b| b.soap_binding()).map(|b| b.version()) {
+        Some(SoapVersion::Soap12) => "Soap12",
+        _ => "Soap11",
+    };
+    let action = binding
+        .and_then(|b| b.operation(func.name))
+        .and_then(|op| op.soap_operation())
+        .and_then(|op| op.soap_action());
+    format!(
+        r#"{indent}let operation = transport::SoapOperation {{
+{indent}    version: transport::SoapVersion::{version},
+{indent}    action: {action},
+{indent}}};
+"#,
+        indent = " ".repeat(indent),
+        version = version,
+        action = match action {
+            Some(action) => format!("Some(\"{}\")", action),
+            None => "None".to_string(),
+        },
+    )
+}
+
+fn generate_method(
+    func: &Function<'_>,
+    binding: Option<&Binding>,
+    definitions: &Definitions,
+    naming: &dyn NamingStrategy,
+) -> String {
+    format!(
+        r#"{comment}    pub async fn {name}(
+        &self,
+        {arguments}
+    ) -> Result<{return_type}, {error_type}> {{
+{operation}        transport::{call}(&self.transport, &self.endpoint, &operation, {request}).await{map}
+    }}
+"#,
+        comment = default_format_comment(func.documentation, 80, 4),
+        name = naming.function_name(func.name),
+        arguments = arguments(func, definitions, naming, "&").join(",\n        "),
+        return_type = return_type(func, definitions, naming),
+        error_type = error_type(func, naming),
+        operation = soap_operation(func, binding, 8),
+        call = if func.is_one_way() { "send" } else { "call" },
+        request = request_value(func, naming),
+        map = map_response(func, naming),
+    )
+}
@@ -0,0 +1,112 @@
+use roxmltree::Namespace;
+use xsd_parser::generator::naming::NamingStrategy;
+
+use crate::generator::function::Function;
+
+/// Name of the enum of the faults of the operation `operation`.
+pub fn fault_enum_name(operation: &str, naming: &dyn NamingStrategy) -> String {
+    format!("{}Fault", naming.type_name(operation))
+}
+
+/// Error type of the operation: `transport::Error` of its fault enum, or of the generic fault
+/// when it declares no fault.
+pub fn error_type(func: &Function<'_>, naming: &dyn NamingStrategy) -> String {
+    if func.faults.is_empty() {
+        "transport::Error".to_string()
+    } else {
+        format!("transport::Error<{}>", fault_enum_name(func.name, naming))
+    }
+}
+
+/// Generates the enum of the faults the operation declares, decoded from the detail of a
+/// `soap:Fault` by the name of its element and encoded into it by services. Empty when the
+/// operation declares no fault.
+pub fn generate_fault_enum(
+    func: &Function<'_>,
+    target_ns: Option<&Namespace>,
+    naming: &dyn NamingStrategy,
+) -> String {
+    if func.faults.is_empty() {
+        return String::new();
+    }
+    let name = fault_enum_name(func.name, naming);
+    let variant = |fault: &str| naming.type_name(fault);
+
+    format!(
+        r#"
+/// Faults of the operation `{operation}`.
+#[derive(Debug, PartialEq)]
+pub enum {name} {{
+{variants}    /// A fault the operation does not declare.
+    Other(transport::Fault),
+}}
+
+impl transport::OperationFault for {name} {{
+    fn from_fault(fault: transport::Fault) -> Self {{
+{decoders}        {name}::Other(fault)
+    }}
+
+    fn into_fault(self) -> transport::Fault {{
+        match self {{
+{encoders}            {name}::Other(fault) => fault,
+        }}
+    }}
+}}
+
+impl std::fmt::Display for {name} {{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+        match self {{
+{displays}            {name}::Other(fault) => write!(f, "{{}}", fault),
+        }}
+    }}
+}}
+"#,
+        operation = func.name,
+        name = name,
+        variants = func
+            .faults
+            .iter()
+            .map(|fault| format!(
+                "    {}({}),\n",
+                variant(fault.name),
+                naming.type_path(fault.element, target_ns)
+            ))
+            .collect::<String>(),
+        decoders = func
+            .faults
+            .iter()
+            .map(|fault| format!(
+                "        if let Some(detail) = fault.detail_as({namespace}, \"{element}\") {{
+            return {name}::{variant}(detail);
+        }}\n",
+                namespace = match fault.namespace {
+                    Some(ns) => format!("Some(\"{}\")", ns),
+                    None => "None".to_string(),
+                },
+                element = fault.element.rsplit(':').next().unwrap_or(fault.element),
+                name = name,
+                variant = variant(fault.name),
+            ))
+            .collect::<String>(),
+        encoders = func
+            .faults
+            .iter()
+            .map(|fault| format!(
+                "            {name}::{variant}(detail) => transport::Fault::with_detail(\"{fault}\", &detail),\n",
+                name = name,
+                variant = variant(fault.name),
+                fault = fault.name,
+            ))
+            .collect::<String>(),
+        displays = func
+            .faults
+            .iter()
+            .map(|fault| format!(
+                "            {name}::{variant}(detail) => write!(f, \"SOAP fault {fault}: {{:?}}\", detail),\n",
+                name = name,
+                variant = variant(fault.name),
+                fault = fault.name,
+            ))
+            .collect::<String>(),
+    )
+}
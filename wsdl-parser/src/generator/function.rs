@@ -1,20 +1,26 @@
+use xsd_parser::generator::{match_built_in_type, naming::NamingStrategy};
+
 use crate::parser::{
+    binding::{Binding, OperationBinding, Param as BindingParam},
     definitions::Definitions,
     message::{Message, Part},
     port_type::{
-        Operation,
-        OperationType::{OneWay, RequestResponse},
+        Fault, Operation,
+        OperationType::{Notification, OneWay, RequestResponse, SolicitResponse},
     },
+    soap::{SoapBody, Style},
 };
 
 const GENERIC_TRANSPORT: Param<'static> = Param { name: "T", typename: "transport::Transport" };
-const ARGUMENT_TRANSPORT: Param<'static> = Param { name: "transport", typename: "T" };
+pub(crate) const ARGUMENT_TRANSPORT: Param<'static> = Param { name: "transport", typename: "T" };
 
 #[derive(Debug)]
 pub struct Function<'a> {
     pub generic_params: Vec<Param<'a>>,
     pub arguments: Vec<Param<'a>>,
     pub return_type: &'a str,
+    pub faults: Vec<FaultParam<'a>>,
+    pub rpc: Option<Rpc<'a>>,
     pub documentation: Option<&'a str>,
     pub name: &'a str,
 }
@@ -37,43 +43,253 @@ impl<'a> Param<'a> {
     }
 }
 
+/// A `wsdl:fault` whose message has a part of an element, which is the detail of the fault.
+#[derive(Debug, Clone)]
+pub struct FaultParam<'a> {
+    pub name: &'a str,
+    pub element: &'a str,
+    pub namespace: Option<&'a str>,
+}
+
+/// The messages of an operation in RPC style, whose parts are the children of a wrapper
+/// element named after the operation, with `Response` appended for the answer.
+#[derive(Debug, Clone)]
+pub struct Rpc<'a> {
+    pub input: Wrapper<'a>,
+    /// `None` for operations without answer.
+    pub output: Option<Wrapper<'a>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Wrapper<'a> {
+    /// The `namespace` of the `soap:body` of the message.
+    pub namespace: Option<&'a str>,
+    pub parts: Vec<Param<'a>>,
+}
+
 impl<'a> Function<'a> {
-    pub fn new(operation: &'a Operation<'_>, definitions: &'a Definitions<'_>) -> Self {
+    /// The operation as the binding `binding`, if any, describes it. Parts of messages exclude
+    /// the ones the binding sends in headers. Arguments are in the order of the
+    /// `parameterOrder` of the operation, the parts of RPC wrappers in the order of their
+    /// message.
+    pub fn new(
+        operation: &'a Operation<'_>,
+        definitions: &'a Definitions<'_>,
+        binding: Option<&'a Binding<'a>>,
+    ) -> Self {
         let input_message;
         let mut output_message = None;
+        let mut faults = vec![];
+        // Whether the first message is the input of the binding, the service sending the
+        // output first otherwise.
+        let mut input_first = true;
         match operation.operation_type() {
-            RequestResponse { input, output, .. } => {
+            RequestResponse { input, output, faults: fault_params } => {
                 input_message = definitions.get_message_by_param(input);
                 output_message = definitions.get_message_by_param(output);
+                faults = get_faults(fault_params, definitions);
             }
             OneWay { input } => input_message = definitions.get_message_by_param(input),
-            _ => {
-                unreachable!("No present in ONVIF");
+            // The service sends the first message, which the application handles and answers.
+            SolicitResponse { output, input, faults: fault_params } => {
+                input_message = definitions.get_message_by_param(output);
+                output_message = definitions.get_message_by_param(input);
+                faults = get_faults(fault_params, definitions);
+                input_first = false;
             }
+            Notification { output } => {
+                input_message = definitions.get_message_by_param(output);
+                input_first = false;
+            }
+        };
+
+        let operation_binding = binding.and_then(|binding| {
+            let operation = binding.operation(operation.name())?;
+            Some(OperationBinding { binding, operation })
+        });
+        let (input_body, output_body) = match operation_binding {
+            Some(b) if input_first => (b.operation.input(), b.operation.output()),
+            Some(b) => (b.operation.output(), b.operation.input()),
+            None => (None, None),
         };
+        let order = operation.parameter_order();
+        let input_parts = body_parts(input_message, input_body.and_then(|p| p.body()));
+        let output_parts = body_parts(output_message, output_body.and_then(|p| p.body()));
+
+        if operation_binding.map(|b| b.style()) == Some(Style::Rpc) {
+            let namespace = |param: Option<&'a BindingParam<'a>>| {
+                param.and_then(|p| p.body()).and_then(|body| body.namespace())
+            };
+            let arguments = in_order(&input_parts, order).into_iter().map(Param::new);
+            let return_type = match output_parts.as_slice() {
+                [part] => Param::new(part).typename,
+                _ => "",
+            };
+            return Function {
+                generic_params: vec![GENERIC_TRANSPORT],
+                arguments: std::iter::once(ARGUMENT_TRANSPORT).chain(arguments).collect(),
+                return_type,
+                faults,
+                rpc: Some(Rpc {
+                    input: Wrapper {
+                        namespace: namespace(input_body),
+                        parts: input_parts.iter().map(|part| Param::new(part)).collect(),
+                    },
+                    output: output_message.map(|_| Wrapper {
+                        namespace: namespace(output_body),
+                        parts: output_parts.iter().map(|part| Param::new(part)).collect(),
+                    }),
+                }),
+                documentation: operation.documentation(),
+                name: operation.name(),
+            };
+        }
 
         Function {
             generic_params: vec![GENERIC_TRANSPORT],
-            arguments: get_input_params(input_message),
-            return_type: output_message.and_then(|m| m.parts()[0].element()).unwrap_or(""),
+            arguments: get_input_params(input_message.map(|_| in_order(&input_parts, order))),
+            return_type: output_parts.first().and_then(|part| part.element()).unwrap_or(""),
+            faults,
+            rpc: None,
             documentation: operation.documentation(),
             name: operation.name(),
         }
     }
 }
 
-fn get_input_params<'a>(input: Option<&'a Message<'_>>) -> Vec<Param<'a>> {
+impl Function<'_> {
+    /// Whether the operation has no output, its servers acknowledging it with an empty
+    /// response.
+    pub fn is_one_way(&self) -> bool {
+        match &self.rpc {
+            Some(rpc) => rpc.output.is_none(),
+            None => self.return_type.is_empty(),
+        }
+    }
+}
+
+// Parts of `message` in `body`, all of them without binding.
+fn body_parts<'a>(
+    message: Option<&'a Message<'_>>,
+    body: Option<&SoapBody<'_>>,
+) -> Vec<&'a Part<'a>> {
+    let names = body.and_then(|body| body.parts());
+    message
+        .map(|m| m.parts())
+        .unwrap_or_default()
+        .iter()
+        .filter(|part| names.as_ref().is_none_or(|names| names.contains(&part.name())))
+        .collect()
+}
+
+// `parts` in the order of `order`. Parts `order` does not name come last.
+fn in_order<'a>(parts: &[&'a Part<'a>], order: Option<&str>) -> Vec<&'a Part<'a>> {
+    let mut parts = parts.to_vec();
+    if let Some(order) = order {
+        let order = order.split_whitespace().collect::<Vec<_>>();
+        parts.sort_by_key(|part| {
+            order.iter().position(|name| *name == part.name()).unwrap_or(order.len())
+        });
+    }
+    parts
+}
+
+fn get_input_params<'a>(input: Option<Vec<&'a Part<'a>>>) -> Vec<Param<'a>> {
     let mut result = vec![ARGUMENT_TRANSPORT];
     if input.is_none() {
         return result;
     }
     let input = input.unwrap();
 
-    if input.parts().len() == 1 {
+    if input.len() == 1 {
         // ONVIF case
-        result.push(Param { name: "request", typename: input.parts()[0].element().unwrap() })
+        result.push(Param { name: "request", typename: input[0].element().unwrap() })
     } else {
-        result.append(&mut input.parts().iter().map(Param::new).collect());
+        result.append(&mut input.into_iter().map(Param::new).collect());
     };
     result
 }
+
+// Faults whose message is unknown or has no part of an element are left to the generic fault.
+fn get_faults<'a>(
+    faults: &'a [Fault<'_>],
+    definitions: &'a Definitions<'_>,
+) -> Vec<FaultParam<'a>> {
+    faults
+        .iter()
+        .filter_map(|fault| {
+            let part = definitions.get_message_by_fault(fault)?.parts().first()?;
+            Some(FaultParam {
+                name: fault.name(),
+                element: part.element()?,
+                namespace: part.element_namespace(),
+            })
+        })
+        .collect()
+}
+
+const XSD_NS: &str = "http://www.w3.org/2001/XMLSchema";
+
+/// Path to the Rust type of the element or type `typename`, built-in types of XSD included.
+pub fn type_path(typename: &str, definitions: &Definitions, naming: &dyn NamingStrategy) -> String {
+    let xsd_ns = definitions.node().namespaces().find(|ns| ns.uri() == XSD_NS).cloned();
+    match match_built_in_type(typename, &xsd_ns) {
+        Some(built_in) => built_in.to_string(),
+        None => naming.type_path(typename, definitions.target_namespace()),
+    }
+}
+
+/// Rust type the function returns: its return type, the struct of the output of RPC
+/// operations with several parts, or `()`.
+pub fn return_type(
+    func: &Function<'_>,
+    definitions: &Definitions,
+    naming: &dyn NamingStrategy,
+) -> String {
+    match (func.return_type, &func.rpc) {
+        ("", Some(Rpc { output: Some(output), .. })) if !output.parts.is_empty() => {
+            output_struct_name(func.name, naming)
+        }
+        ("", _) => "()".to_string(),
+        (typename, _) => type_path(typename, definitions, naming),
+    }
+}
+
+/// Arguments of the function, but the transport. Arguments of RPC operations are passed by
+/// value, to be moved into the wrapper element, the others by `reference`, `&` or nothing.
+pub fn arguments(
+    func: &Function<'_>,
+    definitions: &Definitions,
+    naming: &dyn NamingStrategy,
+    reference: &str,
+) -> Vec<String> {
+    let reference = if func.rpc.is_some() { "" } else { reference };
+    func.arguments
+        .iter()
+        .filter(|p| p.name != ARGUMENT_TRANSPORT.name)
+        .map(|p| {
+            format!(
+                "{}: {}{}",
+                argument_name(func, p.name, naming),
+                reference,
+                type_path(p.typename, definitions, naming)
+            )
+        })
+        .collect()
+}
+
+// Names of parts are field names in RPC operations, kept as they are otherwise.
+fn argument_name(func: &Function<'_>, name: &str, naming: &dyn NamingStrategy) -> String {
+    match func.rpc {
+        Some(_) => naming.field_name(name),
+        None => name.to_string(),
+    }
+}
+
+pub fn input_struct_name(operation: &str, naming: &dyn NamingStrategy) -> String {
+    format!("{}Input", naming.type_name(operation))
+}
+
+pub fn output_struct_name(operation: &str, naming: &dyn NamingStrategy) -> String {
+    format!("{}Output", naming.type_name(operation))
+}
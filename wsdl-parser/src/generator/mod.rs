@@ -1,190 +1,188 @@
-use std::borrow::Cow;
+use std::{fmt, str::FromStr};
+
+use xsd_parser::generator::{
+    default::default_format_comment,
+    naming::{DefaultNamingStrategy, NamingStrategy},
+    order::ItemOrder,
+};
+
+use crate::{
+    generator::{
+        client::{generate_client, soap_operation},
+        fault::{error_type, generate_fault_enum},
+        function::{arguments, return_type, Function, ARGUMENT_TRANSPORT},
+        rpc::{generate_wrappers, map_response, request_value},
+        server::{generate_handler, generate_server},
+        service::generate_services,
+        wrapped::{generate_unwrapped_function, SequenceElements, Wrapped},
+    },
+    parser::{binding::Binding, definitions::Definitions},
+};
+
+pub mod client;
+pub mod fault;
+pub mod function;
+pub mod rpc;
+pub mod server;
+pub mod service;
+pub mod wrapped;
+
+/// How the operations of port types are generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OperationStyle {
+    /// A client struct per port type, with a method per operation.
+    Clients,
+    /// A function per operation, taking the transport as argument.
+    #[default]
+    Functions,
+}
 
-use inflector::cases::{pascalcase::to_pascal_case, snakecase::to_snake_case};
-use roxmltree::Namespace;
+impl FromStr for OperationStyle {
+    type Err = String;
 
-use crate::{generator::function::Function, parser::definitions::Definitions};
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "clients" => Ok(OperationStyle::Clients),
+            "functions" => Ok(OperationStyle::Functions),
+            _ => Err(format!("unknown operation style `{}`, expected `clients` or `functions`", s)),
+        }
+    }
+}
 
-pub mod function;
+impl fmt::Display for OperationStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            OperationStyle::Clients => "clients",
+            OperationStyle::Functions => "functions",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    pub order: ItemOrder,
+    pub operations: OperationStyle,
+    /// Generate a server trait per port type.
+    pub servers: bool,
+    /// Also generate a method per operation in the document/literal wrapped convention, taking
+    /// the children of its request element and returning the single child of its response.
+    pub unwrapped: bool,
+}
 
 pub fn generate(definitions: &Definitions) -> String {
-    let mut res = vec![];
+    generate_with_naming(definitions, &DefaultNamingStrategy)
+}
+
+pub fn generate_with_naming(definitions: &Definitions, naming: &dyn NamingStrategy) -> String {
+    generate_with(definitions, naming, &Options::default())
+}
 
-    for port_type in definitions.port_types().values() {
-        for op in port_type.operations() {
-            let func = Function::new(op, definitions);
-            res.push(generate_function(&func, definitions.target_namespace()));
+/// Generates the clients or functions of the operations of all port types, then the handlers
+/// of the operations services initiate, and the servers when requested, followed by the
+/// `services` module, in the order of the options.
+pub fn generate_with(
+    definitions: &Definitions,
+    naming: &dyn NamingStrategy,
+    options: &Options,
+) -> String {
+    let mut port_types = definitions.port_types_in_order();
+    if options.order == ItemOrder::Alphabetical {
+        port_types.sort_by_key(|pt| naming.type_name(pt.name()));
+    }
+    let elements = options.unwrapped.then(|| SequenceElements::new(definitions, naming));
+    let mut code = match options.operations {
+        OperationStyle::Clients => port_types
+            .iter()
+            .map(|pt| generate_client(pt, definitions, naming, elements.as_ref()))
+            .collect::<Vec<String>>(),
+        OperationStyle::Functions => {
+            let mut functions = port_types
+                .iter()
+                .flat_map(|port_type| {
+                    let binding = definitions.bindings_of(port_type.name()).into_iter().next();
+                    port_type
+                        .operations()
+                        .iter()
+                        .filter(|op| !op.operation_type().initiated_by_service())
+                        .map(move |op| (Function::new(op, definitions, binding), binding))
+                })
+                .collect::<Vec<_>>();
+            if options.order == ItemOrder::Alphabetical {
+                functions.sort_by_key(|(func, _)| naming.function_name(func.name));
+            }
+            functions
+                .iter()
+                .map(|(func, binding)| {
+                    let unwrapped = elements
+                        .as_ref()
+                        .and_then(|elements| Wrapped::new(func, elements, definitions, naming))
+                        .map(|wrapped| {
+                            generate_unwrapped_function(func, &wrapped, definitions, naming)
+                        })
+                        .unwrap_or_default();
+                    generate_function(func, *binding, definitions, naming) + &unwrapped
+                })
+                .collect::<Vec<String>>()
         }
+    };
+    code.extend(port_types.iter().map(|pt| generate_handler(pt, definitions, naming)));
+    if options.servers {
+        code.extend(port_types.iter().map(|pt| generate_server(pt, definitions, naming)));
     }
-    res.join("")
+    code.join("") + &generate_services(definitions, naming, options.order)
 }
 
-const REQUEST_FUNC_BODY: &str = "transport::request(transport, request).await";
-
-fn generate_function(func: &Function<'_>, target_ns: Option<&Namespace>) -> String {
-    let ftype = |t| default_format_type(t, target_ns);
+// Operations are sent as `binding` describes them, to the endpoint the transport knows. Without
+// binding, they are sent with SOAP 1.2 and no action.
+fn generate_function(
+    func: &Function<'_>,
+    binding: Option<&Binding>,
+    definitions: &Definitions,
+    naming: &dyn NamingStrategy,
+) -> String {
+    let ftype = |t| naming.type_path(t, definitions.target_namespace());
+    let body = match (binding, func.is_one_way()) {
+        (Some(_), one_way) => format!(
+            "{}    transport::{}(transport, \"\", &operation, {})",
+            soap_operation(func, binding, 4),
+            if one_way { "send" } else { "call" },
+            request_value(func, naming)
+        ),
+        (None, true) => {
+            format!("    transport::send_request(transport, {})", request_value(func, naming))
+        }
+        (None, false) => {
+            format!("    transport::request(transport, {})", request_value(func, naming))
+        }
+    };
     format!(
         r#"
 {comment}pub async fn {name}<{generics}>(
     {arguments}
-) -> Result<{return_type}, transport::Error> {{
-    {body}
+) -> Result<{return_type}, {error_type}> {{
+{body}.await{map}
 }}
-"#,
+{types}"#,
         comment = default_format_comment(func.documentation, 80, 0),
-        name = default_format_name(func.name),
+        name = naming.function_name(func.name),
         generics = func
             .generic_params
             .iter()
             .map(|p| format!("{}: {}", p.name, ftype(p.typename)))
             .collect::<Vec<String>>()
             .join(", "),
-        arguments = func
-            .arguments
-            .iter()
-            .map(|p| format!("{}: &{}", p.name, ftype(p.typename)))
-            .collect::<Vec<String>>()
-            .join(",\n    "),
-        return_type = ftype(func.return_type),
-        body = REQUEST_FUNC_BODY
+        arguments = std::iter::once(format!(
+            "{}: &{}",
+            ARGUMENT_TRANSPORT.name, ARGUMENT_TRANSPORT.typename
+        ))
+        .chain(arguments(func, definitions, naming, "&"))
+        .collect::<Vec<String>>()
+        .join(",\n    "),
+        return_type = return_type(func, definitions, naming),
+        error_type = error_type(func, naming),
+        body = body,
+        map = map_response(func, naming),
+        types = generate_wrappers(func, definitions, naming)
+            + &generate_fault_enum(func, definitions.target_namespace(), naming),
     )
 }
-
-fn split_comment_line(s: &str, max_len: usize, indent: usize) -> String {
-    let indent_str = " ".repeat(indent);
-
-    let mut splitted = format!("{}//", indent_str);
-    let mut current_line_length = indent + 2;
-    for word in s.split_whitespace() {
-        let len = word.len();
-        if current_line_length + len < max_len {
-            splitted = format!("{} {}", splitted, word);
-            current_line_length += 1 + len;
-        } else {
-            splitted = format!("{}\n{}// {}", splitted, indent_str, word);
-            current_line_length = indent + 3 + len;
-        }
-    }
-    format!("{}\n", splitted)
-}
-
-fn default_format_comment(doc: Option<&str>, max_len: usize, indent: usize) -> String {
-    doc.unwrap_or("")
-        .lines()
-        .map(|s| s.trim())
-        .filter(|s| s.len() > 1)
-        .map(|s| split_comment_line(s, max_len, indent))
-        .fold(String::new(), |x, y| (x + &y))
-}
-
-fn default_format_type(type_name: &str, target_ns: Option<&Namespace>) -> Cow<'static, str> {
-    let (prefix, name) = split_name(type_name);
-    let option_tns = target_ns.as_ref().and_then(|ns| ns.name());
-
-    let pascalized_name = filter_type_name(to_pascal_case(name).as_str());
-
-    let qname = |prefix| format!("{}::{}", prefix, pascalized_name);
-
-    let res = match (prefix, option_tns) {
-        (Some(ns), Some(tns)) => {
-            if ns == tns {
-                pascalized_name
-            } else {
-                qname(ns)
-            }
-        }
-        (Some(ns), None) => qname(ns),
-        _ => pascalized_name,
-    };
-
-    sanitize(res).into()
-}
-
-pub fn default_format_name(name: &str) -> String {
-    sanitize(to_snake_case(name.split(':').last().unwrap()))
-}
-
-fn split_name(name: &str) -> (Option<&str>, &str) {
-    match name.find(':') {
-        Some(index) => (Some(&name[0..index]), &name[index + 1..]),
-        None => (None, name),
-    }
-}
-
-fn filter_type_name(name: &str) -> String {
-    fn is_valid_symbol(c: char) -> bool {
-        (c.is_alphanumeric() || c == '_') && c.is_ascii() && !c.is_whitespace()
-    }
-
-    name.chars().filter(|c| is_valid_symbol(*c)).collect()
-}
-
-fn sanitize(s: String) -> String {
-    if s.is_empty() {
-        s
-    } else if s.chars().next().unwrap().is_numeric() || RS_KEYWORDS.contains(&s.as_str()) {
-        format!("_{}", s)
-    } else {
-        s
-    }
-}
-
-const RS_KEYWORDS: &[&str] = &[
-    "abstract",
-    "alignof",
-    "as",
-    "async",
-    "await",
-    "become",
-    "box",
-    "break",
-    "const",
-    "continue",
-    "crate",
-    "do",
-    "dyn",
-    "else",
-    "enum",
-    "extern crate",
-    "extern",
-    "false",
-    "final",
-    "fn",
-    "for",
-    "if let",
-    "if",
-    "impl",
-    "in",
-    "let",
-    "loop",
-    "macro",
-    "match",
-    "mod",
-    "move",
-    "mut",
-    "offsetof",
-    "override",
-    "priv",
-    "proc",
-    "pub",
-    "pure",
-    "ref",
-    "return",
-    "Self",
-    "self",
-    "sizeof",
-    "static",
-    "struct",
-    "super",
-    "trait",
-    "true",
-    "type",
-    "typeof",
-    "unsafe",
-    "unsized",
-    "use",
-    "virtual",
-    "where",
-    "while",
-    "yield",
-];
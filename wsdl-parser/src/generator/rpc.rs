@@ -0,0 +1,158 @@
+use xsd_parser::generator::naming::NamingStrategy;
+
+use crate::{
+    generator::function::{
+        input_struct_name, output_struct_name, type_path, Function, Param, Wrapper,
+        ARGUMENT_TRANSPORT,
+    },
+    parser::definitions::Definitions,
+};
+
+/// Generates the structs of the wrapper elements of the messages of an operation in RPC
+/// style. Empty for operations in document style.
+pub fn generate_wrappers(
+    func: &Function<'_>,
+    definitions: &Definitions,
+    naming: &dyn NamingStrategy,
+) -> String {
+    let rpc = match &func.rpc {
+        Some(rpc) => rpc,
+        None => return String::new(),
+    };
+    let mut code = generate_wrapper(
+        &format!("Request of the operation `{}`, in RPC style.", func.name),
+        &input_struct_name(func.name, naming),
+        func.name,
+        &rpc.input,
+        definitions,
+        naming,
+    );
+    if let Some(output) = &rpc.output {
+        code += &generate_wrapper(
+            &format!("Response of the operation `{}`, in RPC style.", func.name),
+            &output_struct_name(func.name, naming),
+            &format!("{}Response", func.name),
+            output,
+            definitions,
+            naming,
+        );
+    }
+    code
+}
+
+// A struct named `name` for the element `element`, with a field per part. The elements of
+// the parts are not qualified.
+fn generate_wrapper(
+    comment: &str,
+    name: &str,
+    element: &str,
+    wrapper: &Wrapper<'_>,
+    definitions: &Definitions,
+    naming: &dyn NamingStrategy,
+) -> String {
+    let namespace = match wrapper.namespace {
+        Some(uri) => {
+            let prefix = definitions
+                .node()
+                .namespaces()
+                .find(|ns| ns.uri() == uri && ns.name().is_some())
+                .and_then(|ns| ns.name())
+                .unwrap_or("ns");
+            format!(", prefix = \"{0}\", namespace = \"{0}: {1}\"", prefix, uri)
+        }
+        None => String::new(),
+    };
+    let fields = wrapper
+        .parts
+        .iter()
+        .map(|part| {
+            format!(
+                "    #[yaserde(rename = \"{}\")]\n    pub {}: {},\n",
+                part.name,
+                naming.field_name(part.name),
+                type_path(part.typename, definitions, naming)
+            )
+        })
+        .collect::<String>();
+    format!(
+        r#"
+/// {comment}
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(rename = "{element}"{namespace})]
+pub struct {name} {{
+{fields}}}
+"#,
+        comment = comment,
+        element = element,
+        namespace = namespace,
+        name = name,
+        fields = fields,
+    )
+}
+
+/// The request clients send: the argument `request`, or the wrapper element of the arguments
+/// in RPC style.
+pub fn request_value(func: &Function<'_>, naming: &dyn NamingStrategy) -> String {
+    match &func.rpc {
+        Some(rpc) => format!(
+            "&{} {{ {} }}",
+            input_struct_name(func.name, naming),
+            field_names(&rpc.input.parts, naming).join(", ")
+        ),
+        None => "request".to_string(),
+    }
+}
+
+/// Conversion of the response clients receive into the return type: the single part of the
+/// wrapper element in RPC style, returned as it is otherwise.
+pub fn map_response(func: &Function<'_>, naming: &dyn NamingStrategy) -> String {
+    let output = match func.rpc.as_ref().and_then(|rpc| rpc.output.as_ref()) {
+        Some(output) => output,
+        None => return String::new(),
+    };
+    let name = output_struct_name(func.name, naming);
+    match output.parts.as_slice() {
+        [] => format!(".map(|_: {}| ())", name),
+        [part] => format!(".map(|output: {}| output.{})", name, naming.field_name(part.name)),
+        _ => String::new(),
+    }
+}
+
+/// Type of the body a server deserializes, and the arguments of the method it calls, in the
+/// order of the arguments of the function.
+pub fn dispatch_arguments(func: &Function<'_>, naming: &dyn NamingStrategy) -> (String, String) {
+    match &func.rpc {
+        Some(_) => (
+            format!("::<{}>", input_struct_name(func.name, naming)),
+            func.arguments
+                .iter()
+                .filter(|p| p.name != ARGUMENT_TRANSPORT.name)
+                .map(|p| format!("body.{}", naming.field_name(p.name)))
+                .collect::<Vec<_>>()
+                .join(", "),
+        ),
+        None => (String::new(), "body".to_string()),
+    }
+}
+
+/// Conversion of the output of a method of a server into the response it sends: the wrapper
+/// element of the output in RPC style, sent as it is otherwise.
+pub fn map_output(func: &Function<'_>, naming: &dyn NamingStrategy) -> String {
+    let output = match func.rpc.as_ref().and_then(|rpc| rpc.output.as_ref()) {
+        Some(output) => output,
+        None => return String::new(),
+    };
+    let name = output_struct_name(func.name, naming);
+    match output.parts.as_slice() {
+        [] => format!(".map(|()| {} {{}})", name),
+        [part] => {
+            let field = naming.field_name(part.name);
+            format!(".map(|{0}| {1} {{ {0} }})", field, name)
+        }
+        _ => String::new(),
+    }
+}
+
+fn field_names(parts: &[Param<'_>], naming: &dyn NamingStrategy) -> Vec<String> {
+    parts.iter().map(|part| naming.field_name(part.name)).collect()
+}
@@ -0,0 +1,241 @@
+use xsd_parser::generator::{default::default_format_comment, naming::NamingStrategy};
+
+use crate::{
+    generator::{
+        fault::{fault_enum_name, generate_fault_enum},
+        function::{arguments, return_type, Function},
+        rpc::{dispatch_arguments, generate_wrappers, map_output},
+    },
+    parser::{
+        binding::Binding,
+        definitions::Definitions,
+        port_type::{Operation, OperationType, PortType},
+    },
+};
+
+/// Generates the server trait of a port type, with a method per operation the client
+/// initiates for the application to implement and a `dispatch` method calling them for SOAP
+/// requests. Actions are the ones of the first binding of the port type.
+pub fn generate_server(
+    port_type: &PortType,
+    definitions: &Definitions,
+    naming: &dyn NamingStrategy,
+) -> String {
+    let operations = port_type
+        .operations()
+        .iter()
+        .filter(|op| !op.operation_type().initiated_by_service())
+        .collect::<Vec<_>>();
+    generate_trait(
+        &format!("Server of the port type `{}`.", port_type.name()),
+        &server_name(port_type.name(), naming),
+        port_type,
+        &operations,
+        definitions,
+        naming,
+    )
+}
+
+/// Generates the handler trait of the solicit-response and notification operations of a port
+/// type, which the service initiates, followed by their wrapper structs and fault enums. The
+/// application implements a method per operation, answering the message of the service, and
+/// passes the messages it receives to `dispatch`. Empty when the service initiates no
+/// operation.
+pub fn generate_handler(
+    port_type: &PortType,
+    definitions: &Definitions,
+    naming: &dyn NamingStrategy,
+) -> String {
+    let operations = port_type
+        .operations()
+        .iter()
+        .filter(|op| op.operation_type().initiated_by_service())
+        .collect::<Vec<_>>();
+    if operations.is_empty() {
+        return String::new();
+    }
+    let binding = definitions.bindings_of(port_type.name()).into_iter().next();
+    let types = operations
+        .iter()
+        .map(|op| {
+            let func = Function::new(op, definitions, binding);
+            generate_wrappers(&func, definitions, naming)
+                + &generate_fault_enum(&func, definitions.target_namespace(), naming)
+        })
+        .collect::<String>();
+    generate_trait(
+        &format!("Handler of the operations the port type `{}` initiates.", port_type.name()),
+        &handler_name(port_type.name(), naming),
+        port_type,
+        &operations,
+        definitions,
+        naming,
+    ) + &types
+}
+
+// A trait with a method per operation and a `dispatch` method calling them for SOAP requests.
+fn generate_trait(
+    comment: &str,
+    name: &str,
+    port_type: &PortType,
+    operations: &[&Operation],
+    definitions: &Definitions,
+    naming: &dyn NamingStrategy,
+) -> String {
+    let binding = definitions.bindings_of(port_type.name()).into_iter().next();
+    let operations = operations
+        .iter()
+        .map(|op| {
+            let func = Function::new(op, definitions, binding);
+            let element = served_element(&func, op, definitions);
+            (func, element)
+        })
+        .collect::<Vec<_>>();
+
+    format!(
+        r#"
+/// {comment}
+pub trait {name} {{
+{methods}
+    /// Dispatches the SOAP request `envelope`, sent with the action `action`, to the operation
+    /// it invokes and returns the response.
+    fn dispatch(
+        &self,
+        envelope: &[u8],
+        action: Option<&str>,
+    ) -> impl std::future::Future<Output = transport::Response> {{
+        async move {{
+            let request = match transport::ServerRequest::parse(envelope, action) {{
+                Ok(request) => request,
+                Err(response) => return response,
+            }};
+            let operations = [
+{served}            ];
+            match request.operation(&operations) {{
+{arms}                _ => request.unknown_operation(),
+            }}
+        }}
+    }}
+}}
+"#,
+        comment = comment,
+        name = name,
+        methods = operations
+            .iter()
+            .map(|(func, _)| generate_method(func, definitions, naming))
+            .collect::<Vec<String>>()
+            .join("\n"),
+        served = operations
+            .iter()
+            .filter_map(|(func, element)| Some(generate_served(func, element.as_ref()?, binding)))
+            .collect::<String>(),
+        arms = operations
+            .iter()
+            .filter(|(_, element)| element.is_some())
+            .map(|(func, _)| generate_arm(func, naming))
+            .collect::<String>(),
+    )
+}
+
+pub fn server_name(port_type: &str, naming: &dyn NamingStrategy) -> String {
+    format!("{}Server", naming.type_name(port_type))
+}
+
+pub fn handler_name(port_type: &str, naming: &dyn NamingStrategy) -> String {
+    format!("{}Handler", naming.type_name(port_type))
+}
+
+// Namespace and name of the element of the first message of an operation, which the
+// dispatcher deserializes the body as: the wrapper element in RPC style. Operations in
+// document style whose first message is not a single element cannot be dispatched.
+fn served_element<'a>(
+    func: &Function<'a>,
+    operation: &Operation<'_>,
+    definitions: &'a Definitions<'_>,
+) -> Option<(Option<&'a str>, &'a str)> {
+    if let Some(rpc) = &func.rpc {
+        return Some((rpc.input.namespace, func.name));
+    }
+    let input = match operation.operation_type() {
+        OperationType::RequestResponse { input, .. } | OperationType::OneWay { input } => input,
+        OperationType::SolicitResponse { output, .. } | OperationType::Notification { output } => {
+            output
+        }
+    };
+    match definitions.get_message_by_param(input)?.parts() {
+        [part] => {
+            let element = part.element()?;
+            Some((part.element_namespace(), element.rsplit(':').next().unwrap_or(element)))
+        }
+        _ => None,
+    }
+}
+
+fn generate_method(
+    func: &Function<'_>,
+    definitions: &Definitions,
+    naming: &dyn NamingStrategy,
+) -> String {
+    let fault = if func.faults.is_empty() {
+        "transport::Fault".to_string()
+    } else {
+        fault_enum_name(func.name, naming)
+    };
+    format!(
+        r#"{comment}    fn {name}(
+        &self,
+        {arguments}
+    ) -> impl std::future::Future<Output = Result<{return_type}, {fault}>>;
+"#,
+        comment = default_format_comment(func.documentation, 80, 4),
+        name = naming.function_name(func.name),
+        arguments = arguments(func, definitions, naming, "").join(",\n        "),
+        return_type = return_type(func, definitions, naming),
+        fault = fault,
+    )
+}
+
+fn generate_served(
+    func: &Function<'_>,
+    (namespace, element): &(Option<&str>, &str),
+    binding: Option<&Binding>,
+) -> String {
+    let quoted = |s: Option<&str>| match s {
+        Some(s) => format!("Some(\"{}\")", s),
+        None => "None".to_string(),
+    };
+    let action = binding
+        .and_then(|b| b.operation(func.name))
+        .and_then(|op| op.soap_operation())
+        .and_then(|op| op.soap_action());
+    format!(
+        r#"                transport::ServedOperation {{
+                    name: "{name}",
+                    action: {action},
+                    namespace: {namespace},
+                    element: "{element}",
+                }},
+"#,
+        name = func.name,
+        action = quoted(action),
+        namespace = quoted(*namespace),
+        element = element,
+    )
+}
+
+fn generate_arm(func: &Function<'_>, naming: &dyn NamingStrategy) -> String {
+    let (body_type, arguments) = dispatch_arguments(func, naming);
+    format!(
+        r#"                Some("{operation}") => match request.body{body_type}() {{
+                    Ok(body) => request.{respond}(self.{name}({arguments}).await{map}),
+                    Err(response) => response,
+                }},
+"#,
+        operation = func.name,
+        body_type = body_type,
+        respond = if func.is_one_way() { "acknowledge" } else { "respond" },
+        name = naming.function_name(func.name),
+        arguments = arguments,
+        map = map_output(func, naming),
+    )
+}
@@ -0,0 +1,59 @@
+use xsd_parser::generator::{naming::NamingStrategy, order::ItemOrder};
+
+use crate::parser::{definitions::Definitions, service::Port};
+
+/// Generates the `services` module, with a module of constants naming every service and its
+/// ports. Ports without a `soap:address` are left out, they have no endpoint to call.
+pub fn generate_services(
+    definitions: &Definitions,
+    naming: &dyn NamingStrategy,
+    order: ItemOrder,
+) -> String {
+    let mut services = definitions.services().iter().collect::<Vec<_>>();
+    if order == ItemOrder::Alphabetical {
+        services.sort_by_key(|s| s.name());
+    }
+
+    let services = services
+        .into_iter()
+        .map(|service| {
+            let mut ports = service.ports().iter().filter(|p| p.address().is_some()).collect::<Vec<_>>();
+            if order == ItemOrder::Alphabetical {
+                ports.sort_by_key(|p| p.name());
+            }
+            format!(
+                "    pub mod {module} {{\n        pub const NAME: &str = \"{name}\";\n{ports}    }}\n",
+                module = naming.module_name(service.name()),
+                name = service.name(),
+                ports = ports
+                    .into_iter()
+                    .map(|port| generate_port(port, definitions, naming))
+                    .collect::<String>(),
+            )
+        })
+        .collect::<Vec<String>>();
+    if services.is_empty() {
+        return String::new();
+    }
+    format!("\npub mod services {{\n{}}}\n", services.join("\n"))
+}
+
+fn generate_port(port: &Port, definitions: &Definitions, naming: &dyn NamingStrategy) -> String {
+    let binding = definitions.binding_of(port);
+    let port_type = binding.and_then(|b| definitions.port_type_of(b));
+    format!(
+        r#"
+        pub mod {module} {{
+            pub const NAME: &str = "{name}";
+            pub const BINDING: &str = "{binding}";
+            pub const PORT_TYPE: &str = "{port_type}";
+            pub const ADDRESS: &str = "{address}";
+        }}
+"#,
+        module = naming.module_name(port.name()),
+        name = port.name(),
+        binding = binding.map(|b| b.name()).unwrap_or_else(|| port.binding()),
+        port_type = port_type.map(|pt| pt.name()).unwrap_or_default(),
+        address = port.address().map(|a| a.location()).unwrap_or_default(),
+    )
+}
@@ -0,0 +1,287 @@
+use std::collections::HashMap;
+
+use xsd_parser::{
+    generator::{
+        default::default_modify_type, ident::disambiguate, match_built_in_type,
+        naming::NamingStrategy,
+    },
+    parser::{
+        schema::parse_schema,
+        types::{RsEntity, RsFile, Struct, StructFieldSource, TypeModifier},
+    },
+};
+
+use crate::{
+    generator::{
+        fault::error_type,
+        function::{type_path, Function, ARGUMENT_TRANSPORT},
+    },
+    parser::definitions::Definitions,
+};
+
+/// A child element of a global element, as a field of the struct of the element.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Child {
+    pub name: String,
+    pub type_path: String,
+}
+
+/// The children of the global elements of the schemas of the definitions whose type is a
+/// sequence of elements, by namespace and name of the element. Elements with attributes,
+/// choices, extensions or anonymous children are left out, their structs not being built
+/// from children alone.
+#[derive(Debug, Clone, Default)]
+pub struct SequenceElements {
+    elements: HashMap<(Option<String>, String), Vec<Child>>,
+}
+
+impl SequenceElements {
+    pub fn new(definitions: &Definitions, naming: &dyn NamingStrategy) -> Self {
+        let mut elements = HashMap::new();
+        for schema in definitions.types().iter().flat_map(|t| t.schemas()) {
+            let file = parse_schema(&schema);
+            let namespace = file.target_ns.as_ref().map(|ns| ns.uri().to_string());
+            for element in &file.root_elements {
+                let type_name = element.type_name.rsplit(':').next().unwrap_or_default();
+                if let Some(children) = find_struct(&file, type_name)
+                    .and_then(|st| sequence_children(st, &file, naming))
+                {
+                    elements.insert((namespace.clone(), element.name.clone()), children);
+                }
+            }
+        }
+        Self { elements }
+    }
+
+    /// Children of the element `name`, prefixed as in the definitions.
+    pub fn children(&self, name: &str, definitions: &Definitions) -> Option<&[Child]> {
+        let (prefix, local_name) = match name.rsplit_once(':') {
+            Some((prefix, local_name)) => (Some(prefix), local_name),
+            None => (None, name),
+        };
+        let namespace = definitions.node().lookup_namespace_uri(prefix).map(str::to_string);
+        self.elements.get(&(namespace, local_name.to_string())).map(Vec::as_slice)
+    }
+}
+
+fn find_struct<'a>(file: &'a RsFile<'_>, name: &str) -> Option<&'a Struct> {
+    file.types.iter().find_map(|ty| match ty {
+        RsEntity::Struct(st) if st.name == name => Some(st),
+        _ => None,
+    })
+}
+
+fn sequence_children(
+    st: &Struct,
+    file: &RsFile<'_>,
+    naming: &dyn NamingStrategy,
+) -> Option<Vec<Child>> {
+    if st.is_abstract || st.base.is_some() || !st.attribute_groups.borrow().is_empty() {
+        return None;
+    }
+    let fields = st.fields.borrow();
+    let fields = fields
+        .iter()
+        .filter(|f| !f.type_modifiers.contains(&TypeModifier::Empty))
+        .collect::<Vec<_>>();
+    if fields
+        .iter()
+        .any(|f| !matches!(f.source, StructFieldSource::Element) || !f.subtypes.is_empty())
+    {
+        return None;
+    }
+    let names = disambiguate(fields.iter().map(|f| naming.field_name(&f.name)));
+    Some(
+        fields
+            .iter()
+            .zip(names)
+            .map(|(f, name)| {
+                let type_name = match match_built_in_type(&f.type_name, &file.xsd_ns) {
+                    Some(built_in) => built_in.to_string(),
+                    None => naming.type_path(&f.type_name, file.target_ns.as_ref()),
+                };
+                Child { name, type_path: default_modify_type(&type_name, &f.type_modifiers).into() }
+            })
+            .collect(),
+    )
+}
+
+/// An operation in the document/literal "wrapped" convention: its request is a single element
+/// named after the operation, a sequence of children.
+#[derive(Debug, Clone)]
+pub struct Wrapped<'a> {
+    /// Type of the request element.
+    pub request: String,
+    pub arguments: &'a [Child],
+    pub response: WrappedResponse<'a>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum WrappedResponse<'a> {
+    /// The operation has no response, or a response element without children.
+    Unit,
+    /// The single child of the response element.
+    Child(&'a Child),
+    /// A response element with several children, or which is not a sequence, returned as it
+    /// is.
+    Element,
+}
+
+impl<'a> Wrapped<'a> {
+    pub fn new(
+        func: &Function<'_>,
+        elements: &'a SequenceElements,
+        definitions: &Definitions,
+        naming: &dyn NamingStrategy,
+    ) -> Option<Self> {
+        if func.rpc.is_some() {
+            return None;
+        }
+        let request = match func.arguments.as_slice() {
+            [transport, request] if transport.name == ARGUMENT_TRANSPORT.name => request,
+            _ => return None,
+        };
+        if request.typename.rsplit(':').next() != Some(func.name) {
+            return None;
+        }
+        let arguments = elements.children(request.typename, definitions)?;
+        let response = match func.return_type {
+            "" => WrappedResponse::Unit,
+            response => match elements.children(response, definitions) {
+                Some([]) => WrappedResponse::Unit,
+                Some([child]) => WrappedResponse::Child(child),
+                _ => WrappedResponse::Element,
+            },
+        };
+        Some(Wrapped {
+            request: type_path(request.typename, definitions, naming),
+            arguments,
+            response,
+        })
+    }
+
+    fn comment(&self, func: &Function<'_>, naming: &dyn NamingStrategy, indent: usize) -> String {
+        let indent = " ".repeat(indent);
+        let returns = match self.response {
+            WrappedResponse::Child(_) => {
+                format!("\n{}/// Returns the child of its response element.", indent)
+            }
+            _ => String::new(),
+        };
+        format!(
+            "{}/// Calls `{}` with the children of its request element.{}\n",
+            indent,
+            naming.function_name(func.name),
+            returns
+        )
+    }
+
+    fn arguments(&self) -> impl Iterator<Item = String> + '_ {
+        self.arguments.iter().map(|child| format!("{}: {}", child.name, child.type_path))
+    }
+
+    fn request_value(&self) -> String {
+        if self.arguments.is_empty() {
+            return format!("&{} {{}}", self.request);
+        }
+        format!(
+            "&{} {{ {} }}",
+            self.request,
+            self.arguments.iter().map(|child| child.name.as_str()).collect::<Vec<_>>().join(", ")
+        )
+    }
+
+    fn return_type(
+        &self,
+        func: &Function<'_>,
+        definitions: &Definitions,
+        naming: &dyn NamingStrategy,
+    ) -> String {
+        match self.response {
+            WrappedResponse::Unit => "()".to_string(),
+            WrappedResponse::Child(child) => child.type_path.clone(),
+            WrappedResponse::Element => type_path(func.return_type, definitions, naming),
+        }
+    }
+
+    fn map_response(&self, func: &Function<'_>) -> String {
+        match (&self.response, func.return_type) {
+            (WrappedResponse::Unit, "") | (WrappedResponse::Element, _) => String::new(),
+            (WrappedResponse::Unit, _) => ".map(|_| ())".to_string(),
+            (WrappedResponse::Child(child), _) => {
+                format!(".map(|response| response.{})", child.name)
+            }
+        }
+    }
+}
+
+pub fn unwrapped_name(operation: &str, naming: &dyn NamingStrategy) -> String {
+    format!("{}_unwrapped", naming.function_name(operation))
+}
+
+/// Generates the method of a client calling the method of an operation in the wrapped
+/// convention with the children of its request element as arguments.
+pub fn generate_unwrapped_method(
+    func: &Function<'_>,
+    wrapped: &Wrapped<'_>,
+    definitions: &Definitions,
+    naming: &dyn NamingStrategy,
+) -> String {
+    format!(
+        r#"
+{comment}    pub async fn {name}(
+        {arguments}
+    ) -> Result<{return_type}, {error_type}> {{
+        self.{wrapper}({request}).await{map}
+    }}
+"#,
+        comment = wrapped.comment(func, naming, 4),
+        name = unwrapped_name(func.name, naming),
+        arguments = std::iter::once("&self".to_string())
+            .chain(wrapped.arguments())
+            .collect::<Vec<String>>()
+            .join(",\n        "),
+        return_type = wrapped.return_type(func, definitions, naming),
+        error_type = error_type(func, naming),
+        wrapper = naming.function_name(func.name),
+        request = wrapped.request_value(),
+        map = wrapped.map_response(func),
+    )
+}
+
+/// Generates the function calling the function of an operation in the wrapped convention with
+/// the children of its request element as arguments. Empty when a child is named like the
+/// transport argument.
+pub fn generate_unwrapped_function(
+    func: &Function<'_>,
+    wrapped: &Wrapped<'_>,
+    definitions: &Definitions,
+    naming: &dyn NamingStrategy,
+) -> String {
+    if wrapped.arguments.iter().any(|child| child.name == ARGUMENT_TRANSPORT.name) {
+        return String::new();
+    }
+    format!(
+        r#"
+{comment}pub async fn {name}<T: transport::Transport>(
+    {arguments}
+) -> Result<{return_type}, {error_type}> {{
+    {wrapper}(transport, {request}).await{map}
+}}
+"#,
+        comment = wrapped.comment(func, naming, 0),
+        name = unwrapped_name(func.name, naming),
+        arguments = std::iter::once(format!(
+            "{}: &{}",
+            ARGUMENT_TRANSPORT.name, ARGUMENT_TRANSPORT.typename
+        ))
+        .chain(wrapped.arguments())
+        .collect::<Vec<String>>()
+        .join(",\n    "),
+        return_type = wrapped.return_type(func, definitions, naming),
+        error_type = error_type(func, naming),
+        wrapper = naming.function_name(func.name),
+        request = wrapped.request_value(),
+        map = wrapped.map_response(func),
+    )
+}
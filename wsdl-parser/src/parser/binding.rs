@@ -1,25 +1,60 @@
 use roxmltree::Node;
 
-use crate::parser::{constants::attribute, ElementType, WsdlElement};
+use crate::parser::{
+    constants::attribute,
+    soap::{
+        find_soap_child, soap_children, SoapBinding, SoapBody, SoapFault, SoapHeader,
+        SoapOperation, SoapVersion, Style,
+    },
+    ElementType, WsdlElement,
+};
+
+// Content: Sequence [1..1]
+// wsdl:documentation   [0..1] from type wsdl:tDocumented
+// Any element          [0..*] Namespace: ##other, e.g. soap:binding
+// wsdl:operation       [0..*]
+
+// Attributes:
+// name	           [1..1] xsd:NCName
+// type	           [1..1] xsd:QName
 
 #[derive(Clone, Debug)]
 pub struct Binding<'a> {
     node: Node<'a, 'a>,
+    soap_binding: Option<SoapBinding<'a>>,
     operations: Vec<Operation<'a>>,
 }
 
 impl<'a> Binding<'a> {
+    pub fn node(&self) -> &Node<'a, 'a> {
+        &self.node
+    }
+
     pub fn name(&self) -> &'a str {
-        self.node.attribute(attribute::NAME).expect("Namespace required for wsdl:binding")
+        self.node.attribute(attribute::NAME).expect("Name required for wsdl:binding")
     }
 
     pub fn type_(&self) -> &'a str {
-        self.node.attribute(attribute::TYPE).expect("Location required for wsdl:binding")
+        self.node.attribute(attribute::TYPE).expect("Type required for wsdl:binding")
+    }
+
+    pub fn soap_binding(&self) -> Option<&SoapBinding<'a>> {
+        self.soap_binding.as_ref()
+    }
+
+    pub fn operations(&self) -> &[Operation<'a>] {
+        self.operations.as_ref()
+    }
+
+    pub fn operation(&self, name: &str) -> Option<&Operation<'a>> {
+        self.operations.iter().find(|op| op.name() == name)
     }
 
     pub fn new(node: &Node<'a, '_>) -> Self {
         Self {
             node: *node,
+            soap_binding: find_soap_child(node, "binding")
+                .map(|n| SoapBinding::new(&n, SoapVersion::of(&n).unwrap())),
             operations: node
                 .children()
                 .filter_map(|node| {
@@ -37,6 +72,7 @@ impl<'a> Binding<'a> {
 #[derive(Clone, Debug)]
 pub struct Operation<'a> {
     node: Node<'a, 'a>,
+    soap_operation: Option<SoapOperation<'a>>,
     input: Option<Param<'a>>,
     output: Option<Param<'a>>,
     faults: Vec<Param<'a>>,
@@ -44,7 +80,23 @@ pub struct Operation<'a> {
 
 impl<'a> Operation<'a> {
     pub fn name(&self) -> &'a str {
-        self.node.attribute(attribute::NAME).expect("Namespace required for wsdl:binding")
+        self.node.attribute(attribute::NAME).expect("Name required for wsdl:operation")
+    }
+
+    pub fn soap_operation(&self) -> Option<&SoapOperation<'a>> {
+        self.soap_operation.as_ref()
+    }
+
+    pub fn input(&self) -> Option<&Param<'a>> {
+        self.input.as_ref()
+    }
+
+    pub fn output(&self) -> Option<&Param<'a>> {
+        self.output.as_ref()
+    }
+
+    pub fn faults(&self) -> &[Param<'a>] {
+        self.faults.as_ref()
     }
 
     pub fn new(node: &Node<'a, '_>) -> Self {
@@ -59,21 +111,88 @@ impl<'a> Operation<'a> {
                 _ => {}
             }
         }
-        Self { node: *node, input, output, faults }
+        Self {
+            node: *node,
+            soap_operation: find_soap_child(node, "operation").map(|n| SoapOperation::new(&n)),
+            input,
+            output,
+            faults,
+        }
     }
 }
 
+/// Input, output or fault of an operation of a binding, with its SOAP extensions.
 #[derive(Clone, Debug)]
 pub struct Param<'a> {
     node: Node<'a, 'a>,
+    body: Option<SoapBody<'a>>,
+    headers: Vec<SoapHeader<'a>>,
+    soap_fault: Option<SoapFault<'a>>,
 }
 
 impl<'a> Param<'a> {
     pub fn new(node: &Node<'a, '_>) -> Self {
-        Self { node: *node }
+        Self {
+            node: *node,
+            body: find_soap_child(node, "body").map(|n| SoapBody::new(&n)),
+            headers: soap_children(node, "header").map(|n| SoapHeader::new(&n)).collect(),
+            soap_fault: find_soap_child(node, "fault").map(|n| SoapFault::new(&n)),
+        }
     }
 
     pub fn name(&self) -> Option<&'a str> {
         self.node.attribute(attribute::NAME)
     }
+
+    pub fn body(&self) -> Option<&SoapBody<'a>> {
+        self.body.as_ref()
+    }
+
+    pub fn headers(&self) -> &[SoapHeader<'a>] {
+        self.headers.as_ref()
+    }
+
+    pub fn soap_fault(&self) -> Option<&SoapFault<'a>> {
+        self.soap_fault.as_ref()
+    }
+}
+
+/// An operation of a port type as a binding implements it.
+#[derive(Clone, Copy, Debug)]
+pub struct OperationBinding<'b, 'a> {
+    pub binding: &'b Binding<'a>,
+    pub operation: &'b Operation<'a>,
+}
+
+impl<'b, 'a> OperationBinding<'b, 'a> {
+    /// Version of SOAP, `None` for bindings of other protocols.
+    pub fn version(&self) -> Option<SoapVersion> {
+        self.binding.soap_binding().map(|b| b.version())
+    }
+
+    pub fn transport(&self) -> Option<&'a str> {
+        self.binding.soap_binding().and_then(|b| b.transport())
+    }
+
+    /// Style of the operation, the one of the binding by default, otherwise `document`.
+    pub fn style(&self) -> Style {
+        self.operation
+            .soap_operation()
+            .and_then(|op| op.style())
+            .or_else(|| self.binding.soap_binding().and_then(|b| b.style()))
+            .unwrap_or(Style::Document)
+    }
+
+    pub fn soap_action(&self) -> Option<&'a str> {
+        self.operation.soap_operation().and_then(|op| op.soap_action())
+    }
+
+    /// Names of the faults of the operation.
+    pub fn fault_names(&self) -> Vec<&'a str> {
+        self.operation
+            .faults()
+            .iter()
+            .filter_map(|f| f.name().or_else(|| f.soap_fault().and_then(|sf| sf.name())))
+            .collect()
+    }
 }
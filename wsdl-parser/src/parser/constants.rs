@@ -7,4 +7,15 @@ pub mod attribute {
     pub const ELEMENT: &str = "element";
     pub const PARAMETER_ORDER: &str = "parameterOrder";
     pub const MESSAGE: &str = "message";
+    pub const BINDING: &str = "binding";
+    pub const TRANSPORT: &str = "transport";
+    pub const STYLE: &str = "style";
+    pub const SOAP_ACTION: &str = "soapAction";
+    pub const SOAP_ACTION_REQUIRED: &str = "soapActionRequired";
+    pub const USE: &str = "use";
+    pub const PARTS: &str = "parts";
+    pub const PART: &str = "part";
+    pub const ENCODING_STYLE: &str = "encodingStyle";
 }
+
+pub const WSDL_NS: &str = "http://schemas.xmlsoap.org/wsdl/";
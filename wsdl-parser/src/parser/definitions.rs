@@ -1,12 +1,13 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use roxmltree::{Namespace, Node};
 
 use crate::parser::{
-    binding::Binding,
+    binding::{Binding, OperationBinding},
     constants::attribute,
     message::Message,
-    port_type::{Param, PortType},
+    port_type::{Fault, Param, PortType},
+    service::{Port, Service},
     types::Types,
     ElementType, WsdlElement,
 };
@@ -37,12 +38,14 @@ use crate::parser::{
 #[derive(Debug)]
 pub struct Definitions<'a> {
     node: Node<'a, 'a>,
+    /// `wsdl:definitions` of the merged documents.
+    imported: Vec<Node<'a, 'a>>,
     imports: HashMap<&'a str, Import<'a>>,
     types: Vec<Types<'a>>,
     messages: HashMap<&'a str, Message<'a>>,
     port_types: HashMap<&'a str, PortType<'a>>,
     bindings: HashMap<&'a str, Binding<'a>>,
-    //TODO: services
+    services: Vec<Service<'a>>,
 }
 
 impl<'a> Definitions<'a> {
@@ -69,6 +72,58 @@ impl<'a> Definitions<'a> {
         &self.port_types
     }
 
+    /// Port types in the order of the document, then of the merged ones, unlike `port_types`.
+    /// A port type defined by several documents comes once.
+    pub fn port_types_in_order(&self) -> Vec<&PortType<'a>> {
+        let mut seen = HashSet::new();
+        self.children()
+            .filter(|n| n.is_element() && n.wsdl_type() == ElementType::PortType)
+            .filter_map(|n| self.port_types.get(n.attribute(attribute::NAME)?))
+            .filter(|port_type| seen.insert(qualified_name(port_type.node())))
+            .collect()
+    }
+
+    pub fn bindings(&self) -> &HashMap<&'a str, Binding<'a>> {
+        &self.bindings
+    }
+
+    /// Bindings of the port type `port_type` in the order of the document, then of the merged
+    /// ones. A binding defined by several documents comes once.
+    pub fn bindings_of(&self, port_type: &str) -> Vec<&Binding<'a>> {
+        let mut seen = HashSet::new();
+        self.children()
+            .filter(|n| n.is_element() && n.wsdl_type() == ElementType::Binding)
+            .filter_map(|n| self.bindings.get(n.attribute(attribute::NAME)?))
+            .filter(|b| b.type_().rsplit(':').next() == Some(port_type))
+            .filter(|b| seen.insert(qualified_name(b.node())))
+            .collect()
+    }
+
+    /// Binding of the operation `operation` of the port type `port_type` by the first binding
+    /// of the port type implementing it.
+    pub fn operation_binding(
+        &self,
+        port_type: &str,
+        operation: &str,
+    ) -> Option<OperationBinding<'_, 'a>> {
+        self.bindings_of(port_type).into_iter().find_map(|binding| {
+            binding.operation(operation).map(|operation| OperationBinding { binding, operation })
+        })
+    }
+
+    /// Services in the order of the document, then of the merged ones.
+    pub fn services(&self) -> &[Service<'a>] {
+        self.services.as_ref()
+    }
+
+    pub fn binding_of(&self, port: &Port<'_>) -> Option<&Binding<'a>> {
+        self.bindings.get(port.binding().rsplit(':').next()?)
+    }
+
+    pub fn port_type_of(&self, binding: &Binding<'_>) -> Option<&PortType<'a>> {
+        self.port_types.get(binding.type_().rsplit(':').next()?)
+    }
+
     pub fn types(&self) -> &[Types] {
         self.types.as_ref()
     }
@@ -81,14 +136,49 @@ impl<'a> Definitions<'a> {
         self.messages.get(param.message().split(':').last().unwrap())
     }
 
+    pub fn get_message_by_fault(&self, fault: &Fault<'_>) -> Option<&Message<'a>> {
+        self.messages.get(fault.message().rsplit(':').next()?)
+    }
+
+    // Children of the `wsdl:definitions` of the document and of the merged ones.
+    fn children(&self) -> impl Iterator<Item = Node<'a, 'a>> + '_ {
+        std::iter::once(self.node).chain(self.imported.iter().copied()).flat_map(|n| n.children())
+    }
+
+    /// Adds the types, messages, port types, bindings and services of `imported`, a document
+    /// this one imports. Items of this document are kept over imported ones of the same name,
+    /// services over the ones of the same qualified name.
+    pub fn merge(&mut self, imported: Definitions<'a>) {
+        self.imported.push(imported.node);
+        self.imported.extend(imported.imported);
+        self.types.extend(imported.types);
+        for (name, message) in imported.messages {
+            self.messages.entry(name).or_insert(message);
+        }
+        for (name, port_type) in imported.port_types {
+            self.port_types.entry(name).or_insert(port_type);
+        }
+        for (name, binding) in imported.bindings {
+            self.bindings.entry(name).or_insert(binding);
+        }
+        for service in imported.services {
+            let name = qualified_name(service.node());
+            if self.services.iter().all(|s| qualified_name(s.node()) != name) {
+                self.services.push(service);
+            }
+        }
+    }
+
     pub fn new(definitions: &Node<'a, '_>) -> Self {
         let mut res = Self {
             node: *definitions,
+            imported: vec![],
             imports: HashMap::new(),
             messages: HashMap::new(),
             port_types: HashMap::new(),
             types: vec![],
             bindings: HashMap::new(),
+            services: vec![],
         };
         for ch in definitions.children().filter(|n| n.is_element()) {
             match ch.wsdl_type() {
@@ -97,6 +187,7 @@ impl<'a> Definitions<'a> {
                 ElementType::Message => res.add_message(&ch),
                 ElementType::PortType => res.add_port_type(&ch),
                 ElementType::Binding => res.add_binding(&ch),
+                ElementType::Service => res.add_service(&ch),
                 _ => {}
             }
         }
@@ -127,6 +218,15 @@ impl<'a> Definitions<'a> {
         );
     }
 
+    fn add_service(&mut self, node: &Node<'a, '_>) {
+        let service = Service::new(node);
+        assert!(
+            self.services.iter().all(|s| s.name() != service.name()),
+            "service name must be unique"
+        );
+        self.services.push(service);
+    }
+
     fn add_binding(&mut self, node: &Node<'a, '_>) {
         let binding = Binding::new(node);
         assert!(
@@ -136,6 +236,12 @@ impl<'a> Definitions<'a> {
     }
 }
 
+// Target namespace of the document defining the item `node`, and its name.
+fn qualified_name<'a>(node: &Node<'a, 'a>) -> (Option<&'a str>, Option<&'a str>) {
+    let target_ns = node.document().root_element().attribute(attribute::TARGET_NAMESPACE);
+    (target_ns, node.attribute(attribute::NAME))
+}
+
 // Element information
 // Namespace: http://schemas.xmlsoap.org/wsdl/
 // Schema document: wsdl11.xsd
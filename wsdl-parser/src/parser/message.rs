@@ -86,6 +86,13 @@ impl<'a> Part<'a> {
         self.node.attribute(attribute::ELEMENT)
     }
 
+    /// Namespace of the element, resolved from the prefix of its name.
+    pub fn element_namespace(&self) -> Option<&'a str> {
+        let element = self.element()?;
+        let prefix = element.rsplit_once(':').map(|(prefix, _)| prefix);
+        self.node.lookup_namespace_uri(prefix)
+    }
+
     pub fn type_(&self) -> Option<&'a str> {
         self.node.attribute(attribute::TYPE)
     }
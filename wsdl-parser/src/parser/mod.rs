@@ -1,12 +1,14 @@
 #[allow(dead_code)]
 pub mod binding;
-mod constants;
+pub(crate) mod constants;
 #[allow(dead_code)]
 pub mod definitions;
 #[allow(dead_code)]
 pub mod message;
 #[allow(dead_code)]
 pub mod port_type;
+pub mod service;
+pub mod soap;
 #[allow(dead_code)]
 pub mod types;
 
@@ -26,7 +28,9 @@ pub enum ElementType {
     Operation,
     Output,
     Part,
+    Port,
     PortType,
+    Service,
     Types,
     UnknownElement(String),
 }
@@ -34,7 +38,9 @@ pub enum ElementType {
 impl<'a> WsdlElement for roxmltree::Node<'a, '_> {
     fn wsdl_type(&self) -> ElementType {
         use ElementType::*;
-        // TODO: check for wsdl prefix
+        if self.tag_name().namespace() != Some(constants::WSDL_NS) {
+            return UnknownElement(self.tag_name().name().to_string());
+        }
         match self.tag_name().name() {
             "binding" => Binding,
             "definitions" => Definitions,
@@ -46,7 +52,9 @@ impl<'a> WsdlElement for roxmltree::Node<'a, '_> {
             "operation" => Operation,
             "output" => Output,
             "part" => Part,
+            "port" => Port,
             "portType" => PortType,
+            "service" => Service,
             "types" => Types,
             _ => UnknownElement(self.tag_name().name().to_string()),
         }
@@ -148,4 +156,147 @@ mod test {
         assert_eq!(def.imports().len(), 0);
         assert_eq!(def.port_types().len(), 1);
     }
+
+    #[test]
+    fn test_port_types_in_order() {
+        let doc = Document::parse(
+            r#"
+<wsdl:definitions xmlns:wsdl="http://schemas.xmlsoap.org/wsdl/">
+    <wsdl:portType name="Media"/>
+    <wsdl:portType name="Device"/>
+    <wsdl:portType name="Imaging"/>
+    <wsdl:portType name="Events"/>
+</wsdl:definitions>
+            "#,
+        )
+        .unwrap();
+        let def = Definitions::new(&doc.root_element());
+
+        let names = def.port_types_in_order().iter().map(|pt| pt.name()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["Media", "Device", "Imaging", "Events"]);
+    }
+
+    #[test]
+    fn test_soap_binding() {
+        use crate::parser::soap::{SoapVersion, Style, Use};
+
+        let doc = Document::parse(TEXT).unwrap();
+        let def = Definitions::new(&doc.root_element());
+
+        let op = def.operation_binding("Device", "GetServices").unwrap();
+        assert_eq!(op.version(), Some(SoapVersion::Soap12));
+        assert_eq!(op.transport(), Some("http://schemas.xmlsoap.org/soap/http"));
+        assert_eq!(op.style(), Style::Document);
+        assert_eq!(op.soap_action(), Some("http://www.onvif.org/ver10/device/wsdl/GetServices"));
+        let body = op.operation.input().unwrap().body().unwrap();
+        assert_eq!(body.use_(), Use::Literal);
+        assert_eq!(body.parts(), None);
+        assert!(def.operation_binding("Device", "Unknown").is_none());
+    }
+
+    #[test]
+    fn test_soap_11_binding() {
+        use crate::parser::soap::{SoapVersion, Style, Use};
+
+        let doc = Document::parse(
+            r#"
+<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+             xmlns:soap="http://schemas.xmlsoap.org/wsdl/soap/"
+             xmlns:tns="http://example.com">
+    <portType name="Calculator"/>
+    <binding name="CalculatorBinding" type="tns:Calculator">
+        <soap:binding style="rpc" transport="http://schemas.xmlsoap.org/soap/http"/>
+        <operation name="Add">
+            <soap:operation soapAction=""/>
+            <input>
+                <soap:header message="tns:AuthHeader" part="auth" use="literal"/>
+                <soap:body use="encoded" parts="a b" namespace="http://example.com/calc"/>
+            </input>
+            <output>
+                <soap:body use="literal"/>
+            </output>
+            <fault name="Overflow">
+                <soap:fault name="Overflow" use="literal"/>
+            </fault>
+        </operation>
+    </binding>
+</definitions>
+            "#,
+        )
+        .unwrap();
+        let def = Definitions::new(&doc.root_element());
+
+        let op = def.operation_binding("Calculator", "Add").unwrap();
+        assert_eq!(op.version(), Some(SoapVersion::Soap11));
+        assert_eq!(op.style(), Style::Rpc);
+        assert_eq!(op.soap_action(), None);
+        assert_eq!(op.fault_names(), vec!["Overflow"]);
+
+        let input = op.operation.input().unwrap();
+        let body = input.body().unwrap();
+        assert_eq!(body.use_(), Use::Encoded);
+        assert_eq!(body.parts(), Some(vec!["a", "b"]));
+        assert_eq!(body.namespace(), Some("http://example.com/calc"));
+        assert_eq!(input.headers().len(), 1);
+        assert_eq!(input.headers()[0].message(), "tns:AuthHeader");
+        assert_eq!(input.headers()[0].part(), "auth");
+        assert_eq!(op.operation.faults()[0].soap_fault().unwrap().use_(), Use::Literal);
+    }
+
+    #[test]
+    fn test_services() {
+        let doc = Document::parse(include_str!("../../tests/service_endpoints/input.xsd")).unwrap();
+        let def = Definitions::new(&doc.root_element());
+
+        assert_eq!(def.services().len(), 1);
+        let service = &def.services()[0];
+        assert_eq!(service.name(), "WeatherService");
+        assert_eq!(service.ports().len(), 2);
+
+        let port = &service.ports()[0];
+        assert_eq!(port.address().unwrap().location(), "http://example.com/weather/service");
+        let binding = def.binding_of(port).unwrap();
+        assert_eq!(binding.name(), "WeatherBinding");
+        assert_eq!(def.port_type_of(binding).unwrap().name(), "Weather");
+
+        let port = &service.ports()[1];
+        assert!(port.address().is_none());
+        assert!(def.binding_of(port).is_none());
+    }
+
+    #[test]
+    fn test_merge() {
+        let document = |service: &str| {
+            format!(
+                r#"
+<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+             xmlns:tns="http://example.com"
+             targetNamespace="http://example.com">
+    <portType name="Calculator"/>
+    <binding name="CalculatorBinding" type="tns:Calculator"/>
+    <service name="{}">
+        <port name="CalculatorPort" binding="tns:CalculatorBinding"/>
+    </service>
+</definitions>
+                "#,
+                service
+            )
+        };
+        let (text, imported_text) = (document("Calculator"), document("CalculatorBackup"));
+        let (doc, imported_doc) =
+            (Document::parse(&text).unwrap(), Document::parse(&imported_text).unwrap());
+        let mut def = Definitions::new(&doc.root_element());
+        def.merge(Definitions::new(&imported_doc.root_element()));
+        def.merge(Definitions::new(&doc.root_element()));
+
+        let port_types = def.port_types_in_order();
+        assert_eq!(port_types.iter().map(|pt| pt.name()).collect::<Vec<_>>(), vec!["Calculator"]);
+        let bindings = def.bindings_of("Calculator");
+        assert_eq!(
+            bindings.iter().map(|b| b.name()).collect::<Vec<_>>(),
+            vec!["CalculatorBinding"]
+        );
+        let services = def.services().iter().map(|s| s.name()).collect::<Vec<_>>();
+        assert_eq!(services, vec!["Calculator", "CalculatorBackup"]);
+    }
 }
@@ -20,6 +20,10 @@ pub struct PortType<'a> {
 }
 
 impl<'a> PortType<'a> {
+    pub fn node(&self) -> &Node<'a, 'a> {
+        &self.node
+    }
+
     pub fn new(node: &Node<'a, '_>) -> Self {
         Self {
             node: *node,
@@ -116,6 +120,12 @@ pub enum OperationType<'a> {
 }
 
 impl<'a> OperationType<'a> {
+    /// Whether the service sends the first message, in solicit-response and notification
+    /// operations.
+    pub fn initiated_by_service(&self) -> bool {
+        matches!(self, OperationType::SolicitResponse { .. } | OperationType::Notification { .. })
+    }
+
     pub fn new(node: &Node<'a, '_>) -> Self {
         let mut children = node.children().filter(|n| n.is_element());
 
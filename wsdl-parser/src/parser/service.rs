@@ -0,0 +1,90 @@
+use roxmltree::Node;
+
+use crate::parser::{
+    constants::attribute,
+    soap::{find_soap_child, SoapAddress},
+    ElementType, WsdlElement,
+};
+
+// Element information
+// Namespace: http://schemas.xmlsoap.org/wsdl/
+// Type: wsdl:tService
+//
+// Content: Sequence [1..1]
+// wsdl:documentation   [0..1] from type wsdl:tDocumented
+// wsdl:port            [0..*]
+//
+// Attributes:
+// name	           [1..1] xsd:NCName
+//
+// Used in Group wsdl:anyTopLevelOptionalElement
+#[derive(Clone, Debug)]
+pub struct Service<'a> {
+    node: Node<'a, 'a>,
+    ports: Vec<Port<'a>>,
+}
+
+impl<'a> Service<'a> {
+    pub fn node(&self) -> &Node<'a, 'a> {
+        &self.node
+    }
+
+    pub fn new(node: &Node<'a, '_>) -> Self {
+        Self {
+            node: *node,
+            ports: node
+                .children()
+                .filter(|n| n.is_element() && n.wsdl_type() == ElementType::Port)
+                .map(|n| Port::new(&n))
+                .collect(),
+        }
+    }
+
+    pub fn name(&self) -> &'a str {
+        self.node.attribute(attribute::NAME).expect("Name required for wsdl:service")
+    }
+
+    pub fn ports(&self) -> &[Port<'a>] {
+        self.ports.as_ref()
+    }
+}
+
+// Element information
+// Namespace: http://schemas.xmlsoap.org/wsdl/
+// Type: wsdl:tPort
+//
+// Content: Sequence [1..1]
+// wsdl:documentation   [0..1] from type wsdl:tDocumented
+// Any element          [0..*] Namespace: ##other, e.g. soap:address
+//
+// Attributes:
+// name	           [1..1] xsd:NCName
+// binding	       [1..1] xsd:QName
+//
+// Used in Type wsdl:tService (Element wsdl:service)
+#[derive(Clone, Debug)]
+pub struct Port<'a> {
+    node: Node<'a, 'a>,
+    address: Option<SoapAddress<'a>>,
+}
+
+impl<'a> Port<'a> {
+    pub fn new(node: &Node<'a, '_>) -> Self {
+        Self {
+            node: *node,
+            address: find_soap_child(node, "address").map(|n| SoapAddress::new(&n)),
+        }
+    }
+
+    pub fn name(&self) -> &'a str {
+        self.node.attribute(attribute::NAME).expect("Name required for wsdl:port")
+    }
+
+    pub fn binding(&self) -> &'a str {
+        self.node.attribute(attribute::BINDING).expect("Binding required for wsdl:port")
+    }
+
+    pub fn address(&self) -> Option<&SoapAddress<'a>> {
+        self.address.as_ref()
+    }
+}
@@ -0,0 +1,270 @@
+use roxmltree::Node;
+
+use crate::parser::constants::attribute;
+
+pub const SOAP_11_NS: &str = "http://schemas.xmlsoap.org/wsdl/soap/";
+pub const SOAP_12_NS: &str = "http://schemas.xmlsoap.org/wsdl/soap12/";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SoapVersion {
+    Soap11,
+    Soap12,
+}
+
+impl SoapVersion {
+    pub fn of(node: &Node<'_, '_>) -> Option<Self> {
+        match node.tag_name().namespace()? {
+            SOAP_11_NS => Some(SoapVersion::Soap11),
+            SOAP_12_NS => Some(SoapVersion::Soap12),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Style {
+    Document,
+    Rpc,
+}
+
+impl Style {
+    fn of(node: &Node<'_, '_>) -> Option<Self> {
+        match node.attribute(attribute::STYLE)? {
+            "rpc" => Some(Style::Rpc),
+            _ => Some(Style::Document),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Use {
+    Literal,
+    Encoded,
+}
+
+impl Use {
+    fn of(node: &Node<'_, '_>) -> Self {
+        match node.attribute(attribute::USE) {
+            Some("encoded") => Use::Encoded,
+            _ => Use::Literal,
+        }
+    }
+}
+
+// The SOAP extensibility element `name` among the children of `node`.
+pub(crate) fn find_soap_child<'a>(node: &Node<'a, '_>, name: &str) -> Option<Node<'a, 'a>> {
+    node.children().find(|n| n.tag_name().name() == name && SoapVersion::of(n).is_some())
+}
+
+// The SOAP extensibility elements `name` among the children of `node`.
+pub(crate) fn soap_children<'a, 'b>(
+    node: &Node<'a, 'b>,
+    name: &'static str,
+) -> impl Iterator<Item = Node<'a, 'b>> {
+    node.children().filter(move |n| n.tag_name().name() == name && SoapVersion::of(n).is_some())
+}
+
+// Element information
+// Namespace: http://schemas.xmlsoap.org/wsdl/soap/ or http://schemas.xmlsoap.org/wsdl/soap12/
+// Type: soap:tBinding
+//
+// Attributes
+// transport    [1..1]  xsd:anyURI
+// style        [0..1]  soap:tStyleChoice ("rpc" | "document")
+//
+// Used in
+// wsdl:binding
+#[derive(Clone, Debug)]
+pub struct SoapBinding<'a> {
+    node: Node<'a, 'a>,
+    version: SoapVersion,
+}
+
+impl<'a> SoapBinding<'a> {
+    pub fn new(node: &Node<'a, '_>, version: SoapVersion) -> Self {
+        Self { node: *node, version }
+    }
+
+    pub fn version(&self) -> SoapVersion {
+        self.version
+    }
+
+    pub fn transport(&self) -> Option<&'a str> {
+        self.node.attribute(attribute::TRANSPORT)
+    }
+
+    pub fn style(&self) -> Option<Style> {
+        Style::of(&self.node)
+    }
+}
+
+// Element information
+// Type: soap:tOperation
+//
+// Attributes
+// soapAction           [0..1]  xsd:anyURI
+// soapActionRequired   [0..1]  xsd:boolean (SOAP 1.2 only)
+// style                [0..1]  soap:tStyleChoice
+//
+// Used in
+// wsdl:binding/wsdl:operation
+#[derive(Clone, Debug)]
+pub struct SoapOperation<'a> {
+    node: Node<'a, 'a>,
+}
+
+impl<'a> SoapOperation<'a> {
+    pub fn new(node: &Node<'a, '_>) -> Self {
+        Self { node: *node }
+    }
+
+    pub fn soap_action(&self) -> Option<&'a str> {
+        self.node.attribute(attribute::SOAP_ACTION).filter(|a| !a.is_empty())
+    }
+
+    pub fn soap_action_required(&self) -> bool {
+        matches!(self.node.attribute(attribute::SOAP_ACTION_REQUIRED), Some("true") | Some("1"))
+    }
+
+    pub fn style(&self) -> Option<Style> {
+        Style::of(&self.node)
+    }
+}
+
+// Element information
+// Type: soap:tBody
+//
+// Attributes
+// parts            [0..1]  xsd:NMTOKENS
+// use              [0..1]  soap:useChoice ("literal" | "encoded")
+// namespace        [0..1]  xsd:anyURI
+// encodingStyle    [0..1]  soap:encodingStyle
+//
+// Used in
+// wsdl:binding/wsdl:operation/wsdl:input and wsdl:output
+#[derive(Clone, Debug)]
+pub struct SoapBody<'a> {
+    node: Node<'a, 'a>,
+}
+
+impl<'a> SoapBody<'a> {
+    pub fn new(node: &Node<'a, '_>) -> Self {
+        Self { node: *node }
+    }
+
+    /// Parts of the message in the body, all of them if `None`.
+    pub fn parts(&self) -> Option<Vec<&'a str>> {
+        self.node.attribute(attribute::PARTS).map(|p| p.split_whitespace().collect())
+    }
+
+    pub fn use_(&self) -> Use {
+        Use::of(&self.node)
+    }
+
+    pub fn namespace(&self) -> Option<&'a str> {
+        self.node.attribute(attribute::NAMESPACE)
+    }
+
+    pub fn encoding_style(&self) -> Option<&'a str> {
+        self.node.attribute(attribute::ENCODING_STYLE)
+    }
+}
+
+// Element information
+// Type: soap:tHeader
+//
+// Content
+// soap:headerfault [0..*]
+//
+// Attributes
+// message          [1..1]  xsd:QName
+// part             [1..1]  xsd:NMTOKEN
+// use              [1..1]  soap:useChoice
+// namespace        [0..1]  xsd:anyURI
+// encodingStyle    [0..1]  soap:encodingStyle
+//
+// Used in
+// wsdl:binding/wsdl:operation/wsdl:input and wsdl:output
+#[derive(Clone, Debug)]
+pub struct SoapHeader<'a> {
+    node: Node<'a, 'a>,
+}
+
+impl<'a> SoapHeader<'a> {
+    pub fn new(node: &Node<'a, '_>) -> Self {
+        Self { node: *node }
+    }
+
+    pub fn message(&self) -> &'a str {
+        self.node.attribute(attribute::MESSAGE).expect("Message required for soap:header")
+    }
+
+    pub fn part(&self) -> &'a str {
+        self.node.attribute(attribute::PART).expect("Part required for soap:header")
+    }
+
+    pub fn use_(&self) -> Use {
+        Use::of(&self.node)
+    }
+
+    pub fn namespace(&self) -> Option<&'a str> {
+        self.node.attribute(attribute::NAMESPACE)
+    }
+}
+
+// Element information
+// Type: soap:tFault
+//
+// Attributes
+// name             [1..1]  xsd:NCName
+// use              [0..1]  soap:useChoice
+// namespace        [0..1]  xsd:anyURI
+// encodingStyle    [0..1]  soap:encodingStyle
+//
+// Used in
+// wsdl:binding/wsdl:operation/wsdl:fault
+#[derive(Clone, Debug)]
+pub struct SoapFault<'a> {
+    node: Node<'a, 'a>,
+}
+
+impl<'a> SoapFault<'a> {
+    pub fn new(node: &Node<'a, '_>) -> Self {
+        Self { node: *node }
+    }
+
+    pub fn name(&self) -> Option<&'a str> {
+        self.node.attribute(attribute::NAME)
+    }
+
+    pub fn use_(&self) -> Use {
+        Use::of(&self.node)
+    }
+
+    pub fn namespace(&self) -> Option<&'a str> {
+        self.node.attribute(attribute::NAMESPACE)
+    }
+}
+
+// Element information
+// Type: soap:tAddress
+//
+// Attributes
+// location [1..1]  xsd:anyURI
+//
+// Used in
+// wsdl:service/wsdl:port
+#[derive(Clone, Debug)]
+pub struct SoapAddress<'a> {
+    node: Node<'a, 'a>,
+}
+
+impl<'a> SoapAddress<'a> {
+    pub fn new(node: &Node<'a, '_>) -> Self {
+        Self { node: *node }
+    }
+
+    pub fn location(&self) -> &'a str {
+        self.node.attribute(attribute::LOCATION).expect("Location required for soap:address")
+    }
+}
@@ -0,0 +1,250 @@
+//! WSDL documents spread over several files.
+//!
+//! ```no_run
+//! use std::path::Path;
+//!
+//! use wsdl_parser::{generator::Options, project::Project};
+//! use xsd_parser::generator::naming::DefaultNamingStrategy;
+//!
+//! let project = Project::load(Path::new("wsdl/devicemgmt.wsdl")).unwrap();
+//! let code = project.generate(&DefaultNamingStrategy, &Options::default()).unwrap();
+//! let modules = project.generate_schemas(&DefaultNamingStrategy, &Options::default()).unwrap();
+//! ```
+//!
+//! The imported schemas are generated apart from the WSDL documents, as a module per target
+//! namespace, so that the documents importing the same schemas can share them.
+
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use roxmltree::{Document, Node};
+use xsd_parser::{
+    generator::{
+        builder::GeneratorBuilder, ident::disambiguate, naming::NamingStrategy,
+        split::GeneratedModule, Generator,
+    },
+    parser::{
+        constants::attribute::SCHEMA_LOCATION,
+        parse_document,
+        schema::parse_schema,
+        xsd_elements::{ElementType as XsdElementType, XsdNode},
+    },
+};
+
+use crate::{
+    generator::{generate_with, Options},
+    parser::{constants::attribute, definitions::Definitions, ElementType, WsdlElement},
+};
+
+#[derive(Debug)]
+pub enum Error {
+    Io(PathBuf, io::Error),
+    Xml(PathBuf, roxmltree::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(path, err) => write!(f, "{}: {}", path.display(), err),
+            Error::Xml(path, err) => write!(f, "{}: {}", path.display(), err),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(_, err) => Some(err),
+            Error::Xml(_, err) => Some(err),
+        }
+    }
+}
+
+/// A file of a project.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceFile {
+    pub path: PathBuf,
+    pub text: String,
+}
+
+impl SourceFile {
+    pub fn read(path: &Path) -> Result<Self, Error> {
+        let text = fs::read_to_string(path).map_err(|e| Error::Io(path.to_path_buf(), e))?;
+        Ok(Self { path: path.to_path_buf(), text })
+    }
+
+    pub fn parse(&self) -> Result<Document<'_>, Error> {
+        Document::parse(&self.text).map_err(|e| Error::Xml(self.path.clone(), e))
+    }
+}
+
+/// A WSDL document with the WSDL documents and schemas it imports, transitively.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Project {
+    /// The WSDL document, then the ones it imports.
+    pub wsdl: Vec<SourceFile>,
+    /// The schemas the WSDL documents import, with the ones these import or include.
+    pub schemas: Vec<SourceFile>,
+}
+
+impl Project {
+    /// Loads the WSDL document `path` and the files its `wsdl:import` elements and the
+    /// `xs:import`, `xs:include` and `xs:redefine` elements of its schemas refer to, relative
+    /// to the file referring to them. Remote locations and missing files are skipped.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let mut project = Project::default();
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        visited.insert(canonical(path));
+        queue.push_back(path.to_path_buf());
+
+        while let Some(current) = queue.pop_front() {
+            let file = SourceFile::read(&current)?;
+            let doc = file.parse()?;
+            let root = doc.root_element();
+            for location in locations(&root) {
+                let dep = match current.parent() {
+                    Some(dir) => dir.join(location),
+                    None => PathBuf::from(location),
+                };
+                if dep.is_file() && visited.insert(canonical(&dep)) {
+                    queue.push_back(dep);
+                }
+            }
+            let is_wsdl = project.wsdl.is_empty() || root.wsdl_type() == ElementType::Definitions;
+            drop(doc);
+            match is_wsdl {
+                true => project.wsdl.push(file),
+                false => project.schemas.push(file),
+            }
+        }
+        Ok(project)
+    }
+
+    /// Generates the types of the schemas of the WSDL documents and the operations of their
+    /// port types as `generate_with` does. Types of the imported schemas are left to
+    /// `generate_schemas`.
+    pub fn generate(
+        &self,
+        naming: &dyn NamingStrategy,
+        options: &Options,
+    ) -> Result<String, Error> {
+        let docs = self.wsdl.iter().map(SourceFile::parse).collect::<Result<Vec<_>, _>>()?;
+        let mut definitions = docs.iter().map(|doc| Definitions::new(&doc.root_element()));
+        let mut merged = match definitions.next() {
+            Some(definitions) => definitions,
+            None => return Ok(String::new()),
+        };
+        definitions.for_each(|imported| merged.merge(imported));
+
+        let gen = generator(naming, options);
+        let schemas =
+            merged.types().iter().flat_map(|t| t.schemas()).collect::<Vec<Node<'_, '_>>>();
+        let mut code =
+            schemas.iter().map(|f| gen.generate_rs_file(&parse_schema(f))).collect::<Vec<String>>();
+        code.push(generate_with(&merged, naming, options));
+        Ok(code.join(""))
+    }
+
+    /// Generates the types of the imported schemas, see `generate_schemas`.
+    pub fn generate_schemas(
+        &self,
+        naming: &dyn NamingStrategy,
+        options: &Options,
+    ) -> Result<Vec<GeneratedModule>, Error> {
+        generate_schemas(&self.schemas, naming, options)
+    }
+}
+
+/// Generates the types of `schemas`, a module per target namespace named after the prefix
+/// the first of its schemas declares for it, which the generated code refers to the types
+/// with. Schemas without a prefix for their target namespace are named after their file, and
+/// namespaces named alike are told apart by a number.
+pub fn generate_schemas(
+    schemas: &[SourceFile],
+    naming: &dyn NamingStrategy,
+    options: &Options,
+) -> Result<Vec<GeneratedModule>, Error> {
+    // Target namespace, name and code of each module.
+    let mut modules: Vec<(Option<String>, String, String)> = vec![];
+    for schema in schemas {
+        let doc = schema.parse()?;
+        let root = doc.root_element();
+        let target_ns = root.attribute(attribute::TARGET_NAMESPACE);
+        let gen = generator(naming, options);
+        let code = gen.generate_rs_file(&parse_document(&doc));
+        match modules.iter_mut().find(|(ns, _, _)| ns.as_deref() == target_ns) {
+            Some((_, _, module_code)) => module_code.push_str(&code),
+            None => modules.push((
+                target_ns.map(str::to_string),
+                module_name(schema, &root, naming),
+                code,
+            )),
+        }
+    }
+    let names = disambiguate(modules.iter().map(|(_, name, _)| name.clone()));
+    Ok(modules
+        .into_iter()
+        .zip(names)
+        .map(|((_, _, code), name)| GeneratedModule {
+            name,
+            code: format!("use super::*;\n\n{}", code),
+        })
+        .collect())
+}
+
+// The prefix the schema `root` declares for its target namespace, or the name of its file.
+fn module_name(schema: &SourceFile, root: &Node<'_, '_>, naming: &dyn NamingStrategy) -> String {
+    let prefix = root
+        .attribute(attribute::TARGET_NAMESPACE)
+        .and_then(|tn| root.namespaces().find(|ns| ns.uri() == tn && ns.name().is_some())?.name());
+    match prefix {
+        Some(prefix) => prefix.to_string(),
+        None => naming.module_name(
+            &schema.path.file_stem().unwrap_or_default().to_string_lossy().replace('.', "_"),
+        ),
+    }
+}
+
+fn generator<'a>(naming: &'a dyn NamingStrategy, options: &Options) -> Generator<'a> {
+    GeneratorBuilder::default().with_item_order(options.order).with_naming(Box::new(naming)).build()
+}
+
+// Locations of the files a WSDL document or a schema refers to, remote ones excepted.
+fn locations<'a>(root: &Node<'a, '_>) -> Vec<&'a str> {
+    let schema_locations = |schema: Node<'a, '_>| {
+        schema
+            .children()
+            .filter(|n| {
+                matches!(
+                    n.xsd_type(),
+                    XsdElementType::Import | XsdElementType::Include | XsdElementType::Redefine
+                )
+            })
+            .filter_map(|n| n.attribute(SCHEMA_LOCATION))
+            .collect::<Vec<_>>()
+    };
+    let locations = match root.wsdl_type() {
+        ElementType::Definitions => root
+            .children()
+            .filter(|n| n.wsdl_type() == ElementType::Import)
+            .filter_map(|n| n.attribute(attribute::LOCATION))
+            .chain(
+                root.children()
+                    .filter(|n| n.wsdl_type() == ElementType::Types)
+                    .flat_map(|types| types.children())
+                    .filter(|n| n.xsd_type() == XsdElementType::Schema)
+                    .flat_map(schema_locations),
+            )
+            .collect(),
+        _ => schema_locations(*root),
+    };
+    locations.into_iter().filter(|location| !location.contains("://")).collect()
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
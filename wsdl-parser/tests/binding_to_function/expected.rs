@@ -0,0 +1,144 @@
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct GetForecast {
+    #[yaserde(prefix = "tns", rename = "City")]
+    pub city: String,
+}
+
+impl Validate for GetForecast {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct GetForecastResponse {
+    #[yaserde(prefix = "tns", rename = "Forecast")]
+    pub forecast: String,
+}
+
+impl Validate for GetForecastResponse {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct UnknownCity {
+    #[yaserde(prefix = "tns", rename = "City")]
+    pub city: String,
+}
+
+impl Validate for UnknownCity {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct ServiceUnavailable {
+    #[yaserde(prefix = "tns", rename = "RetryAfter")]
+    pub retry_after: i32,
+}
+
+impl Validate for ServiceUnavailable {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct ReportObservation {
+    #[yaserde(prefix = "tns", rename = "City")]
+    pub city: String,
+
+    #[yaserde(prefix = "tns", rename = "Temperature")]
+    pub temperature: f64,
+}
+
+impl Validate for ReportObservation {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct GetCities {}
+
+impl Validate for GetCities {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct GetCitiesResponse {
+    #[yaserde(prefix = "tns", rename = "City")]
+    pub city: Vec<String>,
+}
+
+impl Validate for GetCitiesResponse {}
+
+
+
+pub async fn get_forecast<T: transport::Transport>(
+    transport: &T,
+    request: &GetForecast
+) -> Result<GetForecastResponse, transport::Error<GetForecastFault>> {
+    let operation = transport::SoapOperation {
+        version: transport::SoapVersion::Soap12,
+        action: Some("http://example.com/weather/GetForecast"),
+    };
+    transport::call(transport, "", &operation, request).await
+}
+
+/// Faults of the operation `GetForecast`.
+#[derive(Debug, PartialEq)]
+pub enum GetForecastFault {
+    UnknownCityFault(UnknownCity),
+    ServiceUnavailableFault(ServiceUnavailable),
+    /// A fault the operation does not declare.
+    Other(transport::Fault),
+}
+
+impl transport::OperationFault for GetForecastFault {
+    fn from_fault(fault: transport::Fault) -> Self {
+        if let Some(detail) = fault.detail_as(Some("http://example.com/weather"), "UnknownCity") {
+            return GetForecastFault::UnknownCityFault(detail);
+        }
+        if let Some(detail) = fault.detail_as(Some("http://example.com/weather"), "ServiceUnavailable") {
+            return GetForecastFault::ServiceUnavailableFault(detail);
+        }
+        GetForecastFault::Other(fault)
+    }
+
+    fn into_fault(self) -> transport::Fault {
+        match self {
+            GetForecastFault::UnknownCityFault(detail) => transport::Fault::with_detail("UnknownCityFault", &detail),
+            GetForecastFault::ServiceUnavailableFault(detail) => transport::Fault::with_detail("ServiceUnavailableFault", &detail),
+            GetForecastFault::Other(fault) => fault,
+        }
+    }
+}
+
+impl std::fmt::Display for GetForecastFault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GetForecastFault::UnknownCityFault(detail) => write!(f, "SOAP fault UnknownCityFault: {:?}", detail),
+            GetForecastFault::ServiceUnavailableFault(detail) => write!(f, "SOAP fault ServiceUnavailableFault: {:?}", detail),
+            GetForecastFault::Other(fault) => write!(f, "{}", fault),
+        }
+    }
+}
+
+/// Returns the cities forecasts are known for.
+pub async fn get_cities<T: transport::Transport>(
+    transport: &T,
+    request: &GetCities
+) -> Result<GetCitiesResponse, transport::Error> {
+    let operation = transport::SoapOperation {
+        version: transport::SoapVersion::Soap12,
+        action: Some("http://example.com/weather/GetCities"),
+    };
+    transport::call(transport, "", &operation, request).await
+}
+
+pub async fn report_observation<T: transport::Transport>(
+    transport: &T,
+    request: &ReportObservation
+) -> Result<(), transport::Error> {
+    let operation = transport::SoapOperation {
+        version: transport::SoapVersion::Soap12,
+        action: Some("http://example.com/weather/ReportObservation"),
+    };
+    transport::send(transport, "", &operation, request).await
+}
+
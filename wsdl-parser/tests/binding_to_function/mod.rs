@@ -0,0 +1,58 @@
+use std::sync::Mutex;
+
+use wsdl_parser::generator::{OperationStyle, Options};
+use wsdl_runtime as transport;
+
+use super::{ast_test_with, block_on, generate_wsdl_with};
+
+fn options() -> Options {
+    Options { operations: OperationStyle::Functions, ..Default::default() }
+}
+
+#[test]
+fn generator_does_not_panic() {
+    println!("{}", generate_wsdl_with(include_str!("input.xsd"), &options()))
+}
+
+#[test]
+fn generator_output_has_correct_ast() {
+    ast_test_with(include_str!("input.xsd"), include_str!("expected.rs"), &options());
+}
+
+// The generated code compiles against the runtime. `yaserde_derive` puts its impls in named
+// constants, which the lint takes for impls nested in another item.
+#[allow(dead_code, non_local_definitions)]
+mod expected {
+    use wsdl_runtime as transport;
+    use xsd_parser::generator::validator::Validate;
+    use yaserde_derive::{YaDeserialize, YaSerialize};
+
+    include!("expected.rs");
+}
+
+// Records the requests it sends, answered with an empty envelope.
+#[derive(Default)]
+struct MockTransport {
+    requests: Mutex<Vec<transport::Request>>,
+}
+
+impl transport::Transport for MockTransport {
+    async fn send(&self, request: transport::Request) -> Result<String, transport::Error> {
+        self.requests.lock().unwrap().push(request);
+        Ok(String::new())
+    }
+}
+
+#[test]
+fn functions_send_the_action_of_the_binding() {
+    let transport = MockTransport::default();
+
+    let request = expected::ReportObservation { city: "Paris".to_string(), temperature: 21.5 };
+    assert_eq!(block_on(expected::report_observation(&transport, &request)), Ok(()));
+
+    let requests = transport.requests.lock().unwrap();
+    assert_eq!(
+        requests[0].content_type,
+        "application/soap+xml; charset=utf-8; action=\"http://example.com/weather/ReportObservation\""
+    );
+}
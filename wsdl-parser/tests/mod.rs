@@ -1,10 +1,35 @@
+use std::{
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
 use roxmltree::{Document, Node};
-use wsdl_parser::{generator::generate, parser::definitions::Definitions};
-use xsd_parser::{generator::builder::GeneratorBuilder, parser::schema::parse_schema};
+use wsdl_parser::{
+    generator::{generate_with, Options},
+    parser::definitions::Definitions,
+};
+use xsd_parser::{
+    generator::{builder::GeneratorBuilder, naming::DefaultNamingStrategy},
+    parser::schema::parse_schema,
+};
 
+mod binding_to_function;
+mod operation_faults;
+mod port_type_to_client;
 mod port_type_to_function;
+mod port_type_to_server;
+mod rpc_style;
+mod service_endpoints;
+mod service_initiated_operations;
+mod wrapped_operations;
+mod wsdl_imports;
 
 pub fn generate_wsdl(input: &str) -> String {
+    generate_wsdl_with(input, &Options::default())
+}
+
+pub fn generate_wsdl_with(input: &str, options: &Options) -> String {
     let doc = Document::parse(input).unwrap();
     let definitions = Definitions::new(&doc.root_element());
     let gen = GeneratorBuilder::default().build();
@@ -13,7 +38,7 @@ pub fn generate_wsdl(input: &str) -> String {
     let mut code =
         schemas.iter().map(|f| gen.generate_rs_file(&parse_schema(f))).collect::<Vec<String>>();
 
-    code.push(generate(&definitions));
+    code.push(generate_with(&definitions, &DefaultNamingStrategy, options));
     code.join("")
 }
 
@@ -25,8 +50,12 @@ pub fn assert_ast_eq(expected: &str, actual: &str) {
 }
 
 pub fn ast_test(input_wsdl: &str, expected_rs: &str) {
+    ast_test_with(input_wsdl, expected_rs, &Options::default())
+}
+
+pub fn ast_test_with(input_wsdl: &str, expected_rs: &str, options: &Options) {
     let expected = expected_rs;
-    let actual = generate_wsdl(input_wsdl);
+    let actual = generate_wsdl_with(input_wsdl, options);
 
     println!("=== expected:\n{}", expected);
     println!("=== actual:\n{}", actual);
@@ -36,3 +65,11 @@ pub fn ast_test(input_wsdl: &str, expected_rs: &str) {
 
     assert_ast_eq(expected, &actual)
 }
+
+// Runs `future`, whose transport is in-process, so that it is ready at once.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("future is not ready"),
+    }
+}
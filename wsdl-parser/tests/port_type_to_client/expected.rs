@@ -0,0 +1,56 @@
+//use ../../../ver10/schema/onvif.xsd  http://www.onvif.org/ver10/schema;
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tds", namespace = "tds: http://www.onvif.org/ver10/device/wsdl")]
+pub struct GetServices {
+    /// Indicates if the service capabilities (untyped) should be included in
+    /// the response.
+    #[yaserde(prefix = "tds", rename = "IncludeCapability")]
+    pub include_capability: bool,
+}
+
+impl Validate for GetServices {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tds", namespace = "tds: http://www.onvif.org/ver10/device/wsdl")]
+pub struct GetServicesResponse {
+    /// Each Service element contains information about one service.
+    #[yaserde(prefix = "tds", rename = "Service")]
+    pub service: Vec<Service>,
+}
+
+impl Validate for GetServicesResponse {}
+
+
+
+/// Client of the port type `Device`.
+pub struct DeviceClient<T: transport::Transport> {
+    transport: T,
+    endpoint: String,
+}
+
+impl<T: transport::Transport> DeviceClient<T> {
+    pub fn new(transport: T, endpoint: &str) -> Self {
+        Self { transport, endpoint: endpoint.to_string() }
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Returns information about services on the device.
+    pub async fn get_services(
+        &self,
+        request: &GetServices
+    ) -> Result<GetServicesResponse, transport::Error> {
+        let operation = transport::SoapOperation {
+            version: transport::SoapVersion::Soap12,
+            action: Some("http://www.onvif.org/ver10/device/wsdl/GetServices"),
+        };
+        transport::call(&self.transport, &self.endpoint, &operation, request).await
+    }
+}
@@ -0,0 +1,17 @@
+use wsdl_parser::generator::{OperationStyle, Options};
+
+use super::{ast_test_with, generate_wsdl_with};
+
+fn options() -> Options {
+    Options { operations: OperationStyle::Clients, ..Default::default() }
+}
+
+#[test]
+fn generator_does_not_panic() {
+    println!("{}", generate_wsdl_with(include_str!("input.xsd"), &options()))
+}
+
+#[test]
+fn generator_output_has_correct_ast() {
+    ast_test_with(include_str!("input.xsd"), include_str!("expected.rs"), &options());
+}
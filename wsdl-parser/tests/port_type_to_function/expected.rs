@@ -2,8 +2,8 @@
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tds", namespace = "tds: http://www.onvif.org/ver10/device/wsdl")]
 pub struct GetServices {
-    // Indicates if the service capabilities (untyped) should be included in the
-    // response.
+    /// Indicates if the service capabilities (untyped) should be included in
+    /// the response.
     #[yaserde(prefix = "tds", rename = "IncludeCapability")]
     pub include_capability: bool,
 }
@@ -14,14 +14,14 @@ impl Validate for GetServices {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tds", namespace = "tds: http://www.onvif.org/ver10/device/wsdl")]
 pub struct GetServicesResponse {
-    // Each Service element contains information about one service.
+    /// Each Service element contains information about one service.
     #[yaserde(prefix = "tds", rename = "Service")]
     pub service: Vec<Service>,
 }
 
 impl Validate for GetServicesResponse {}
 
-// Returns information about services on the device.
+/// Returns information about services on the device.
 pub async fn get_services<T: transport::Transport>(
     transport: &T,
     request: &GetServices
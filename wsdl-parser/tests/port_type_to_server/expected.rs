@@ -0,0 +1,232 @@
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct GetForecast {
+    #[yaserde(prefix = "tns", rename = "City")]
+    pub city: String,
+}
+
+impl Validate for GetForecast {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct GetForecastResponse {
+    #[yaserde(prefix = "tns", rename = "Forecast")]
+    pub forecast: String,
+}
+
+impl Validate for GetForecastResponse {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct UnknownCity {
+    #[yaserde(prefix = "tns", rename = "City")]
+    pub city: String,
+}
+
+impl Validate for UnknownCity {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct ServiceUnavailable {
+    #[yaserde(prefix = "tns", rename = "RetryAfter")]
+    pub retry_after: i32,
+}
+
+impl Validate for ServiceUnavailable {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct ReportObservation {
+    #[yaserde(prefix = "tns", rename = "City")]
+    pub city: String,
+
+    #[yaserde(prefix = "tns", rename = "Temperature")]
+    pub temperature: f64,
+}
+
+impl Validate for ReportObservation {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct GetCities {}
+
+impl Validate for GetCities {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct GetCitiesResponse {
+    #[yaserde(prefix = "tns", rename = "City")]
+    pub city: Vec<String>,
+}
+
+impl Validate for GetCitiesResponse {}
+
+
+
+/// Client of the port type `Weather`.
+pub struct WeatherClient<T: transport::Transport> {
+    transport: T,
+    endpoint: String,
+}
+
+impl<T: transport::Transport> WeatherClient<T> {
+    pub fn new(transport: T, endpoint: &str) -> Self {
+        Self { transport, endpoint: endpoint.to_string() }
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    pub async fn get_forecast(
+        &self,
+        request: &GetForecast
+    ) -> Result<GetForecastResponse, transport::Error<GetForecastFault>> {
+        let operation = transport::SoapOperation {
+            version: transport::SoapVersion::Soap12,
+            action: Some("http://example.com/weather/GetForecast"),
+        };
+        transport::call(&self.transport, &self.endpoint, &operation, request).await
+    }
+
+    /// Returns the cities forecasts are known for.
+    pub async fn get_cities(
+        &self,
+        request: &GetCities
+    ) -> Result<GetCitiesResponse, transport::Error> {
+        let operation = transport::SoapOperation {
+            version: transport::SoapVersion::Soap12,
+            action: Some("http://example.com/weather/GetCities"),
+        };
+        transport::call(&self.transport, &self.endpoint, &operation, request).await
+    }
+
+    pub async fn report_observation(
+        &self,
+        request: &ReportObservation
+    ) -> Result<(), transport::Error> {
+        let operation = transport::SoapOperation {
+            version: transport::SoapVersion::Soap12,
+            action: Some("http://example.com/weather/ReportObservation"),
+        };
+        transport::send(&self.transport, &self.endpoint, &operation, request).await
+    }
+}
+
+/// Faults of the operation `GetForecast`.
+#[derive(Debug, PartialEq)]
+pub enum GetForecastFault {
+    UnknownCityFault(UnknownCity),
+    ServiceUnavailableFault(ServiceUnavailable),
+    /// A fault the operation does not declare.
+    Other(transport::Fault),
+}
+
+impl transport::OperationFault for GetForecastFault {
+    fn from_fault(fault: transport::Fault) -> Self {
+        if let Some(detail) = fault.detail_as(Some("http://example.com/weather"), "UnknownCity") {
+            return GetForecastFault::UnknownCityFault(detail);
+        }
+        if let Some(detail) = fault.detail_as(Some("http://example.com/weather"), "ServiceUnavailable") {
+            return GetForecastFault::ServiceUnavailableFault(detail);
+        }
+        GetForecastFault::Other(fault)
+    }
+
+    fn into_fault(self) -> transport::Fault {
+        match self {
+            GetForecastFault::UnknownCityFault(detail) => transport::Fault::with_detail("UnknownCityFault", &detail),
+            GetForecastFault::ServiceUnavailableFault(detail) => transport::Fault::with_detail("ServiceUnavailableFault", &detail),
+            GetForecastFault::Other(fault) => fault,
+        }
+    }
+}
+
+impl std::fmt::Display for GetForecastFault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GetForecastFault::UnknownCityFault(detail) => write!(f, "SOAP fault UnknownCityFault: {:?}", detail),
+            GetForecastFault::ServiceUnavailableFault(detail) => write!(f, "SOAP fault ServiceUnavailableFault: {:?}", detail),
+            GetForecastFault::Other(fault) => write!(f, "{}", fault),
+        }
+    }
+}
+
+/// Server of the port type `Weather`.
+pub trait WeatherServer {
+    fn get_forecast(
+        &self,
+        request: GetForecast
+    ) -> impl std::future::Future<Output = Result<GetForecastResponse, GetForecastFault>>;
+
+    /// Returns the cities forecasts are known for.
+    fn get_cities(
+        &self,
+        request: GetCities
+    ) -> impl std::future::Future<Output = Result<GetCitiesResponse, transport::Fault>>;
+
+    fn report_observation(
+        &self,
+        request: ReportObservation
+    ) -> impl std::future::Future<Output = Result<(), transport::Fault>>;
+
+    /// Dispatches the SOAP request `envelope`, sent with the action `action`, to the operation
+    /// it invokes and returns the response.
+    fn dispatch(
+        &self,
+        envelope: &[u8],
+        action: Option<&str>,
+    ) -> impl std::future::Future<Output = transport::Response> {
+        async move {
+            let request = match transport::ServerRequest::parse(envelope, action) {
+                Ok(request) => request,
+                Err(response) => return response,
+            };
+            let operations = [
+                transport::ServedOperation {
+                    name: "GetForecast",
+                    action: Some("http://example.com/weather/GetForecast"),
+                    namespace: Some("http://example.com/weather"),
+                    element: "GetForecast",
+                },
+                transport::ServedOperation {
+                    name: "GetCities",
+                    action: Some("http://example.com/weather/GetCities"),
+                    namespace: Some("http://example.com/weather"),
+                    element: "GetCities",
+                },
+                transport::ServedOperation {
+                    name: "ReportObservation",
+                    action: Some("http://example.com/weather/ReportObservation"),
+                    namespace: Some("http://example.com/weather"),
+                    element: "ReportObservation",
+                },
+            ];
+            match request.operation(&operations) {
+                Some("GetForecast") => match request.body() {
+                    Ok(body) => request.respond(self.get_forecast(body).await),
+                    Err(response) => response,
+                },
+                Some("GetCities") => match request.body() {
+                    Ok(body) => request.respond(self.get_cities(body).await),
+                    Err(response) => response,
+                },
+                Some("ReportObservation") => match request.body() {
+                    Ok(body) => request.acknowledge(self.report_observation(body).await),
+                    Err(response) => response,
+                },
+                _ => request.unknown_operation(),
+            }
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use std::sync::Mutex;
+
+use wsdl_parser::generator::{OperationStyle, Options};
+
+use expected::{
+    GetCities, GetCitiesResponse, GetForecast, GetForecastFault, GetForecastResponse,
+    ReportObservation, UnknownCity, WeatherClient, WeatherServer,
+};
+use wsdl_runtime as transport;
+
+use super::{ast_test_with, block_on, generate_wsdl_with};
+
+fn options() -> Options {
+    Options { operations: OperationStyle::Clients, servers: true, ..Default::default() }
+}
+
+#[test]
+fn generator_does_not_panic() {
+    println!("{}", generate_wsdl_with(include_str!("input.xsd"), &options()))
+}
+
+#[test]
+fn generator_output_has_correct_ast() {
+    ast_test_with(include_str!("input.xsd"), include_str!("expected.rs"), &options());
+}
+
+#[allow(dead_code)]
+mod expected {
+    use wsdl_runtime as transport;
+    use xsd_parser::generator::validator::Validate;
+    use yaserde_derive::{YaDeserialize, YaSerialize};
+
+    include!("expected.rs");
+}
+
+// Records the observations it is reported and dispatches requests to itself in-process.
+#[derive(Default)]
+struct Weather {
+    observations: Mutex<Vec<(String, f64)>>,
+}
+
+impl WeatherServer for Weather {
+    async fn get_forecast(
+        &self,
+        request: GetForecast,
+    ) -> Result<GetForecastResponse, GetForecastFault> {
+        match request.city.as_str() {
+            "Paris" => Ok(GetForecastResponse { forecast: "Sunny".to_string() }),
+            city => Err(GetForecastFault::UnknownCityFault(UnknownCity { city: city.to_string() })),
+        }
+    }
+
+    async fn get_cities(&self, _: GetCities) -> Result<GetCitiesResponse, transport::Fault> {
+        Ok(GetCitiesResponse { city: vec!["Paris".to_string()] })
+    }
+
+    async fn report_observation(&self, request: ReportObservation) -> Result<(), transport::Fault> {
+        if request.city.is_empty() {
+            return Err(transport::Fault { reason: "No city".to_string(), ..Default::default() });
+        }
+        self.observations.lock().unwrap().push((request.city, request.temperature));
+        Ok(())
+    }
+}
+
+impl transport::Transport for Weather {
+    async fn send(&self, request: transport::Request) -> Result<String, transport::Error> {
+        let action = request.soap_action.as_deref();
+        Ok(self.dispatch(request.envelope.as_bytes(), action).await.envelope)
+    }
+}
+
+#[test]
+fn client_calls_server() {
+    let client = WeatherClient::new(Weather::default(), "");
+
+    let response = block_on(client.get_forecast(&GetForecast { city: "Paris".to_string() }));
+    assert_eq!(response.unwrap().forecast, "Sunny");
+
+    let response = block_on(client.get_forecast(&GetForecast { city: "Atlantis".to_string() }));
+    let expected = UnknownCity { city: "Atlantis".to_string() };
+    assert_eq!(
+        response,
+        Err(transport::Error::Fault(GetForecastFault::UnknownCityFault(expected)))
+    );
+
+    let response = block_on(client.get_cities(&GetCities {}));
+    assert_eq!(response.unwrap().city, vec!["Paris"]);
+}
+
+#[test]
+fn client_sends_one_way_operations() {
+    let client = WeatherClient::new(Weather::default(), "");
+
+    let request = ReportObservation { city: "Paris".to_string(), temperature: 21.5 };
+    assert_eq!(block_on(client.report_observation(&request)), Ok(()));
+    assert_eq!(*client.transport().observations.lock().unwrap(), vec![("Paris".to_string(), 21.5)]);
+
+    let result = block_on(client.report_observation(&ReportObservation::default()));
+    assert!(matches!(result, Err(transport::Error::Fault(fault)) if fault.reason == "No city"));
+}
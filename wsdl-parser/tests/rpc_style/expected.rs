@@ -0,0 +1,146 @@
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/quotes")]
+pub struct Quote {
+    #[yaserde(prefix = "tns", rename = "Price")]
+    pub price: f64,
+
+    #[yaserde(prefix = "tns", rename = "Time")]
+    pub time: xs::DateTime,
+}
+
+impl Validate for Quote {}
+
+
+
+/// Client of the port type `Quotes`.
+pub struct QuotesClient<T: transport::Transport> {
+    transport: T,
+    endpoint: String,
+}
+
+impl<T: transport::Transport> QuotesClient<T> {
+    pub fn new(transport: T, endpoint: &str) -> Self {
+        Self { transport, endpoint: endpoint.to_string() }
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    pub async fn get_quote(
+        &self,
+        currency: String,
+        symbol: String
+    ) -> Result<Quote, transport::Error> {
+        let operation = transport::SoapOperation {
+            version: transport::SoapVersion::Soap11,
+            action: Some("http://example.com/quotes/GetQuote"),
+        };
+        transport::call(&self.transport, &self.endpoint, &operation, &GetQuoteInput { symbol, currency }).await.map(|output: GetQuoteOutput| output.quote)
+    }
+
+    pub async fn get_range(
+        &self,
+        symbol: String
+    ) -> Result<GetRangeOutput, transport::Error> {
+        let operation = transport::SoapOperation {
+            version: transport::SoapVersion::Soap11,
+            action: Some("http://example.com/quotes/GetRange"),
+        };
+        transport::call(&self.transport, &self.endpoint, &operation, &GetRangeInput { symbol }).await
+    }
+}
+
+/// Request of the operation `GetQuote`, in RPC style.
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(rename = "GetQuote", prefix = "tns", namespace = "tns: http://example.com/quotes")]
+pub struct GetQuoteInput {
+    #[yaserde(rename = "symbol")]
+    pub symbol: String,
+    #[yaserde(rename = "currency")]
+    pub currency: String,
+}
+
+/// Response of the operation `GetQuote`, in RPC style.
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(rename = "GetQuoteResponse", prefix = "tns", namespace = "tns: http://example.com/quotes")]
+pub struct GetQuoteOutput {
+    #[yaserde(rename = "quote")]
+    pub quote: Quote,
+}
+
+/// Request of the operation `GetRange`, in RPC style.
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(rename = "GetRange", prefix = "tns", namespace = "tns: http://example.com/quotes")]
+pub struct GetRangeInput {
+    #[yaserde(rename = "symbol")]
+    pub symbol: String,
+}
+
+/// Response of the operation `GetRange`, in RPC style.
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(rename = "GetRangeResponse", prefix = "tns", namespace = "tns: http://example.com/quotes")]
+pub struct GetRangeOutput {
+    #[yaserde(rename = "low")]
+    pub low: f64,
+    #[yaserde(rename = "high")]
+    pub high: f64,
+}
+
+/// Server of the port type `Quotes`.
+pub trait QuotesServer {
+    fn get_quote(
+        &self,
+        currency: String,
+        symbol: String
+    ) -> impl std::future::Future<Output = Result<Quote, transport::Fault>>;
+
+    fn get_range(
+        &self,
+        symbol: String
+    ) -> impl std::future::Future<Output = Result<GetRangeOutput, transport::Fault>>;
+
+    /// Dispatches the SOAP request `envelope`, sent with the action `action`, to the operation
+    /// it invokes and returns the response.
+    fn dispatch(
+        &self,
+        envelope: &[u8],
+        action: Option<&str>,
+    ) -> impl std::future::Future<Output = transport::Response> {
+        async move {
+            let request = match transport::ServerRequest::parse(envelope, action) {
+                Ok(request) => request,
+                Err(response) => return response,
+            };
+            let operations = [
+                transport::ServedOperation {
+                    name: "GetQuote",
+                    action: Some("http://example.com/quotes/GetQuote"),
+                    namespace: Some("http://example.com/quotes"),
+                    element: "GetQuote",
+                },
+                transport::ServedOperation {
+                    name: "GetRange",
+                    action: Some("http://example.com/quotes/GetRange"),
+                    namespace: Some("http://example.com/quotes"),
+                    element: "GetRange",
+                },
+            ];
+            match request.operation(&operations) {
+                Some("GetQuote") => match request.body::<GetQuoteInput>() {
+                    Ok(body) => request.respond(self.get_quote(body.currency, body.symbol).await.map(|quote| GetQuoteOutput { quote })),
+                    Err(response) => response,
+                },
+                Some("GetRange") => match request.body::<GetRangeInput>() {
+                    Ok(body) => request.respond(self.get_range(body.symbol).await),
+                    Err(response) => response,
+                },
+                _ => request.unknown_operation(),
+            }
+        }
+    }
+}
@@ -0,0 +1,69 @@
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct GetForecast {
+    #[yaserde(prefix = "tns", rename = "City")]
+    pub city: String,
+}
+
+impl Validate for GetForecast {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct GetForecastResponse {
+    #[yaserde(prefix = "tns", rename = "Forecast")]
+    pub forecast: String,
+}
+
+impl Validate for GetForecastResponse {}
+
+
+
+/// Client of the port type `Weather`.
+pub struct WeatherClient<T: transport::Transport> {
+    transport: T,
+    endpoint: String,
+}
+
+impl<T: transport::Transport> WeatherClient<T> {
+    pub fn new(transport: T, endpoint: &str) -> Self {
+        Self { transport, endpoint: endpoint.to_string() }
+    }
+
+    /// Client of the port `WeatherPort` of the service `WeatherService`.
+    pub fn with_default_endpoint(transport: T) -> Self {
+        Self::new(transport, services::weather_service::weather_port::ADDRESS)
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    pub async fn get_forecast(
+        &self,
+        request: &GetForecast
+    ) -> Result<GetForecastResponse, transport::Error> {
+        let operation = transport::SoapOperation {
+            version: transport::SoapVersion::Soap12,
+            action: Some("http://example.com/weather/GetForecast"),
+        };
+        transport::call(&self.transport, &self.endpoint, &operation, request).await
+    }
+}
+
+pub mod services {
+    pub mod weather_service {
+        pub const NAME: &str = "WeatherService";
+
+        pub mod weather_port {
+            pub const NAME: &str = "WeatherPort";
+            pub const BINDING: &str = "WeatherBinding";
+            pub const PORT_TYPE: &str = "Weather";
+            pub const ADDRESS: &str = "http://example.com/weather/service";
+        }
+    }
+}
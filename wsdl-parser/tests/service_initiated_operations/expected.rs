@@ -0,0 +1,178 @@
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/alarms")]
+pub struct Subscribe {
+    #[yaserde(prefix = "tns", rename = "Zone")]
+    pub zone: String,
+}
+
+impl Validate for Subscribe {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/alarms")]
+pub struct SubscribeResponse {
+    #[yaserde(prefix = "tns", rename = "SubscriptionId")]
+    pub subscription_id: String,
+}
+
+impl Validate for SubscribeResponse {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/alarms")]
+pub struct AlarmRaised {
+    #[yaserde(prefix = "tns", rename = "AlarmId")]
+    pub alarm_id: String,
+}
+
+impl Validate for AlarmRaised {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/alarms")]
+pub struct ConfirmAlarm {
+    #[yaserde(prefix = "tns", rename = "AlarmId")]
+    pub alarm_id: String,
+}
+
+impl Validate for ConfirmAlarm {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/alarms")]
+pub struct ConfirmAlarmResponse {
+    #[yaserde(prefix = "tns", rename = "Confirmed")]
+    pub confirmed: bool,
+}
+
+impl Validate for ConfirmAlarmResponse {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/alarms")]
+pub struct AlarmUnknown {
+    #[yaserde(prefix = "tns", rename = "AlarmId")]
+    pub alarm_id: String,
+}
+
+impl Validate for AlarmUnknown {}
+
+
+
+/// Client of the port type `Alarms`.
+pub struct AlarmsClient<T: transport::Transport> {
+    transport: T,
+    endpoint: String,
+}
+
+impl<T: transport::Transport> AlarmsClient<T> {
+    pub fn new(transport: T, endpoint: &str) -> Self {
+        Self { transport, endpoint: endpoint.to_string() }
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    pub async fn subscribe(
+        &self,
+        request: &Subscribe
+    ) -> Result<SubscribeResponse, transport::Error> {
+        let operation = transport::SoapOperation {
+            version: transport::SoapVersion::Soap11,
+            action: Some("http://example.com/alarms/Subscribe"),
+        };
+        transport::call(&self.transport, &self.endpoint, &operation, request).await
+    }
+}
+
+/// Handler of the operations the port type `Alarms` initiates.
+pub trait AlarmsHandler {
+    /// Sent when an alarm of a subscribed zone is raised.
+    fn alarm_raised(
+        &self,
+        request: AlarmRaised
+    ) -> impl std::future::Future<Output = Result<(), transport::Fault>>;
+
+    fn confirm_alarm(
+        &self,
+        request: ConfirmAlarm
+    ) -> impl std::future::Future<Output = Result<ConfirmAlarmResponse, ConfirmAlarmFault>>;
+
+    /// Dispatches the SOAP request `envelope`, sent with the action `action`, to the operation
+    /// it invokes and returns the response.
+    fn dispatch(
+        &self,
+        envelope: &[u8],
+        action: Option<&str>,
+    ) -> impl std::future::Future<Output = transport::Response> {
+        async move {
+            let request = match transport::ServerRequest::parse(envelope, action) {
+                Ok(request) => request,
+                Err(response) => return response,
+            };
+            let operations = [
+                transport::ServedOperation {
+                    name: "AlarmRaised",
+                    action: Some("http://example.com/alarms/AlarmRaised"),
+                    namespace: Some("http://example.com/alarms"),
+                    element: "AlarmRaised",
+                },
+                transport::ServedOperation {
+                    name: "ConfirmAlarm",
+                    action: Some("http://example.com/alarms/ConfirmAlarm"),
+                    namespace: Some("http://example.com/alarms"),
+                    element: "ConfirmAlarm",
+                },
+            ];
+            match request.operation(&operations) {
+                Some("AlarmRaised") => match request.body() {
+                    Ok(body) => request.acknowledge(self.alarm_raised(body).await),
+                    Err(response) => response,
+                },
+                Some("ConfirmAlarm") => match request.body() {
+                    Ok(body) => request.respond(self.confirm_alarm(body).await),
+                    Err(response) => response,
+                },
+                _ => request.unknown_operation(),
+            }
+        }
+    }
+}
+
+/// Faults of the operation `ConfirmAlarm`.
+#[derive(Debug, PartialEq)]
+pub enum ConfirmAlarmFault {
+    AlarmUnknownFault(AlarmUnknown),
+    /// A fault the operation does not declare.
+    Other(transport::Fault),
+}
+
+impl transport::OperationFault for ConfirmAlarmFault {
+    fn from_fault(fault: transport::Fault) -> Self {
+        if let Some(detail) = fault.detail_as(Some("http://example.com/alarms"), "AlarmUnknown") {
+            return ConfirmAlarmFault::AlarmUnknownFault(detail);
+        }
+        ConfirmAlarmFault::Other(fault)
+    }
+
+    fn into_fault(self) -> transport::Fault {
+        match self {
+            ConfirmAlarmFault::AlarmUnknownFault(detail) => transport::Fault::with_detail("AlarmUnknownFault", &detail),
+            ConfirmAlarmFault::Other(fault) => fault,
+        }
+    }
+}
+
+impl std::fmt::Display for ConfirmAlarmFault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfirmAlarmFault::AlarmUnknownFault(detail) => write!(f, "SOAP fault AlarmUnknownFault: {:?}", detail),
+            ConfirmAlarmFault::Other(fault) => write!(f, "{}", fault),
+        }
+    }
+}
@@ -0,0 +1,207 @@
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct Forecast {
+    #[yaserde(prefix = "tns", rename = "Low")]
+    pub low: f64,
+
+    #[yaserde(prefix = "tns", rename = "High")]
+    pub high: f64,
+}
+
+impl Validate for Forecast {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct GetTemperature {
+    #[yaserde(prefix = "tns", rename = "City")]
+    pub city: String,
+
+    #[yaserde(prefix = "tns", rename = "Country")]
+    pub country: Option<String>,
+}
+
+impl Validate for GetTemperature {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct GetTemperatureResponse {
+    #[yaserde(prefix = "tns", rename = "Temperature")]
+    pub temperature: f64,
+}
+
+impl Validate for GetTemperatureResponse {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct GetForecasts {
+    #[yaserde(prefix = "tns", rename = "City")]
+    pub city: String,
+
+    #[yaserde(prefix = "tns", rename = "Days")]
+    pub days: i32,
+}
+
+impl Validate for GetForecasts {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct GetForecastsResponse {
+    #[yaserde(prefix = "tns", rename = "Forecast")]
+    pub forecast: Vec<Forecast>,
+
+    #[yaserde(prefix = "tns", rename = "Source")]
+    pub source: String,
+}
+
+impl Validate for GetForecastsResponse {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct Reset {}
+
+impl Validate for Reset {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct ResetResponse {}
+
+impl Validate for ResetResponse {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct Subscribe {
+    #[yaserde(prefix = "tns", rename = "Address")]
+    pub address: String,
+
+    #[yaserde(attribute, rename = "Duration")]
+    pub duration: Option<xs::Duration>,
+}
+
+impl Validate for Subscribe {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct SubscribeResponse {}
+
+impl Validate for SubscribeResponse {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/weather")]
+pub struct Report {
+    #[yaserde(prefix = "tns", rename = "Text")]
+    pub text: String,
+}
+
+impl Validate for Report {}
+
+
+
+/// Client of the port type `Weather`.
+pub struct WeatherClient<T: transport::Transport> {
+    transport: T,
+    endpoint: String,
+}
+
+impl<T: transport::Transport> WeatherClient<T> {
+    pub fn new(transport: T, endpoint: &str) -> Self {
+        Self { transport, endpoint: endpoint.to_string() }
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    pub async fn get_temperature(
+        &self,
+        request: &GetTemperature
+    ) -> Result<GetTemperatureResponse, transport::Error> {
+        let operation = transport::SoapOperation {
+            version: transport::SoapVersion::Soap11,
+            action: Some("http://example.com/weather/GetTemperature"),
+        };
+        transport::call(&self.transport, &self.endpoint, &operation, request).await
+    }
+
+    /// Calls `get_temperature` with the children of its request element.
+    /// Returns the child of its response element.
+    pub async fn get_temperature_unwrapped(
+        &self,
+        city: String,
+        country: Option<String>
+    ) -> Result<f64, transport::Error> {
+        self.get_temperature(&GetTemperature { city, country }).await.map(|response| response.temperature)
+    }
+
+    pub async fn get_forecasts(
+        &self,
+        request: &GetForecasts
+    ) -> Result<GetForecastsResponse, transport::Error> {
+        let operation = transport::SoapOperation {
+            version: transport::SoapVersion::Soap11,
+            action: Some("http://example.com/weather/GetForecasts"),
+        };
+        transport::call(&self.transport, &self.endpoint, &operation, request).await
+    }
+
+    /// Calls `get_forecasts` with the children of its request element.
+    pub async fn get_forecasts_unwrapped(
+        &self,
+        city: String,
+        days: i32
+    ) -> Result<GetForecastsResponse, transport::Error> {
+        self.get_forecasts(&GetForecasts { city, days }).await
+    }
+
+    pub async fn reset(
+        &self,
+        request: &Reset
+    ) -> Result<ResetResponse, transport::Error> {
+        let operation = transport::SoapOperation {
+            version: transport::SoapVersion::Soap11,
+            action: Some("http://example.com/weather/Reset"),
+        };
+        transport::call(&self.transport, &self.endpoint, &operation, request).await
+    }
+
+    /// Calls `reset` with the children of its request element.
+    pub async fn reset_unwrapped(
+        &self
+    ) -> Result<(), transport::Error> {
+        self.reset(&Reset {}).await.map(|_| ())
+    }
+
+    pub async fn subscribe(
+        &self,
+        request: &Subscribe
+    ) -> Result<SubscribeResponse, transport::Error> {
+        let operation = transport::SoapOperation {
+            version: transport::SoapVersion::Soap11,
+            action: Some("http://example.com/weather/Subscribe"),
+        };
+        transport::call(&self.transport, &self.endpoint, &operation, request).await
+    }
+
+    pub async fn send_report(
+        &self,
+        request: &Report
+    ) -> Result<(), transport::Error> {
+        let operation = transport::SoapOperation {
+            version: transport::SoapVersion::Soap11,
+            action: Some("http://example.com/weather/SendReport"),
+        };
+        transport::send(&self.transport, &self.endpoint, &operation, request).await
+    }
+}
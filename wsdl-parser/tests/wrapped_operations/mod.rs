@@ -0,0 +1,28 @@
+use wsdl_parser::generator::{OperationStyle, Options};
+
+use super::{ast_test_with, generate_wsdl_with};
+
+fn options() -> Options {
+    Options { operations: OperationStyle::Clients, unwrapped: true, ..Default::default() }
+}
+
+#[test]
+fn generator_does_not_panic() {
+    println!("{}", generate_wsdl_with(include_str!("input.xsd"), &options()))
+}
+
+#[test]
+fn generator_output_has_correct_ast() {
+    ast_test_with(include_str!("input.xsd"), include_str!("expected.rs"), &options());
+}
+
+// The generated code compiles against the runtime.
+#[allow(dead_code)]
+mod expected {
+    use wsdl_runtime as transport;
+    use xsd_parser::generator::validator::Validate;
+    use xsd_types::types as xs;
+    use yaserde_derive::{YaDeserialize, YaSerialize};
+
+    include!("expected.rs");
+}
@@ -0,0 +1,71 @@
+//use types.xsd  http://example.com/types;
+//use http://example.com/remote.xsd  http://example.com/remote;
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/shop")]
+pub struct GetAddress {
+    #[yaserde(prefix = "tns", rename = "CustomerId")]
+    pub customer_id: String,
+}
+
+impl Validate for GetAddress {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com/shop")]
+pub struct GetAddressResponse {
+    #[yaserde(prefix = "tns", rename = "Address")]
+    pub address: typ::Address,
+}
+
+impl Validate for GetAddressResponse {}
+
+
+
+/// Client of the port type `Shop`.
+pub struct ShopClient<T: transport::Transport> {
+    transport: T,
+    endpoint: String,
+}
+
+impl<T: transport::Transport> ShopClient<T> {
+    pub fn new(transport: T, endpoint: &str) -> Self {
+        Self { transport, endpoint: endpoint.to_string() }
+    }
+
+    /// Client of the port `ShopPort` of the service `ShopService`.
+    pub fn with_default_endpoint(transport: T) -> Self {
+        Self::new(transport, services::shop_service::shop_port::ADDRESS)
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    pub async fn get_address(
+        &self,
+        request: &GetAddress
+    ) -> Result<GetAddressResponse, transport::Error> {
+        let operation = transport::SoapOperation {
+            version: transport::SoapVersion::Soap11,
+            action: Some("http://example.com/shop/GetAddress"),
+        };
+        transport::call(&self.transport, &self.endpoint, &operation, request).await
+    }
+}
+
+pub mod services {
+    pub mod shop_service {
+        pub const NAME: &str = "ShopService";
+
+        pub mod shop_port {
+            pub const NAME: &str = "ShopPort";
+            pub const BINDING: &str = "ShopBinding";
+            pub const PORT_TYPE: &str = "Shop";
+            pub const ADDRESS: &str = "http://example.com/shop";
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use super::*;
+
+//use country.xsd  ;
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "typ", namespace = "typ: http://example.com/types")]
+pub struct Address {
+    #[yaserde(prefix = "typ", rename = "Street")]
+    pub street: String,
+
+    #[yaserde(prefix = "typ", rename = "Country")]
+    pub country: Country,
+}
+
+impl Validate for Address {}
+
+
+#[derive(Default, PartialEq, Debug, UtilsTupleIo, UtilsDefaultSerde)]
+pub struct Country (pub String);
+
+impl Validate for Country {
+    fn validate(&self) -> Result<(), String> { 
+        if self.0.len() != 2 {
+            return Err(format!("Length validation error. \nExpected: 0 length == 2 \nActual: 0 length == {}", self.0.len()));
+        }
+        Ok(())
+    }
+}
+
@@ -0,0 +1,101 @@
+use std::path::{Path, PathBuf};
+
+use wsdl_parser::{
+    generator::{OperationStyle, Options},
+    project::{generate_schemas, Project, SourceFile},
+};
+use xsd_parser::generator::naming::{DefaultNamingStrategy, OriginalNamingStrategy};
+
+use super::assert_ast_eq;
+
+fn test_case_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("wsdl_imports")
+}
+
+#[test]
+fn imported_files_are_loaded() {
+    let dir = test_case_dir();
+    let project = Project::load(&dir.join("input.xsd")).unwrap();
+
+    let paths = |files: &[SourceFile]| files.iter().map(|f| f.path.clone()).collect::<Vec<_>>();
+    assert_eq!(paths(&project.wsdl), vec![dir.join("input.xsd"), dir.join("interface.wsdl")]);
+    assert_eq!(paths(&project.schemas), vec![dir.join("types.xsd"), dir.join("country.xsd")]);
+}
+
+#[test]
+fn generator_output_has_correct_ast() {
+    let project = Project::load(&test_case_dir().join("input.xsd")).unwrap();
+    let options = Options { operations: OperationStyle::Clients, ..Default::default() };
+    let code = project.generate(&DefaultNamingStrategy, &options).unwrap();
+
+    assert_ast_eq(include_str!("expected.rs"), &code);
+}
+
+#[test]
+fn imported_schemas_are_generated_once_per_namespace() {
+    let project = Project::load(&test_case_dir().join("input.xsd")).unwrap();
+    let modules = project.generate_schemas(&DefaultNamingStrategy, &Options::default()).unwrap();
+
+    assert_eq!(modules.len(), 1);
+    assert_eq!(modules[0].name, "typ");
+    assert_ast_eq(include_str!("expected/typ.rs"), &modules[0].code);
+}
+
+#[test]
+fn namespaces_named_alike_are_told_apart() {
+    let schema = |path: &str, namespace: &str| SourceFile {
+        path: PathBuf::from(path),
+        text: format!(
+            r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"
+                         xmlns:ns="{0}" targetNamespace="{0}">
+                <xs:complexType name="{1}"/>
+            </xs:schema>"#,
+            namespace,
+            path.trim_end_matches(".xsd")
+        ),
+    };
+    let schemas = [
+        schema("Person.xsd", "http://example.com/people"),
+        schema("Company.xsd", "http://example.com/companies"),
+        schema("Employee.xsd", "http://example.com/people"),
+    ];
+
+    let modules = generate_schemas(&schemas, &DefaultNamingStrategy, &Options::default()).unwrap();
+
+    let names = modules.iter().map(|m| m.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["ns", "ns2"]);
+    assert!(
+        modules[0].code.contains("pub struct Person")
+            && modules[0].code.contains("pub struct Employee")
+    );
+    assert!(modules[1].code.contains("pub struct Company"));
+}
+
+#[test]
+fn naming_applies_to_all_types() {
+    let project = Project::load(&test_case_dir().join("input.xsd")).unwrap();
+    let options = Options::default();
+
+    let code = project.generate(&OriginalNamingStrategy, &options).unwrap();
+    assert!(code.contains("pub CustomerId: String,"), "{}", code);
+
+    let modules = project.generate_schemas(&OriginalNamingStrategy, &options).unwrap();
+    assert!(modules[0].code.contains("pub Street: String,"), "{}", modules[0].code);
+}
+
+// The generated code compiles against the runtime.
+#[allow(dead_code)]
+mod expected {
+    use std::str::FromStr;
+
+    use wsdl_runtime as transport;
+    use xsd_macro_utils::*;
+    use xsd_parser::generator::validator::Validate;
+    use yaserde_derive::{YaDeserialize, YaSerialize};
+
+    include!("expected.rs");
+
+    mod typ {
+        include!("expected/typ.rs");
+    }
+}
@@ -0,0 +1,192 @@
+use xml::{
+    reader::XmlEvent,
+    writer::{EmitterConfig, EventWriter},
+};
+use yaserde::{de::Deserializer, ser, YaDeserialize, YaSerialize};
+
+use crate::{Error, Fault, SoapVersion, SOAP_11_ENVELOPE_NS, SOAP_12_ENVELOPE_NS};
+
+/// Envelope of a request whose body is `request`.
+pub fn write_request<Req: YaSerialize>(
+    version: SoapVersion,
+    request: &Req,
+) -> Result<String, Error> {
+    Ok(envelope(version, &to_xml(request)?))
+}
+
+// The envelope whose body is the XML `body`.
+pub(crate) fn envelope(version: SoapVersion, body: &str) -> String {
+    format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+         <s:Envelope xmlns:s=\"{}\"><s:Body>{}</s:Body></s:Envelope>",
+        version.envelope_namespace(),
+        body
+    )
+}
+
+// XML of `value`, without a document declaration.
+pub(crate) fn to_xml<T: YaSerialize>(value: &T) -> Result<String, Error> {
+    let config = ser::Config { write_document_declaration: false, ..Default::default() };
+    ser::to_string_with_config(value, &config).map_err(Error::Serialization)
+}
+
+/// Content of the body of the response `envelope`, of either SOAP version.
+pub fn parse_response<Resp: YaDeserialize>(envelope: &str) -> Result<Resp, Error> {
+    let mut de = Deserializer::new_from_reader(envelope.as_bytes());
+    let ns = Some(open_body(&mut de)?.envelope_namespace().to_string());
+
+    match peek(&mut de)? {
+        XmlEvent::StartElement { name, .. }
+            if name.local_name == "Fault" && name.namespace == ns =>
+        {
+            Err(Error::Fault(read_fault(&mut de)?))
+        }
+        _ => Resp::deserialize(&mut de).map_err(Error::Deserialization),
+    }
+}
+
+/// Checks the response `envelope` of a one-way operation: empty, or with a body that is not a
+/// `soap:Fault`.
+pub fn parse_acknowledgement(envelope: &str) -> Result<(), Error> {
+    if envelope.trim().is_empty() {
+        return Ok(());
+    }
+    let mut de = Deserializer::new_from_reader(envelope.as_bytes());
+    let ns = Some(open_body(&mut de)?.envelope_namespace().to_string());
+
+    match peek(&mut de)? {
+        XmlEvent::StartElement { name, .. }
+            if name.local_name == "Fault" && name.namespace == ns =>
+        {
+            Err(Error::Fault(read_fault(&mut de)?))
+        }
+        _ => Ok(()),
+    }
+}
+
+// Reads the envelope up to the content of its body and returns its version. The header is
+// skipped.
+pub(crate) fn open_body<R: std::io::Read>(de: &mut Deserializer<R>) -> Result<SoapVersion, Error> {
+    let event = next(de)?;
+    let version = match &event {
+        XmlEvent::StartElement { name, .. } if name.local_name == "Envelope" => {
+            match name.namespace.as_deref() {
+                Some(SOAP_11_ENVELOPE_NS) => Some(SoapVersion::Soap11),
+                Some(SOAP_12_ENVELOPE_NS) => Some(SoapVersion::Soap12),
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+    let version = version.ok_or_else(|| unexpected("a SOAP envelope", &event))?;
+    let ns = Some(version.envelope_namespace().to_string());
+
+    loop {
+        match peek(de)? {
+            XmlEvent::StartElement { name, .. }
+                if name.local_name == "Body" && name.namespace == ns =>
+            {
+                next(de)?;
+                return Ok(version);
+            }
+            XmlEvent::StartElement { .. } => {
+                next(de)?;
+                de.skip_element(|_| {}).map_err(Error::Deserialization)?;
+            }
+            XmlEvent::EndElement { .. } | XmlEvent::EndDocument => {
+                return Err(Error::Deserialization("no body in the envelope".to_string()))
+            }
+            _ => {
+                next(de)?;
+            }
+        }
+    }
+}
+
+// Reads a `Fault` element of either SOAP version. Elements are matched by local name, the
+// ones of SOAP 1.1 being unqualified.
+fn read_fault<R: std::io::Read>(de: &mut Deserializer<R>) -> Result<Fault, Error> {
+    next(de)?;
+    let mut fault = Fault::default();
+    let mut path: Vec<String> = vec![];
+    loop {
+        let event = next(de)?;
+        match event {
+            XmlEvent::StartElement { ref name, .. } => {
+                if fault.detail.is_none()
+                    && path.len() == 1
+                    && path[0].eq_ignore_ascii_case("detail")
+                {
+                    fault.detail = Some(copy_element(de, &event)?);
+                    continue;
+                }
+                path.push(name.local_name.clone());
+            }
+            XmlEvent::EndElement { .. } => {
+                // The end of the fault itself.
+                if path.is_empty() {
+                    return Ok(fault);
+                }
+                path.pop();
+            }
+            XmlEvent::Characters(text) => {
+                let path = path.iter().map(String::as_str).collect::<Vec<_>>();
+                match path.as_slice() {
+                    ["faultcode"] | ["Code", "Value"] => fault.code = text,
+                    ["faultstring"] => fault.reason = text,
+                    ["Reason", "Text"] if fault.reason.is_empty() => fault.reason = text,
+                    ["Code", subcodes @ .., "Value"]
+                        if subcodes.iter().all(|s| *s == "Subcode") =>
+                    {
+                        fault.subcodes.push(text)
+                    }
+                    _ => {}
+                }
+            }
+            XmlEvent::EndDocument => {
+                return Err(Error::Deserialization("unterminated fault".to_string()))
+            }
+            _ => {}
+        }
+    }
+}
+
+// XML of the element started by `start`, whose other events are read from `de`.
+pub(crate) fn copy_element<R: std::io::Read>(
+    de: &mut Deserializer<R>,
+    start: &XmlEvent,
+) -> Result<String, Error> {
+    let config = EmitterConfig::new().write_document_declaration(false);
+    let mut writer = EventWriter::new_with_config(Vec::new(), config);
+    let mut write = |event: &XmlEvent| match event.as_writer_event() {
+        Some(event) => writer.write(event).map_err(|e| Error::Deserialization(e.to_string())),
+        None => Ok(()),
+    };
+    write(start)?;
+    let mut depth = 1;
+    while depth > 0 {
+        let event = next(de)?;
+        match event {
+            XmlEvent::StartElement { .. } => depth += 1,
+            XmlEvent::EndElement { .. } => depth -= 1,
+            XmlEvent::EndDocument => {
+                return Err(Error::Deserialization("unterminated fault detail".to_string()))
+            }
+            _ => {}
+        }
+        write(&event)?;
+    }
+    String::from_utf8(writer.into_inner()).map_err(|e| Error::Deserialization(e.to_string()))
+}
+
+pub(crate) fn next<R: std::io::Read>(de: &mut Deserializer<R>) -> Result<XmlEvent, Error> {
+    de.next_event().map_err(Error::Deserialization)
+}
+
+pub(crate) fn peek<R: std::io::Read>(de: &mut Deserializer<R>) -> Result<&XmlEvent, Error> {
+    de.peek().map_err(Error::Deserialization)
+}
+
+pub(crate) fn unexpected(expected: &str, event: &XmlEvent) -> Error {
+    Error::Deserialization(format!("expected {}, found {:?}", expected, event))
+}
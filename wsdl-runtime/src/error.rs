@@ -0,0 +1,108 @@
+use std::fmt;
+
+use xml::reader::XmlEvent;
+use yaserde::{de::Deserializer, YaDeserialize, YaSerialize};
+
+use crate::envelope::to_xml;
+
+/// Errors of an operation, whose declared faults are decoded as `F`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error<F = Fault> {
+    /// The message could not be sent or no response was received.
+    Transport(String),
+    /// The request could not be written.
+    Serialization(String),
+    /// The response is not a SOAP envelope or its body is not the expected one.
+    Deserialization(String),
+    /// The response is a `soap:Fault`.
+    Fault(F),
+}
+
+impl Error {
+    /// Decodes the fault of the error as one of the faults of an operation.
+    pub fn decode_fault<F: OperationFault>(self) -> Error<F> {
+        match self {
+            Error::Transport(e) => Error::Transport(e),
+            Error::Serialization(e) => Error::Serialization(e),
+            Error::Deserialization(e) => Error::Deserialization(e),
+            Error::Fault(fault) => Error::Fault(F::from_fault(fault)),
+        }
+    }
+}
+
+/// Content of a `soap:Fault`, of either SOAP version.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Fault {
+    /// `faultcode` in SOAP 1.1, `Code/Value` in SOAP 1.2, e.g. `s:Sender`. Servers send the
+    /// code of the receiver when it is empty.
+    pub code: String,
+    /// Values of the nested `Subcode` elements of SOAP 1.2, e.g. `ter:InvalidArgVal`.
+    pub subcodes: Vec<String>,
+    /// `faultstring` in SOAP 1.1, the first `Reason/Text` in SOAP 1.2.
+    pub reason: String,
+    /// The first element of the detail, with the namespaces it uses declared.
+    pub detail: Option<String>,
+}
+
+impl Fault {
+    /// A fault of the receiver whose detail is `detail`. The fault is sent without detail
+    /// when `detail` cannot be serialized.
+    pub fn with_detail<T: YaSerialize>(reason: &str, detail: &T) -> Self {
+        Fault { reason: reason.to_string(), detail: to_xml(detail).ok(), ..Default::default() }
+    }
+
+    /// Deserializes the detail when it is the element `name` of `namespace`.
+    pub fn detail_as<T: YaDeserialize>(&self, namespace: Option<&str>, name: &str) -> Option<T> {
+        let mut de = Deserializer::new_from_reader(self.detail.as_ref()?.as_bytes());
+        match de.peek().ok()? {
+            XmlEvent::StartElement { name: element, .. }
+                if element.local_name == name && element.namespace.as_deref() == namespace =>
+            {
+                T::deserialize(&mut de).ok()
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The faults an operation declares with `wsdl:fault`, decoded from the detail of a
+/// `soap:Fault` by clients and encoded into it by servers. Generated per operation as an enum
+/// whose `Other` variant keeps the faults none of the declared ones matches.
+pub trait OperationFault: Sized {
+    fn from_fault(fault: Fault) -> Self;
+
+    fn into_fault(self) -> Fault;
+}
+
+impl OperationFault for Fault {
+    fn from_fault(fault: Fault) -> Self {
+        fault
+    }
+
+    fn into_fault(self) -> Fault {
+        self
+    }
+}
+
+impl<F: fmt::Display> fmt::Display for Error<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Transport(e) => write!(f, "transport error: {}", e),
+            Error::Serialization(e) => write!(f, "cannot write request: {}", e),
+            Error::Deserialization(e) => write!(f, "cannot read response: {}", e),
+            Error::Fault(fault) => write!(f, "{}", fault),
+        }
+    }
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SOAP fault {}", self.code)?;
+        for subcode in &self.subcodes {
+            write!(f, " / {}", subcode)?;
+        }
+        write!(f, ": {}", self.reason)
+    }
+}
+
+impl<F: fmt::Debug + fmt::Display> std::error::Error for Error<F> {}
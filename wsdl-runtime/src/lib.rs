@@ -0,0 +1,646 @@
+//! Runtime of the code `wsdl-parser` generates: the `Transport` trait of clients, the requests
+//! services dispatch, and the SOAP envelopes of both. Generated code refers to it as
+//! `transport`:
+//!
+//! ```ignore
+//! use wsdl_runtime as transport;
+//! ```
+
+mod envelope;
+mod error;
+mod server;
+
+use std::future::Future;
+
+use yaserde::{YaDeserialize, YaSerialize};
+
+pub use crate::{
+    envelope::{parse_acknowledgement, parse_response, write_request},
+    error::{Error, Fault, OperationFault},
+    server::{write_fault, Response, ServedOperation, ServerRequest},
+};
+
+pub const SOAP_11_ENVELOPE_NS: &str = "http://schemas.xmlsoap.org/soap/envelope/";
+pub const SOAP_12_ENVELOPE_NS: &str = "http://www.w3.org/2003/05/soap-envelope";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoapVersion {
+    Soap11,
+    Soap12,
+}
+
+impl SoapVersion {
+    pub fn envelope_namespace(self) -> &'static str {
+        match self {
+            SoapVersion::Soap11 => SOAP_11_ENVELOPE_NS,
+            SoapVersion::Soap12 => SOAP_12_ENVELOPE_NS,
+        }
+    }
+
+    /// Content type of messages, which carries the action in SOAP 1.2.
+    pub fn content_type(self, action: Option<&str>) -> String {
+        match (self, action) {
+            (SoapVersion::Soap11, _) => "text/xml; charset=utf-8".to_string(),
+            (SoapVersion::Soap12, None) => "application/soap+xml; charset=utf-8".to_string(),
+            (SoapVersion::Soap12, Some(action)) => {
+                format!("application/soap+xml; charset=utf-8; action=\"{}\"", action)
+            }
+        }
+    }
+}
+
+/// How an operation is sent, as its binding describes it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SoapOperation<'a> {
+    pub version: SoapVersion,
+    pub action: Option<&'a str>,
+}
+
+/// A SOAP message to send over HTTP or any other protocol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    /// Address of the port, empty when the transport knows where to send messages.
+    pub endpoint: String,
+    pub content_type: String,
+    /// Value of the `SOAPAction` HTTP header, set for SOAP 1.1 only.
+    pub soap_action: Option<String>,
+    pub envelope: String,
+}
+
+/// Sends SOAP messages and returns the envelopes of the responses, faults included.
+pub trait Transport {
+    fn send(&self, request: Request) -> impl Future<Output = Result<String, Error>>;
+}
+
+impl<T: Transport> Transport for &T {
+    fn send(&self, request: Request) -> impl Future<Output = Result<String, Error>> {
+        (**self).send(request)
+    }
+}
+
+/// Sends `request` to `endpoint` as `operation` describes it and returns the content of the
+/// body of the response. A `soap:Fault` in the response is returned as `Error::Fault`, decoded
+/// as one of the faults of the operation.
+pub async fn call<T, Req, Resp, F>(
+    transport: &T,
+    endpoint: &str,
+    operation: &SoapOperation<'_>,
+    request: &Req,
+) -> Result<Resp, Error<F>>
+where
+    T: Transport,
+    Req: YaSerialize,
+    Resp: YaDeserialize,
+    F: OperationFault,
+{
+    let request = soap_request(endpoint, operation, request).map_err(Error::decode_fault)?;
+    let envelope = transport.send(request).await.map_err(Error::decode_fault)?;
+    parse_response(&envelope).map_err(Error::decode_fault)
+}
+
+/// Sends `request` to `endpoint` as `operation` describes it, for one-way operations. Servers
+/// acknowledge these with an empty response, whose body, if any, is not read but for a
+/// `soap:Fault`, returned as `Error::Fault`.
+pub async fn send<T, Req, F>(
+    transport: &T,
+    endpoint: &str,
+    operation: &SoapOperation<'_>,
+    request: &Req,
+) -> Result<(), Error<F>>
+where
+    T: Transport,
+    Req: YaSerialize,
+    F: OperationFault,
+{
+    let request = soap_request(endpoint, operation, request).map_err(Error::decode_fault)?;
+    let envelope = transport.send(request).await.map_err(Error::decode_fault)?;
+    parse_acknowledgement(&envelope).map_err(Error::decode_fault)
+}
+
+fn soap_request<Req: YaSerialize>(
+    endpoint: &str,
+    operation: &SoapOperation<'_>,
+    request: &Req,
+) -> Result<Request, Error> {
+    Ok(Request {
+        endpoint: endpoint.to_string(),
+        content_type: operation.version.content_type(operation.action),
+        soap_action: match operation.version {
+            SoapVersion::Soap11 => Some(format!("\"{}\"", operation.action.unwrap_or_default())),
+            SoapVersion::Soap12 => None,
+        },
+        envelope: write_request(operation.version, request)?,
+    })
+}
+
+/// Sends `request` with SOAP 1.2 and no action to the endpoint the transport knows, for the
+/// functions generated per operation.
+pub async fn request<T, Req, Resp, F>(transport: &T, request: &Req) -> Result<Resp, Error<F>>
+where
+    T: Transport,
+    Req: YaSerialize,
+    Resp: YaDeserialize,
+    F: OperationFault,
+{
+    let operation = SoapOperation { version: SoapVersion::Soap12, action: None };
+    call(transport, "", &operation, request).await
+}
+
+/// Sends `request` as `request` does, for one-way operations, see `send`.
+pub async fn send_request<T, Req, F>(transport: &T, request: &Req) -> Result<(), Error<F>>
+where
+    T: Transport,
+    Req: YaSerialize,
+    F: OperationFault,
+{
+    let operation = SoapOperation { version: SoapVersion::Soap12, action: None };
+    send(transport, "", &operation, request).await
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        future::Future,
+        pin::pin,
+        sync::Mutex,
+        task::{Context, Poll, Waker},
+    };
+
+    use self::messages::*;
+    use super::*;
+
+    // The bodies of the messages of the tests. `yaserde_derive` puts its impls in named
+    // constants, which the lint takes for impls nested in another item.
+    #[allow(non_local_definitions)]
+    mod messages {
+        use yaserde_derive::{YaDeserialize, YaSerialize};
+
+        #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+        #[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+        pub struct GetForecast {
+            #[yaserde(prefix = "tns", rename = "City")]
+            pub city: String,
+        }
+
+        #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+        #[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+        pub struct GetForecastResponse {
+            #[yaserde(prefix = "tns", rename = "Forecast")]
+            pub forecast: String,
+        }
+
+        #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+        #[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+        pub struct UnknownCity {
+            #[yaserde(prefix = "tns", rename = "City")]
+            pub city: String,
+        }
+
+        #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+        #[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+        pub struct ReportObservation {
+            #[yaserde(prefix = "tns", rename = "City")]
+            pub city: String,
+        }
+    }
+
+    // Returns `response` to every request, which it records.
+    struct MockTransport {
+        response: String,
+        requests: Mutex<Vec<Request>>,
+    }
+
+    impl MockTransport {
+        fn new(response: &str) -> Self {
+            Self { response: response.to_string(), requests: Mutex::new(vec![]) }
+        }
+    }
+
+    impl Transport for MockTransport {
+        async fn send(&self, request: Request) -> Result<String, Error> {
+            self.requests.lock().unwrap().push(request);
+            Ok(self.response.clone())
+        }
+    }
+
+    // The futures of the mock transport are ready at once.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("future is not ready"),
+        }
+    }
+
+    const RESPONSE: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+        <env:Envelope xmlns:env="http://www.w3.org/2003/05/soap-envelope"
+                      xmlns:tns="http://example.com">
+            <env:Header>
+                <tns:Session>1</tns:Session>
+            </env:Header>
+            <env:Body>
+                <tns:GetForecastResponse>
+                    <tns:Forecast>Sunny</tns:Forecast>
+                </tns:GetForecastResponse>
+            </env:Body>
+        </env:Envelope>"#;
+
+    #[test]
+    fn test_call_soap_12() {
+        let transport = MockTransport::new(RESPONSE);
+        let operation =
+            SoapOperation { version: SoapVersion::Soap12, action: Some("http://example.com/Get") };
+        let request = GetForecast { city: "Paris".to_string() };
+
+        let response: Result<GetForecastResponse, Error> =
+            block_on(call(&transport, "http://example.com/weather", &operation, &request));
+
+        assert_eq!(response.unwrap().forecast, "Sunny");
+
+        let requests = transport.requests.lock().unwrap();
+        assert_eq!(requests[0].endpoint, "http://example.com/weather");
+        assert_eq!(
+            requests[0].content_type,
+            "application/soap+xml; charset=utf-8; action=\"http://example.com/Get\""
+        );
+        assert_eq!(requests[0].soap_action, None);
+        assert_eq!(
+            requests[0].envelope,
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+             <s:Envelope xmlns:s=\"http://www.w3.org/2003/05/soap-envelope\"><s:Body>\
+             <tns:GetForecast xmlns:tns=\"http://example.com\"><tns:City>Paris</tns:City>\
+             </tns:GetForecast></s:Body></s:Envelope>"
+        );
+    }
+
+    #[test]
+    fn test_call_soap_11() {
+        let transport =
+            MockTransport::new(&RESPONSE.replace(SOAP_12_ENVELOPE_NS, SOAP_11_ENVELOPE_NS));
+        let operation = SoapOperation { version: SoapVersion::Soap11, action: None };
+
+        let response: Result<GetForecastResponse, Error> =
+            block_on(call(&transport, "", &operation, &GetForecast::default()));
+
+        assert_eq!(response.unwrap().forecast, "Sunny");
+
+        let requests = transport.requests.lock().unwrap();
+        assert_eq!(requests[0].content_type, "text/xml; charset=utf-8");
+        assert_eq!(requests[0].soap_action.as_deref(), Some("\"\""));
+        assert!(requests[0].envelope.contains(SOAP_11_ENVELOPE_NS));
+    }
+
+    #[test]
+    fn test_soap_12_fault() {
+        let transport = MockTransport::new(
+            r#"<env:Envelope xmlns:env="http://www.w3.org/2003/05/soap-envelope"
+                             xmlns:ter="http://www.onvif.org/ver10/error">
+                <env:Body>
+                    <env:Fault>
+                        <env:Code>
+                            <env:Value>env:Sender</env:Value>
+                            <env:Subcode>
+                                <env:Value>ter:InvalidArgVal</env:Value>
+                                <env:Subcode>
+                                    <env:Value>ter:NoProfile</env:Value>
+                                </env:Subcode>
+                            </env:Subcode>
+                        </env:Code>
+                        <env:Reason>
+                            <env:Text xml:lang="en">No such profile</env:Text>
+                        </env:Reason>
+                        <env:Detail>
+                            <ter:Profile>main</ter:Profile>
+                        </env:Detail>
+                    </env:Fault>
+                </env:Body>
+            </env:Envelope>"#,
+        );
+
+        let result: Result<GetForecastResponse, Error> =
+            block_on(request(&transport, &GetForecast::default()));
+
+        match result {
+            Err(Error::Fault(fault)) => {
+                assert_eq!(fault.code, "env:Sender");
+                assert_eq!(fault.subcodes, vec!["ter:InvalidArgVal", "ter:NoProfile"]);
+                assert_eq!(fault.reason, "No such profile");
+                let detail = fault.detail.unwrap();
+                assert!(detail.starts_with("<ter:Profile"), "{}", detail);
+                assert!(detail.contains("xmlns:ter=\"http://www.onvif.org/ver10/error\""));
+                assert!(detail.ends_with(">main</ter:Profile>"), "{}", detail);
+            }
+            other => panic!("expected a fault, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_soap_11_fault() {
+        let transport = MockTransport::new(
+            r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/">
+                <s:Body>
+                    <s:Fault>
+                        <faultcode>s:Client</faultcode>
+                        <faultstring>Unknown city</faultstring>
+                    </s:Fault>
+                </s:Body>
+            </s:Envelope>"#,
+        );
+
+        let result: Result<GetForecastResponse, Error> =
+            block_on(request(&transport, &GetForecast::default()));
+
+        let expected = Fault {
+            code: "s:Client".to_string(),
+            reason: "Unknown city".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(result, Err(Error::Fault(expected)));
+    }
+
+    // As generated for an operation with a `wsdl:fault`.
+    #[derive(Debug, PartialEq)]
+    pub enum GetForecastFault {
+        UnknownCity(UnknownCity),
+        Other(Fault),
+    }
+
+    impl OperationFault for GetForecastFault {
+        fn from_fault(fault: Fault) -> Self {
+            if let Some(detail) = fault.detail_as(Some("http://example.com"), "UnknownCity") {
+                return GetForecastFault::UnknownCity(detail);
+            }
+            GetForecastFault::Other(fault)
+        }
+
+        fn into_fault(self) -> Fault {
+            match self {
+                GetForecastFault::UnknownCity(detail) => Fault::with_detail("UnknownCity", &detail),
+                GetForecastFault::Other(fault) => fault,
+            }
+        }
+    }
+
+    fn fault_response(detail: &str) -> String {
+        format!(
+            r#"<env:Envelope xmlns:env="http://www.w3.org/2003/05/soap-envelope"
+                             xmlns:ter="http://www.onvif.org/ver10/error">
+                <env:Body>
+                    <env:Fault>
+                        <env:Code>
+                            <env:Value>env:Sender</env:Value>
+                            <env:Subcode>
+                                <env:Value>ter:InvalidArgVal</env:Value>
+                            </env:Subcode>
+                        </env:Code>
+                        <env:Reason>
+                            <env:Text xml:lang="en">Unknown city</env:Text>
+                        </env:Reason>
+                        <env:Detail>{}</env:Detail>
+                    </env:Fault>
+                </env:Body>
+            </env:Envelope>"#,
+            detail
+        )
+    }
+
+    #[test]
+    fn test_typed_fault() {
+        let transport = MockTransport::new(&fault_response(
+            r#"<UnknownCity xmlns="http://example.com"><City>Atlantis</City></UnknownCity>"#,
+        ));
+
+        let result: Result<GetForecastResponse, Error<GetForecastFault>> =
+            block_on(request(&transport, &GetForecast::default()));
+
+        let expected = UnknownCity { city: "Atlantis".to_string() };
+        assert_eq!(result, Err(Error::Fault(GetForecastFault::UnknownCity(expected))));
+    }
+
+    #[test]
+    fn test_undeclared_fault() {
+        let transport = MockTransport::new(&fault_response(
+            r#"<tns:UnknownCountry xmlns:tns="http://example.com"/>"#,
+        ));
+
+        let result: Result<GetForecastResponse, Error<GetForecastFault>> =
+            block_on(request(&transport, &GetForecast::default()));
+
+        match result {
+            Err(Error::Fault(GetForecastFault::Other(fault))) => {
+                assert_eq!(fault.code, "env:Sender");
+                assert_eq!(fault.subcodes, vec!["ter:InvalidArgVal"]);
+                assert_eq!(fault.reason, "Unknown city");
+            }
+            other => panic!("expected an undeclared fault, got {:?}", other),
+        }
+    }
+
+    // As generated for a port type with the operations `GetForecast` and the one-way
+    // `ReportObservation`.
+    trait WeatherServer {
+        fn get_forecast(
+            &self,
+            request: GetForecast,
+        ) -> impl Future<Output = Result<GetForecastResponse, GetForecastFault>>;
+
+        fn report_observation(
+            &self,
+            request: ReportObservation,
+        ) -> impl Future<Output = Result<(), Fault>>;
+
+        fn dispatch(
+            &self,
+            envelope: &[u8],
+            action: Option<&str>,
+        ) -> impl Future<Output = Response> {
+            async move {
+                let request = match ServerRequest::parse(envelope, action) {
+                    Ok(request) => request,
+                    Err(response) => return response,
+                };
+                let operations = [
+                    ServedOperation {
+                        name: "GetForecast",
+                        action: Some("http://example.com/Get"),
+                        namespace: Some("http://example.com"),
+                        element: "GetForecast",
+                    },
+                    ServedOperation {
+                        name: "ReportObservation",
+                        action: None,
+                        namespace: Some("http://example.com"),
+                        element: "ReportObservation",
+                    },
+                ];
+                match request.operation(&operations) {
+                    Some("GetForecast") => match request.body() {
+                        Ok(body) => request.respond(self.get_forecast(body).await),
+                        Err(response) => response,
+                    },
+                    Some("ReportObservation") => match request.body() {
+                        Ok(body) => request.acknowledge(self.report_observation(body).await),
+                        Err(response) => response,
+                    },
+                    _ => request.unknown_operation(),
+                }
+            }
+        }
+    }
+
+    struct Weather;
+
+    impl WeatherServer for Weather {
+        async fn get_forecast(
+            &self,
+            request: GetForecast,
+        ) -> Result<GetForecastResponse, GetForecastFault> {
+            match request.city.as_str() {
+                "Paris" => Ok(GetForecastResponse { forecast: "Sunny".to_string() }),
+                city => Err(GetForecastFault::UnknownCity(UnknownCity { city: city.to_string() })),
+            }
+        }
+
+        async fn report_observation(&self, request: ReportObservation) -> Result<(), Fault> {
+            match request.city.as_str() {
+                "Paris" => Ok(()),
+                city => {
+                    Err(Fault { reason: format!("Unknown city {}", city), ..Default::default() })
+                }
+            }
+        }
+    }
+
+    // Dispatches requests to a server in-process.
+    impl Transport for Weather {
+        async fn send(&self, request: Request) -> Result<String, Error> {
+            let action = request.soap_action.as_deref();
+            Ok(self.dispatch(request.envelope.as_bytes(), action).await.envelope)
+        }
+    }
+
+    #[test]
+    fn test_dispatch() {
+        let operation = SoapOperation { version: SoapVersion::Soap11, action: None };
+        let request = GetForecast { city: "Paris".to_string() };
+
+        let response: Result<GetForecastResponse, Error<GetForecastFault>> =
+            block_on(call(&Weather, "", &operation, &request));
+
+        assert_eq!(response.unwrap().forecast, "Sunny");
+    }
+
+    #[test]
+    fn test_acknowledgement() {
+        let transport = MockTransport::new("");
+        let operation = SoapOperation { version: SoapVersion::Soap11, action: None };
+
+        let result: Result<(), Error> =
+            block_on(send(&transport, "", &operation, &GetForecast::default()));
+
+        assert_eq!(result, Ok(()));
+        assert!(transport.requests.lock().unwrap()[0].envelope.contains("<tns:GetForecast"));
+    }
+
+    #[test]
+    fn test_acknowledgement_with_body() {
+        // Some services answer one-way operations with an envelope, whose body is ignored.
+        assert_eq!(parse_acknowledgement(RESPONSE), Ok(()));
+        assert_eq!(parse_acknowledgement(" \n"), Ok(()));
+        assert!(matches!(parse_acknowledgement("<Ack/>"), Err(Error::Deserialization(_))));
+    }
+
+    #[test]
+    fn test_acknowledgement_fault() {
+        let transport = MockTransport::new(&fault_response(""));
+
+        let result: Result<(), Error> = block_on(send_request(&transport, &GetForecast::default()));
+
+        assert!(matches!(result, Err(Error::Fault(fault)) if fault.reason == "Unknown city"));
+    }
+
+    #[test]
+    fn test_dispatch_one_way() {
+        let operation = SoapOperation { version: SoapVersion::Soap11, action: None };
+        let request = ReportObservation { city: "Paris".to_string() };
+
+        let response = block_on(
+            Weather.dispatch(write_request(operation.version, &request).unwrap().as_bytes(), None),
+        );
+        assert!(!response.is_fault);
+        assert_eq!(response.envelope, "");
+
+        let result: Result<(), Error> = block_on(send(&Weather, "", &operation, &request));
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_dispatch_one_way_fault() {
+        let request = ReportObservation { city: "Atlantis".to_string() };
+
+        let result: Result<(), Error> = block_on(send_request(&Weather, &request));
+
+        match result {
+            Err(Error::Fault(fault)) => {
+                assert_eq!(fault.code, "s:Receiver");
+                assert_eq!(fault.reason, "Unknown city Atlantis");
+            }
+            other => panic!("expected a fault, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_dispatch_by_action() {
+        let envelope = write_request(SoapVersion::Soap12, &GetForecast::default()).unwrap();
+        let envelope = envelope.replace("GetForecast", "Forecast");
+
+        let response =
+            block_on(Weather.dispatch(envelope.as_bytes(), Some("\"http://example.com/Get\"")));
+
+        // The operation of the action is called, whatever the element of the body.
+        assert!(response.is_fault);
+        assert!(response.envelope.contains("<tns:UnknownCity"), "{}", response.envelope);
+    }
+
+    #[test]
+    fn test_dispatch_typed_fault() {
+        let operation = SoapOperation { version: SoapVersion::Soap12, action: None };
+        let request = GetForecast { city: "Atlantis".to_string() };
+
+        let response: Result<GetForecastResponse, Error<GetForecastFault>> =
+            block_on(call(&Weather, "", &operation, &request));
+
+        let expected = UnknownCity { city: "Atlantis".to_string() };
+        assert_eq!(response, Err(Error::Fault(GetForecastFault::UnknownCity(expected))));
+    }
+
+    #[test]
+    fn test_dispatch_unknown_operation() {
+        let envelope = write_request(SoapVersion::Soap11, &GetForecastResponse::default()).unwrap();
+
+        let response = block_on(Weather.dispatch(envelope.as_bytes(), None));
+
+        assert!(response.is_fault);
+        assert_eq!(response.content_type, "text/xml; charset=utf-8");
+        let result: Result<GetForecastResponse, Error> = parse_response(&response.envelope);
+        let expected = Fault {
+            code: "s:Client".to_string(),
+            reason: "unknown operation `GetForecastResponse`".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(result, Err(Error::Fault(expected)));
+    }
+
+    #[test]
+    fn test_dispatch_not_an_envelope() {
+        let response = block_on(Weather.dispatch(b"<GetForecast/>", None));
+
+        assert!(response.is_fault);
+        assert_eq!(response.content_type, "application/soap+xml; charset=utf-8");
+    }
+
+    #[test]
+    fn test_not_an_envelope() {
+        let result: Result<GetForecastResponse, Error> = parse_response("<GetForecastResponse/>");
+        assert!(matches!(result, Err(Error::Deserialization(_))));
+    }
+}
@@ -0,0 +1,204 @@
+use xml::reader::XmlEvent;
+use yaserde::{de::Deserializer, YaDeserialize, YaSerialize};
+
+use crate::{
+    envelope::{copy_element, envelope, next, open_body, peek, to_xml, unexpected},
+    Error, Fault, OperationFault, SoapVersion,
+};
+
+/// An operation a dispatcher serves, identified by its action or the element of its input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ServedOperation<'a> {
+    pub name: &'a str,
+    pub action: Option<&'a str>,
+    pub namespace: Option<&'a str>,
+    pub element: &'a str,
+}
+
+/// A SOAP request received by a server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerRequest {
+    pub version: SoapVersion,
+    /// The `SOAPAction` header in SOAP 1.1 or the `action` parameter of the content type in
+    /// SOAP 1.2, without quotes.
+    pub action: Option<String>,
+    /// Namespace and local name of the first element of the body.
+    pub namespace: Option<String>,
+    pub element: String,
+    /// The first element of the body, with the namespaces it uses declared.
+    pub body: String,
+}
+
+/// A SOAP response a server sends back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub content_type: String,
+    /// Envelope of the response, empty for one-way operations.
+    pub envelope: String,
+    /// Whether the envelope is a `soap:Fault`, sent with the HTTP status 500.
+    pub is_fault: bool,
+}
+
+impl ServerRequest {
+    /// Reads the request `envelope` of either SOAP version. A request that is not a SOAP
+    /// envelope is answered with a fault of the sender.
+    pub fn parse(envelope: &[u8], action: Option<&str>) -> Result<Self, Response> {
+        let mut de = Deserializer::new_from_reader(envelope);
+        let version =
+            open_body(&mut de).map_err(|e| sender_fault(SoapVersion::Soap12, &e.to_string()))?;
+        read_body(&mut de, version, action).map_err(|e| sender_fault(version, &e.to_string()))
+    }
+
+    /// Name of the operation the request invokes, found by its action, or by the first
+    /// element of the body when no operation has the action.
+    pub fn operation<'a>(&self, operations: &[ServedOperation<'a>]) -> Option<&'a str> {
+        let by_action = self
+            .action
+            .as_deref()
+            .filter(|action| !action.is_empty())
+            .and_then(|action| operations.iter().find(|op| op.action == Some(action)));
+        by_action
+            .or_else(|| {
+                operations.iter().find(|op| {
+                    op.element == self.element && op.namespace == self.namespace.as_deref()
+                })
+            })
+            .map(|op| op.name)
+    }
+
+    /// Deserializes the body, or answers with a fault of the sender.
+    pub fn body<T: YaDeserialize>(&self) -> Result<T, Response> {
+        yaserde::de::from_str(&self.body).map_err(|e| sender_fault(self.version, &e))
+    }
+
+    /// Answers with the envelope of the output of the operation, or of its fault.
+    pub fn respond<Resp: YaSerialize, F: OperationFault>(
+        &self,
+        result: Result<Resp, F>,
+    ) -> Response {
+        let body = match result {
+            Ok(output) => to_xml(&output),
+            Err(fault) => return self.fault(fault.into_fault()),
+        };
+        match body {
+            Ok(body) => Response {
+                content_type: self.version.content_type(None),
+                envelope: envelope(self.version, &body),
+                is_fault: false,
+            },
+            Err(e) => self.fault(Fault { reason: e.to_string(), ..Default::default() }),
+        }
+    }
+
+    /// Answers a one-way operation with no envelope, which clients calling it with `send`
+    /// accept, or with the envelope of its fault.
+    pub fn acknowledge<F: OperationFault>(&self, result: Result<(), F>) -> Response {
+        match result {
+            Ok(()) => Response {
+                content_type: self.version.content_type(None),
+                envelope: String::new(),
+                is_fault: false,
+            },
+            Err(fault) => self.fault(fault.into_fault()),
+        }
+    }
+
+    /// Answers a request invoking none of the operations of the dispatcher.
+    pub fn unknown_operation(&self) -> Response {
+        let reason = match &self.action {
+            Some(action) if !action.is_empty() => format!("unknown action `{}`", action),
+            _ => format!("unknown operation `{}`", self.element),
+        };
+        sender_fault(self.version, &reason)
+    }
+
+    /// Answers with the envelope of `fault`.
+    pub fn fault(&self, fault: Fault) -> Response {
+        write_fault(self.version, &fault)
+    }
+}
+
+fn read_body<R: std::io::Read>(
+    de: &mut Deserializer<R>,
+    version: SoapVersion,
+    action: Option<&str>,
+) -> Result<ServerRequest, Error> {
+    loop {
+        match peek(de)? {
+            XmlEvent::StartElement { .. } => break,
+            XmlEvent::EndElement { .. } | XmlEvent::EndDocument => {
+                return Err(Error::Deserialization("empty body".to_string()))
+            }
+            _ => {
+                next(de)?;
+            }
+        }
+    }
+    let start = next(de)?;
+    let (namespace, element) = match &start {
+        XmlEvent::StartElement { name, .. } => (name.namespace.clone(), name.local_name.clone()),
+        event => return Err(unexpected("an element", event)),
+    };
+    Ok(ServerRequest {
+        version,
+        action: action.map(|action| action.trim_matches('"').to_string()),
+        namespace,
+        element,
+        body: copy_element(de, &start)?,
+    })
+}
+
+fn sender_fault(version: SoapVersion, reason: &str) -> Response {
+    let code = match version {
+        SoapVersion::Soap11 => "s:Client",
+        SoapVersion::Soap12 => "s:Sender",
+    };
+    write_fault(
+        version,
+        &Fault { code: code.to_string(), reason: reason.to_string(), ..Default::default() },
+    )
+}
+
+/// Envelope of `fault`, with the code of the receiver when it has no code.
+pub fn write_fault(version: SoapVersion, fault: &Fault) -> Response {
+    let code = match (fault.code.as_str(), version) {
+        ("", SoapVersion::Soap11) => "s:Server",
+        ("", SoapVersion::Soap12) => "s:Receiver",
+        (code, _) => code,
+    };
+    let detail = fault.detail.as_deref().unwrap_or_default();
+    let body = match version {
+        SoapVersion::Soap11 => format!(
+            "<s:Fault><faultcode>{}</faultcode><faultstring>{}</faultstring>{}</s:Fault>",
+            escape(code),
+            escape(&fault.reason),
+            if detail.is_empty() { String::new() } else { format!("<detail>{}</detail>", detail) },
+        ),
+        SoapVersion::Soap12 => format!(
+            "<s:Fault><s:Code><s:Value>{}</s:Value>{}{}</s:Code>\
+             <s:Reason><s:Text xml:lang=\"en\">{}</s:Text></s:Reason>{}</s:Fault>",
+            escape(code),
+            fault
+                .subcodes
+                .iter()
+                .map(|subcode| format!("<s:Subcode><s:Value>{}</s:Value>", escape(subcode)))
+                .collect::<String>(),
+            "</s:Subcode>".repeat(fault.subcodes.len()),
+            escape(&fault.reason),
+            if detail.is_empty() {
+                String::new()
+            } else {
+                format!("<s:Detail>{}</s:Detail>", detail)
+            },
+        ),
+    };
+    Response {
+        content_type: version.content_type(None),
+        envelope: envelope(version, &body),
+        is_fault: true,
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
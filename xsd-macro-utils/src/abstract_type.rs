@@ -0,0 +1,212 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+
+const XSI_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+// Serializes the enum over the derivations of an abstract type as its current variant, with
+// an `xsi:type` naming it, and deserializes the variant `xsi:type` names, the first one if
+// there is none. Names are compared by namespace and local name, whatever their prefix.
+pub fn serde(ast: &syn::DeriveInput) -> syn::Result<TokenStream> {
+    let enum_name = &ast.ident;
+
+    let variants = match &ast.data {
+        syn::Data::Enum(data_enum) => data_enum
+            .variants
+            .iter()
+            .map(|variant| {
+                let subtype = match &variant.fields {
+                    syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                        &fields.unnamed[0].ty
+                    }
+                    fields => {
+                        return Err(syn::Error::new_spanned(
+                            fields,
+                            "One unnamed field per variant is expected",
+                        ));
+                    }
+                };
+                Ok((&variant.ident, subtype, TypeName::of(variant)?))
+            })
+            .collect::<syn::Result<Vec<_>>>()?,
+        _ => {
+            return Err(syn::Error::new(Span::call_site(), "This macro can only be used on enums"));
+        }
+    };
+
+    let (first_ident, first_subtype, _) = variants
+        .first()
+        .ok_or_else(|| syn::Error::new(Span::call_site(), "At least one variant is expected"))?;
+
+    let ser_variants = variants
+        .iter()
+        .map(|(ident, subtype, type_name)| {
+            let local_name = &type_name.local_name;
+            let qualified_name = match &type_name.prefix {
+                Some(prefix) => format!("{}:{}", prefix, local_name),
+                None => local_name.clone(),
+            };
+            let declaration = match (&type_name.prefix, &type_name.namespace) {
+                (Some(prefix), Some(namespace)) => quote! { namespace.put(#prefix, #namespace); },
+                (None, Some(namespace)) => quote! {
+                    namespace.put(::xml::namespace::NS_NO_PREFIX, #namespace);
+                },
+                _ => quote! {},
+            };
+            quote! {
+                #enum_name::#ident(val) => {
+                    if writer.skip_start_end() {
+                        return <#subtype as ::yaserde::YaSerialize>::serialize(val, writer);
+                    }
+
+                    let name = writer.get_start_event_name().unwrap_or_else(|| #local_name.to_string());
+                    let (mut attributes, mut namespace) =
+                        <#subtype as ::yaserde::YaSerialize>::serialize_attributes(
+                            val,
+                            ::std::vec::Vec::new(),
+                            ::xml::namespace::Namespace::empty(),
+                        )?;
+                    namespace.put("xsi", #XSI_NAMESPACE);
+                    #declaration
+                    attributes.push(::xml::attribute::OwnedAttribute::new(
+                        ::xml::name::OwnedName::qualified("type", #XSI_NAMESPACE, Some("xsi")),
+                        #qualified_name,
+                    ));
+
+                    writer
+                        .write(::xml::writer::XmlEvent::StartElement {
+                            name: ::xml::name::Name::from(name.as_str()),
+                            attributes: ::std::borrow::Cow::Owned(
+                                attributes.iter().map(|attr| attr.borrow()).collect(),
+                            ),
+                            namespace: ::std::borrow::Cow::Owned(namespace),
+                        })
+                        .map_err(|e| e.to_string())?;
+                    writer.set_skip_start_end(true);
+                    <#subtype as ::yaserde::YaSerialize>::serialize(val, writer)?;
+                    writer
+                        .write(::xml::writer::XmlEvent::end_element())
+                        .map_err(|e| e.to_string())
+                }
+            }
+        })
+        .collect::<TokenStream>();
+
+    let ser_attributes_variants = variants
+        .iter()
+        .map(|(ident, subtype, _)| {
+            quote! {
+                #enum_name::#ident(val) => {
+                    <#subtype as ::yaserde::YaSerialize>::serialize_attributes(
+                        val, attributes, namespace,
+                    )
+                }
+            }
+        })
+        .collect::<TokenStream>();
+
+    let de_variants = variants
+        .iter()
+        .map(|(ident, subtype, type_name)| {
+            let local_name = &type_name.local_name;
+            let namespace = match &type_name.namespace {
+                Some(namespace) => quote! { Some(#namespace) },
+                None => quote! { None },
+            };
+            quote! {
+                Some(name) if name.namespace.as_deref() == #namespace && name.local_name == #local_name => {
+                    <#subtype as ::yaserde::YaDeserialize>::deserialize(reader).map(#enum_name::#ident)
+                }
+            }
+        })
+        .collect::<TokenStream>();
+
+    Ok(quote! {
+        impl ::yaserde::YaSerialize for #enum_name {
+            fn serialize<W: ::std::io::Write>(
+                &self,
+                writer: &mut ::yaserde::ser::Serializer<W>,
+            ) -> ::std::result::Result<(), ::std::string::String> {
+                match self {
+                    #ser_variants
+                }
+            }
+
+            fn serialize_attributes(
+                &self,
+                attributes: ::std::vec::Vec<::xml::attribute::OwnedAttribute>,
+                namespace: ::xml::namespace::Namespace,
+            ) -> ::std::result::Result<
+                (
+                    ::std::vec::Vec<::xml::attribute::OwnedAttribute>,
+                    ::xml::namespace::Namespace,
+                ),
+                ::std::string::String,
+            > {
+                match self {
+                    #ser_attributes_variants
+                }
+            }
+        }
+
+        impl ::yaserde::YaDeserialize for #enum_name {
+            fn deserialize<R: ::std::io::Read>(
+                reader: &mut ::yaserde::de::Deserializer<R>,
+            ) -> ::std::result::Result<Self, ::std::string::String> {
+                let type_name = match reader.peek()? {
+                    ::xml::reader::XmlEvent::StartElement { attributes, namespace, .. } => attributes
+                        .iter()
+                        .find(|attr| {
+                            attr.name.local_name == "type"
+                                && attr.name.namespace.as_deref() == Some(#XSI_NAMESPACE)
+                        })
+                        .map(|attr| {
+                            // Elements out of any default namespace bind the empty prefix to
+                            // the empty URI.
+                            ::xsd_types::types::QName::parse_with(&attr.value, |prefix| {
+                                namespace.get(prefix).filter(|uri| !uri.is_empty()).map(String::from)
+                            })
+                        })
+                        .transpose()?,
+                    _ => None,
+                };
+
+                match type_name {
+                    #de_variants
+                    _ => <#first_subtype as ::yaserde::YaDeserialize>::deserialize(reader)
+                        .map(#enum_name::#first_ident),
+                }
+            }
+        }
+    })
+}
+
+// The XSD name of the derivation, `#[xsd(type_name = "...", namespace = "...", prefix = "...")]`,
+// or the name of the variant.
+struct TypeName {
+    local_name: String,
+    namespace: Option<String>,
+    prefix: Option<String>,
+}
+
+impl TypeName {
+    fn of(variant: &syn::Variant) -> syn::Result<Self> {
+        let mut name =
+            TypeName { local_name: variant.ident.to_string(), namespace: None, prefix: None };
+        for attr in variant.attrs.iter().filter(|a| a.path().is_ident("xsd")) {
+            attr.parse_nested_meta(|meta| {
+                let value = || Ok::<_, syn::Error>(meta.value()?.parse::<syn::LitStr>()?.value());
+                if meta.path.is_ident("type_name") {
+                    name.local_name = value()?;
+                } else if meta.path.is_ident("namespace") {
+                    name.namespace = Some(value()?);
+                } else if meta.path.is_ident("prefix") {
+                    name.prefix = Some(value()?);
+                } else {
+                    return Err(meta.error("unsupported xsd attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(name)
+    }
+}
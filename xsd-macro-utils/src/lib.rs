@@ -2,10 +2,11 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::{parse_macro_input, DeriveInput};
 
+mod abstract_type;
 mod tuple;
 mod union;
 
-#[proc_macro_derive(UtilsTupleIo)]
+#[proc_macro_derive(UtilsTupleIo, attributes(xsd))]
 pub fn tuple_serde(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     tuple::serde(&ast).unwrap_or_else(|err| err.to_compile_error()).into()
@@ -62,8 +63,115 @@ pub fn default_serde(input: TokenStream) -> TokenStream {
     serde.into()
 }
 
+// Adds YaSerialize and YaDeserialize implementations for the enums over the values of a QName
+// type, which have a `value()` and an `__Unknown__` case, resolving the prefix of the value
+// with the namespaces of the document.
+#[proc_macro_derive(UtilsQNameSerDe)]
+pub fn qname_serde(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    let enum_name = &ast.ident;
+    let enum_name_literal = &ast.ident.to_string();
+
+    let serde = quote! {
+        impl ::yaserde::YaSerialize for #enum_name {
+            fn serialize<W: ::std::io::Write>(
+                &self,
+                writer: &mut ::yaserde::ser::Serializer<W>,
+            ) -> ::std::result::Result<(), ::std::string::String> {
+                ::xsd_types::utils::yaserde::serialize_qname(
+                    &self.value(),
+                    #enum_name_literal,
+                    writer,
+                )
+            }
+
+            fn serialize_attributes(
+                &self,
+                attributes: ::std::vec::Vec<::xml::attribute::OwnedAttribute>,
+                namespace: ::xml::namespace::Namespace,
+            ) -> ::std::result::Result<
+                (
+                    ::std::vec::Vec<::xml::attribute::OwnedAttribute>,
+                    ::xml::namespace::Namespace,
+                ),
+                ::std::string::String,
+            > {
+                Ok((attributes, namespace))
+            }
+        }
+
+        impl ::yaserde::YaDeserialize for #enum_name {
+            fn deserialize<R: ::std::io::Read>(
+                reader: &mut ::yaserde::de::Deserializer<R>,
+            ) -> ::std::result::Result<Self, ::std::string::String> {
+                ::xsd_types::utils::yaserde::deserialize_qname(reader, |name| {
+                    Ok(<Self as ::std::convert::TryFrom<::xsd_types::types::QName>>::try_from(name)
+                        .unwrap_or_else(Self::__Unknown__))
+                })
+            }
+        }
+    };
+
+    serde.into()
+}
+
 #[proc_macro_derive(UtilsUnionSerDe)]
 pub fn union_serde(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     union::serde(&ast).unwrap_or_else(|err| err.to_compile_error()).into()
 }
+
+// Adds YaSerialize and YaDeserialize implementations for the enum over the derivations of
+// an abstract type, choosing the derivation by `xsi:type` when deserializing.
+#[proc_macro_derive(UtilsAbstractSerDe, attributes(xsd))]
+pub fn abstract_serde(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    abstract_type::serde(&ast).unwrap_or_else(|err| err.to_compile_error()).into()
+}
+
+// Adds YaSerialize and YaDeserialize implementations for `Box<T>`, which recursive types
+// use to reference themselves, by delegating to the ones of `T`.
+#[proc_macro_derive(UtilsBoxSerDe)]
+pub fn box_serde(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let struct_name = &ast.ident;
+
+    let serde = quote! {
+        impl ::yaserde::YaSerialize for ::std::boxed::Box<#struct_name> {
+            fn serialize<W: ::std::io::Write>(
+                &self,
+                writer: &mut ::yaserde::ser::Serializer<W>,
+            ) -> ::std::result::Result<(), ::std::string::String> {
+                <#struct_name as ::yaserde::YaSerialize>::serialize(self, writer)
+            }
+
+            fn serialize_attributes(
+                &self,
+                attributes: ::std::vec::Vec<::xml::attribute::OwnedAttribute>,
+                namespace: ::xml::namespace::Namespace,
+            ) -> ::std::result::Result<
+                (
+                    ::std::vec::Vec<::xml::attribute::OwnedAttribute>,
+                    ::xml::namespace::Namespace,
+                ),
+                ::std::string::String,
+            > {
+                <#struct_name as ::yaserde::YaSerialize>::serialize_attributes(
+                    self, attributes, namespace,
+                )
+            }
+        }
+
+        impl ::yaserde::YaDeserialize for ::std::boxed::Box<#struct_name> {
+            fn deserialize<R: ::std::io::Read>(
+                reader: &mut ::yaserde::de::Deserializer<R>,
+            ) -> ::std::result::Result<Self, ::std::string::String> {
+                <#struct_name as ::yaserde::YaDeserialize>::deserialize(reader)
+                    .map(::std::boxed::Box::new)
+            }
+        }
+    };
+
+    serde.into()
+}
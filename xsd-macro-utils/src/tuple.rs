@@ -36,12 +36,20 @@ fn from_str(ast: &syn::DeriveInput) -> syn::Result<TokenStream> {
     };
 
     let struct_name = &ast.ident;
+    let normalize = match whitespace(ast)? {
+        Some(mode) => {
+            let mode = syn::Ident::new(&mode, proc_macro2::Span::call_site());
+            quote! { let s: &::std::primitive::str = &::xsd_types::utils::whitespace::#mode(s); }
+        }
+        None => quote! {},
+    };
 
     Ok(quote! {
         impl ::std::str::FromStr for #struct_name {
             type Err = ::std::string::String;
 
             fn from_str(s: &::std::primitive::str) -> ::std::result::Result<Self, Self::Err> {
+                #normalize
                 Ok(#struct_name(#convert))
             }
         }
@@ -80,6 +88,26 @@ fn display(ast: &syn::DeriveInput) -> syn::Result<TokenStream> {
     })
 }
 
+// Whitespace normalization set with `#[xsd(whitespace = "replace" | "collapse")]`.
+fn whitespace(ast: &syn::DeriveInput) -> syn::Result<Option<String>> {
+    let mut mode = None;
+    for attr in ast.attrs.iter().filter(|a| a.path().is_ident("xsd")) {
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("whitespace") {
+                return Err(meta.error("unsupported xsd attribute"));
+            }
+            let value = meta.value()?.parse::<syn::LitStr>()?;
+            match value.value().as_str() {
+                "preserve" => mode = None,
+                "replace" | "collapse" => mode = Some(value.value()),
+                _ => return Err(syn::Error::new(value.span(), "unknown whitespace mode")),
+            }
+            Ok(())
+        })?;
+    }
+    Ok(mode)
+}
+
 impl Type<'_> {
     pub fn from_path(path: &syn::Path) -> Type {
         match path.segments.last().expect("Empty type").ident.to_string().as_str() {
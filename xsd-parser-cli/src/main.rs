@@ -1,13 +1,16 @@
 use std::{
     fs,
     fs::OpenOptions,
-    io::{prelude::*, Read},
+    io::prelude::*,
     path::{Path, PathBuf},
 };
 
 use anyhow::Context;
 use clap::Parser;
-use xsd_parser::{generator::builder::GeneratorBuilder, parser::parse};
+use xsd_parser::{
+    build::Builder,
+    generator::{naming::OriginalNamingStrategy, order::ItemOrder, split::SplitStrategy},
+};
 
 #[derive(Parser)]
 #[clap(name = env!("CARGO_PKG_NAME"))]
@@ -21,60 +24,165 @@ struct Opt {
     /// Output file
     #[clap(long, short)]
     output: Option<PathBuf>,
+
+    /// Naming of generated items: `default`, or `original` to keep the casing of the schema
+    #[clap(long, default_value = "default", value_parser = ["default", "original"])]
+    naming: String,
+
+    /// Rust type to use for an XSD type, e.g. `xs:dateTime=chrono::DateTime<chrono::Utc>`
+    #[clap(long = "type-mapping", value_name = "XSD_TYPE=RUST_TYPE", value_parser = parse_key_value)]
+    type_mappings: Vec<(String, String)>,
+
+    /// Extra derive for every generated type, e.g. `Clone`
+    #[clap(long = "derive", value_name = "DERIVE")]
+    derives: Vec<String>,
+
+    /// Extra derive for a single generated type, e.g. `FooType=Hash`
+    #[clap(long = "type-derive", value_name = "XSD_TYPE=DERIVE", value_parser = parse_key_value)]
+    type_derives: Vec<(String, String)>,
+
+    /// Extra attribute for every generated type, e.g. `non_exhaustive`
+    #[clap(long = "attribute", value_name = "ATTRIBUTE")]
+    attributes: Vec<String>,
+
+    /// Extra attribute for a single generated type, e.g. `FooType=non_exhaustive`
+    #[clap(long = "type-attribute", value_name = "XSD_TYPE=ATTRIBUTE", value_parser = parse_key_value)]
+    type_attributes: Vec<(String, String)>,
+
+    /// Visibility of generated types, e.g. `pub(crate)`
+    #[clap(long)]
+    visibility: Option<String>,
+
+    /// Visibility of a single generated type, e.g. `FooType=pub(crate)`
+    #[clap(long = "type-visibility", value_name = "XSD_TYPE=VISIBILITY", value_parser = parse_key_value)]
+    type_visibility: Vec<(String, String)>,
+
+    /// Generate extensions of complex types as structs holding their base in a `base` field
+    #[clap(long = "compose-extensions")]
+    compose_extensions: bool,
+
+    /// Generate functions reading and writing documents rooted at global elements
+    #[clap(long = "root-elements")]
+    root_elements: bool,
+
+    /// Generate only the types reachable from this element or type, may be repeated
+    #[clap(long = "root", value_name = "NAME")]
+    roots: Vec<String>,
+
+    /// Order of generated items: `document`, `alphabetical` or `dependency`
+    #[clap(long, default_value_t = ItemOrder::Document)]
+    order: ItemOrder,
+
+    /// Language of the `xs:documentation` elements to document types with, e.g. `en`
+    #[clap(long = "doc-lang", value_name = "LANG")]
+    doc_lang: Option<String>,
+
+    /// Write a directory of modules with a `mod.rs` instead of a file: a module per
+    /// `namespace`, per `type-group`, or per the given number of types
+    #[clap(long, value_name = "SPLIT")]
+    split: Option<SplitStrategy>,
+}
+
+fn parse_key_value(s: &str) -> Result<(String, String), String> {
+    s.split_once('=')
+        .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+        .ok_or_else(|| format!("expected KEY=VALUE, got `{}`", s))
+}
+
+impl Opt {
+    fn builder(&self) -> Builder {
+        let b = match self.naming.as_str() {
+            "original" => Builder::new().naming(OriginalNamingStrategy),
+            _ => Builder::new(),
+        };
+        let b = self.type_mappings.iter().fold(b, |b, (xsd, rs)| b.type_mapping(xsd, rs));
+        let b = self.derives.iter().fold(b, |b, derive| b.derive(derive));
+        let b = self.type_derives.iter().fold(b, |b, (ty, derive)| b.type_derive(ty, derive));
+        let b = self.attributes.iter().fold(b, |b, attr| b.attribute(attr));
+        let b = self.type_attributes.iter().fold(b, |b, (ty, attr)| b.type_attribute(ty, attr));
+        let b = self.visibility.iter().fold(b, |b, vis| b.visibility(vis));
+        let b = self.type_visibility.iter().fold(b, |b, (ty, vis)| b.type_visibility(ty, vis));
+        let b = self.roots.iter().fold(b, |b, root| b.root(root)).item_order(self.order);
+        let b = self.doc_lang.iter().fold(b, |b, lang| b.doc_lang(lang));
+        b.compose_extensions(self.compose_extensions).root_elements(self.root_elements)
+    }
 }
 
 fn main() -> anyhow::Result<()> {
     let opt: Opt = Opt::parse();
 
+    let builder = opt.builder();
     let input_path = opt.input.unwrap_or_else(|| PathBuf::from("input/xsd"));
     let md = fs::metadata(&input_path).unwrap();
     if md.is_dir() {
         let output_path = opt.output.unwrap_or_else(|| PathBuf::from("output/rs"));
-        process_dir(&input_path, &output_path)?;
+        process_dir(&builder, opt.split, &input_path, &output_path)?;
     } else {
-        process_single_file(&input_path, opt.output.as_deref())?;
+        process_single_file(&builder, opt.split, &input_path, opt.output.as_deref())?;
     }
 
     Ok(())
 }
 
-fn process_dir(input_path: &Path, output_path: &Path) -> anyhow::Result<()> {
+fn process_dir(
+    builder: &Builder,
+    split: Option<SplitStrategy>,
+    input_path: &Path,
+    output_path: &Path,
+) -> anyhow::Result<()> {
     if !output_path.exists() {
         fs::create_dir_all(output_path)?;
     }
     for entry in fs::read_dir(input_path)? {
         let path = entry?.path();
         if path.is_dir() {
-            process_dir(&path, &output_path.join(path.file_name().unwrap()))?;
+            process_dir(builder, split, &path, &output_path.join(path.file_name().unwrap()))?;
         } else {
             let output_file_path = PathBuf::from(path.file_name().unwrap()).with_extension("rs");
             let output_file_path = output_path.join(output_file_path);
-            process_single_file(&path, Some(&output_file_path))?;
+            process_single_file(builder, split, &path, Some(&output_file_path))?;
         }
     }
     Ok(())
 }
 
-fn process_single_file(input_path: &Path, output_path: Option<&Path>) -> anyhow::Result<()> {
-    let text = load_file(input_path)?;
-    let rs_file = parse(text.as_str()).map_err(|_| anyhow::anyhow!("Error parsing file"))?;
-    let gen = GeneratorBuilder::default().build();
-    let code = gen.generate_rs_file(&rs_file);
-    if let Some(output_filename) = output_path {
-        write_to_file(output_filename, &code).context("Error writing file")?;
-    } else {
-        println!("{}", code);
+fn process_single_file(
+    builder: &Builder,
+    split: Option<SplitStrategy>,
+    input_path: &Path,
+    output_path: Option<&Path>,
+) -> anyhow::Result<()> {
+    let pruned = match (split, output_path) {
+        (Some(split), Some(output_path)) => {
+            let (tree, pruned) =
+                builder.generate_tree(input_path, split).context("Error parsing file")?;
+            // `foo.rs` becomes `foo/mod.rs`.
+            tree.write(&output_path.with_extension("")).context("Error writing files")?;
+            pruned
+        }
+        (Some(_), None) => anyhow::bail!("--split requires an output path"),
+        (None, _) => {
+            let (code, pruned) =
+                builder.generate_pruned(input_path).context("Error parsing file")?;
+            if let Some(output_filename) = output_path {
+                write_to_file(output_filename, &code).context("Error writing file")?;
+            } else {
+                println!("{}", code);
+            }
+            pruned
+        }
+    };
+    if !pruned.is_empty() {
+        eprintln!(
+            "{}: dropped {} types not reachable from the roots: {}",
+            input_path.display(),
+            pruned.len(),
+            pruned.join(", ")
+        );
     }
     Ok(())
 }
 
-fn load_file(path: &Path) -> std::io::Result<String> {
-    let mut file = fs::File::open(path)?;
-    let mut text = String::new();
-    file.read_to_string(&mut text)?;
-    Ok(text)
-}
-
 fn write_to_file(path: &Path, text: &str) -> std::io::Result<()> {
     let mut file = OpenOptions::new().write(true).truncate(true).create(true).open(path)?;
     file.write_all(text.as_bytes())
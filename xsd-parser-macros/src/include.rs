@@ -0,0 +1,112 @@
+use std::path::{Path, PathBuf};
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    Ident, LitStr, Token,
+};
+use xsd_parser::build::{schema_dependencies, Builder};
+
+pub struct IncludeXsd {
+    path: LitStr,
+    module: Option<LitStr>,
+}
+
+impl Parse for IncludeXsd {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = input.parse()?;
+        let mut module = None;
+
+        while input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            match key.to_string().as_str() {
+                "module" => module = Some(input.parse()?),
+                _ => return Err(syn::Error::new(key.span(), "unknown option, expected `module`")),
+            }
+        }
+
+        if !input.is_empty() {
+            return Err(input.error("expected `,`"));
+        }
+
+        Ok(Self { path, module })
+    }
+}
+
+pub fn expand(args: &IncludeXsd) -> syn::Result<TokenStream> {
+    let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .ok_or_else(|| syn::Error::new(Span::call_site(), "CARGO_MANIFEST_DIR is not set"))?;
+    expand_in(args, &manifest_dir)
+}
+
+fn expand_in(args: &IncludeXsd, manifest_dir: &Path) -> syn::Result<TokenStream> {
+    let error = |msg: String| syn::Error::new(args.path.span(), msg);
+
+    let path = manifest_dir.join(args.path.value());
+
+    let code = std::panic::catch_unwind(|| Builder::new().generate(&path))
+        .map_err(|payload| {
+            let msg = payload
+                .downcast_ref::<String>()
+                .cloned()
+                .or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()))
+                .unwrap_or_else(|| "unknown error".to_string());
+            error(format!("failed to generate code for {}: {}", path.display(), msg))
+        })?
+        .map_err(|e| error(e.to_string()))?;
+
+    let items: TokenStream = code.parse().map_err(|e| {
+        error(format!("generated code for {} is not valid Rust: {}", path.display(), e))
+    })?;
+
+    // Makes cargo rebuild the crate when the schema or anything it imports changes.
+    let tracked = std::iter::once(path.clone())
+        .chain(schema_dependencies(&path).map_err(|e| error(e.to_string()))?)
+        .map(|p| p.to_string_lossy().into_owned())
+        .map(|p| quote! { const _: &str = include_str!(#p); });
+
+    Ok(match &args.module {
+        Some(module) => {
+            let name = format_ident!("{}", module.value(), span = module.span());
+            quote! {
+                pub mod #name {
+                    use super::*;
+                    #(#tracked)*
+                    #items
+                }
+            }
+        }
+        None => quote! {
+            #(#tracked)*
+            #items
+        },
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(tokens: TokenStream) -> IncludeXsd {
+        syn::parse2(tokens).unwrap()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let res = args(quote! { "schemas/common.xsd", module = "common" });
+        assert_eq!(res.path.value(), "schemas/common.xsd");
+        assert_eq!(res.module.unwrap().value(), "common");
+
+        assert!(args(quote! { "schemas/common.xsd" }).module.is_none());
+        assert!(syn::parse2::<IncludeXsd>(quote! { "a.xsd", mod = "a" }).is_err());
+    }
+
+    #[test]
+    fn test_missing_schema() {
+        let err = expand_in(&args(quote! { "no/such/file.xsd" }), Path::new("/")).unwrap_err();
+        assert!(err.to_string().contains("no/such/file.xsd"), "{}", err);
+    }
+}
@@ -0,0 +1,12 @@
+use proc_macro::TokenStream;
+use syn::parse_macro_input;
+
+mod include;
+
+// Generates code for a schema located relative to `CARGO_MANIFEST_DIR`, e.g.
+// `include_xsd!("schemas/common.xsd", module = "common")`.
+#[proc_macro]
+pub fn include_xsd(input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(input as include::IncludeXsd);
+    include::expand(&args).unwrap_or_else(|err| err.to_compile_error()).into()
+}
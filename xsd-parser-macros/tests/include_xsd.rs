@@ -0,0 +1,15 @@
+use xsd_parser::generator::validator::Validate;
+use xsd_parser_macros::include_xsd;
+use yaserde_derive::{YaDeserialize, YaSerialize};
+
+include_xsd!("../xsd-parser/tests/complex_type/input.xsd", module = "complex_type");
+
+#[test]
+fn deserialization_works() {
+    let ser = include_str!("../../xsd-parser/tests/complex_type/example.xml");
+
+    let de: complex_type::FooType = yaserde::de::from_str(ser).unwrap();
+
+    assert_eq!(de, complex_type::FooType { min: 1, max: 2 });
+    assert!(de.validate().is_ok());
+}
@@ -0,0 +1,494 @@
+//! Code generation from `build.rs` scripts.
+//!
+//! ```no_run
+//! // build.rs
+//! xsd_parser::build::Builder::new()
+//!     .schema("schemas/onvif.xsd")
+//!     .type_mapping("xs:dateTime", "chrono::DateTime<chrono::Utc>")
+//!     .compile()
+//!     .unwrap();
+//! ```
+//!
+//! The generated code is written to `$OUT_DIR/<schema file stem>.rs` and can be
+//! pulled into the crate with `include!(concat!(env!("OUT_DIR"), "/onvif.rs"));`.
+
+use std::{
+    collections::HashSet,
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use crate::{
+    generator::{
+        builder::GeneratorBuilder,
+        naming::NamingStrategy,
+        order::ItemOrder,
+        split::{GeneratedTree, SplitStrategy},
+        Generator,
+    },
+    parser::{
+        constants::attribute,
+        parse_document,
+        types::RsFile,
+        xsd_elements::{ElementType, XsdNode},
+    },
+};
+
+#[derive(Debug)]
+pub enum Error {
+    Io(PathBuf, io::Error),
+    Xml(PathBuf, roxmltree::Error),
+    MissingOutDir,
+    /// Roots matching no element or type of a schema.
+    UnmatchedRoots(PathBuf, Vec<String>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(path, err) => write!(f, "{}: {}", path.display(), err),
+            Error::Xml(path, err) => write!(f, "{}: {}", path.display(), err),
+            Error::MissingOutDir => {
+                write!(f, "output directory is not set and OUT_DIR is not defined")
+            }
+            Error::UnmatchedRoots(path, roots) => write!(
+                f,
+                "{}: no element or type matches the roots {}",
+                path.display(),
+                roots.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(_, err) => Some(err),
+            Error::Xml(_, err) => Some(err),
+            Error::MissingOutDir | Error::UnmatchedRoots(..) => None,
+        }
+    }
+}
+
+// The naming strategy of a builder, shared by the generators it builds.
+#[derive(Clone)]
+struct Naming(Rc<dyn NamingStrategy>);
+
+impl fmt::Debug for Naming {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("NamingStrategy")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Builder {
+    schemas: Vec<PathBuf>,
+    out_dir: Option<PathBuf>,
+    naming: Option<Naming>,
+    type_mappings: Vec<(String, String)>,
+    derives: Vec<(Option<String>, String)>,
+    attributes: Vec<(Option<String>, String)>,
+    visibility: Vec<(Option<String>, String)>,
+    compose_extensions: bool,
+    root_elements: bool,
+    roots: Vec<String>,
+    item_order: ItemOrder,
+    doc_lang: Option<String>,
+    split: Option<SplitStrategy>,
+    emit_rerun_if_changed: bool,
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Self {
+            schemas: vec![],
+            out_dir: None,
+            naming: None,
+            type_mappings: vec![],
+            derives: vec![],
+            attributes: vec![],
+            visibility: vec![],
+            compose_extensions: false,
+            root_elements: false,
+            roots: vec![],
+            item_order: ItemOrder::Document,
+            doc_lang: None,
+            split: None,
+            emit_rerun_if_changed: true,
+        }
+    }
+
+    /// Adds a schema to generate code for. Every schema produces its own `.rs` file.
+    pub fn schema<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.schemas.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Sets the directory generated files are written to. Defaults to `OUT_DIR`.
+    pub fn out_dir<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.out_dir = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Sets the strategy naming the generated types, fields, variants and modules.
+    pub fn naming<N: NamingStrategy + 'static>(mut self, naming: N) -> Self {
+        self.naming = Some(Naming(Rc::new(naming)));
+        self
+    }
+
+    /// Replaces the Rust type used for `xsd_type` (e.g. `xs:dateTime`) in generated code.
+    /// Types of the XML Schema namespace are matched whatever the prefix of the schema.
+    pub fn type_mapping(mut self, xsd_type: &str, rust_type: &str) -> Self {
+        self.type_mappings.push((xsd_type.to_string(), rust_type.to_string()));
+        self
+    }
+
+    /// Adds a derive to every generated type, e.g. `Clone` or `serde::Serialize`.
+    pub fn derive(mut self, derive: &str) -> Self {
+        self.derives.push((None, derive.to_string()));
+        self
+    }
+
+    /// Adds a derive to the generated type for the XSD type `type_name`.
+    pub fn type_derive(mut self, type_name: &str, derive: &str) -> Self {
+        self.derives.push((Some(type_name.to_string()), derive.to_string()));
+        self
+    }
+
+    /// Adds an attribute to every generated type, e.g. `#[non_exhaustive]`.
+    pub fn attribute(mut self, attribute: &str) -> Self {
+        self.attributes.push((None, attribute.to_string()));
+        self
+    }
+
+    /// Adds an attribute to the generated type for the XSD type `type_name`.
+    pub fn type_attribute(mut self, type_name: &str, attribute: &str) -> Self {
+        self.attributes.push((Some(type_name.to_string()), attribute.to_string()));
+        self
+    }
+
+    /// Sets the visibility of generated types, e.g. `pub(crate)`.
+    pub fn visibility(mut self, visibility: &str) -> Self {
+        self.visibility.push((None, visibility.to_string()));
+        self
+    }
+
+    /// Sets the visibility of the generated type for the XSD type `type_name`.
+    pub fn type_visibility(mut self, type_name: &str, visibility: &str) -> Self {
+        self.visibility.push((Some(type_name.to_string()), visibility.to_string()));
+        self
+    }
+
+    /// Generates types extending complex types as structs holding their base in a `base`
+    /// field, which they deref and convert to, instead of copying the fields of the base.
+    pub fn compose_extensions(mut self, enabled: bool) -> Self {
+        self.compose_extensions = enabled;
+        self
+    }
+
+    /// Generates an `elements` module with `from_xml_str`, `from_reader`, `to_xml_string` and
+    /// `to_writer` functions for every global element of a schema.
+    pub fn root_elements(mut self, enabled: bool) -> Self {
+        self.root_elements = enabled;
+        self
+    }
+
+    /// Generates only the types reachable from the element or type `name` and the other roots.
+    /// Generating a schema fails with `Error::UnmatchedRoots` if a root is not part of it.
+    pub fn root(mut self, name: &str) -> Self {
+        self.roots.push(name.to_string());
+        self
+    }
+
+    /// Sets the order of the items of generated files: the one of the schema (default),
+    /// alphabetical, or types after the ones they depend on.
+    pub fn item_order(mut self, order: ItemOrder) -> Self {
+        self.item_order = order;
+        self
+    }
+
+    /// Documents types with the `xs:documentation` elements in the language `lang`, e.g. `en`,
+    /// and the ones without `xml:lang`. All of them are used by default.
+    pub fn doc_lang(mut self, lang: &str) -> Self {
+        self.doc_lang = Some(lang.to_string());
+        self
+    }
+
+    /// Splits the code of every schema into modules written to `<out dir>/<file stem>/`.
+    /// `<out dir>/<file stem>.rs` declares them and can be included as before.
+    pub fn split(mut self, split: SplitStrategy) -> Self {
+        self.split = Some(split);
+        self
+    }
+
+    /// Controls whether `cargo:rerun-if-changed` is printed for every schema
+    /// and everything it transitively imports. Enabled by default.
+    pub fn emit_rerun_if_changed(mut self, enabled: bool) -> Self {
+        self.emit_rerun_if_changed = enabled;
+        self
+    }
+
+    /// A generator builder with the naming, type mappings and type settings of this builder,
+    /// for generating code by hand.
+    pub fn generator_builder<'input>(&self) -> GeneratorBuilder<'input> {
+        let gb = self
+            .type_mappings
+            .iter()
+            .fold(GeneratorBuilder::default(), |gb, (xsd, rs)| gb.with_type_mapping(xsd, rs));
+        let gb =
+            self.naming.iter().fold(gb, |gb, naming| gb.with_naming(Box::new(naming.0.clone())));
+        let gb = self.derives.iter().fold(gb, |gb, (ty, derive)| match ty {
+            Some(ty) => gb.with_type_derive(ty, derive),
+            None => gb.with_derive(derive),
+        });
+        let gb = self.attributes.iter().fold(gb, |gb, (ty, attr)| match ty {
+            Some(ty) => gb.with_type_attribute(ty, attr),
+            None => gb.with_attribute(attr),
+        });
+        let gb = self.visibility.iter().fold(gb, |gb, (ty, vis)| match ty {
+            Some(ty) => gb.with_type_visibility(ty, vis),
+            None => gb.with_visibility(vis),
+        });
+        let gb = self.roots.iter().fold(gb, |gb, root| gb.with_root(root));
+        let gb = gb.with_item_order(self.item_order);
+        let gb = self.doc_lang.iter().fold(gb, |gb, lang| gb.with_doc_lang(lang));
+        let gb = match self.compose_extensions {
+            true => gb.with_composed_extensions(),
+            false => gb,
+        };
+        match self.root_elements {
+            true => gb.with_root_elements(),
+            false => gb,
+        }
+    }
+
+    /// Generates the code for a single schema file.
+    pub fn generate(&self, path: &Path) -> Result<String, Error> {
+        self.generate_pruned(path).map(|(code, _)| code)
+    }
+
+    /// Generates the code for a single schema file, along with the names of the types left
+    /// out because no root reaches them.
+    pub fn generate_pruned(&self, path: &Path) -> Result<(String, Vec<String>), Error> {
+        self.with_schema(path, |gen, rs_file| gen.generate_rs_file(rs_file))
+    }
+
+    /// Generates the code for a single schema file split into modules, along with the names
+    /// of the types left out because no root reaches them.
+    pub fn generate_tree(
+        &self,
+        path: &Path,
+        split: SplitStrategy,
+    ) -> Result<(GeneratedTree, Vec<String>), Error> {
+        self.with_schema(path, |gen, rs_file| gen.generate_rs_tree(rs_file, split))
+    }
+
+    fn with_schema<T>(
+        &self,
+        path: &Path,
+        generate: impl for<'a> FnOnce(&Generator<'a>, &RsFile<'a>) -> T,
+    ) -> Result<(T, Vec<String>), Error> {
+        let text = fs::read_to_string(path).map_err(|e| Error::Io(path.to_path_buf(), e))?;
+        let doc =
+            roxmltree::Document::parse(&text).map_err(|e| Error::Xml(path.to_path_buf(), e))?;
+        let rs_file = parse_document(&doc);
+        let gen = self.generator_builder().build();
+        let res = generate(&gen, &rs_file);
+        match gen.unmatched_roots.take() {
+            roots if roots.is_empty() => Ok((res, gen.pruned_types.take())),
+            roots => Err(Error::UnmatchedRoots(path.to_path_buf(), roots)),
+        }
+    }
+
+    /// Generates code for all schemas and returns paths to the written files.
+    pub fn compile(self) -> Result<Vec<PathBuf>, Error> {
+        let out_dir = match &self.out_dir {
+            Some(dir) => dir.clone(),
+            None => env::var_os("OUT_DIR").map(PathBuf::from).ok_or(Error::MissingOutDir)?,
+        };
+        fs::create_dir_all(&out_dir).map_err(|e| Error::Io(out_dir.clone(), e))?;
+
+        let mut written = vec![];
+        let mut tracked = HashSet::new();
+        for schema in &self.schemas {
+            if self.emit_rerun_if_changed {
+                for dep in std::iter::once(schema.clone()).chain(schema_dependencies(schema)?) {
+                    if tracked.insert(dep.clone()) {
+                        println!("cargo:rerun-if-changed={}", dep.display());
+                    }
+                }
+            }
+
+            let file_name = schema.file_stem().unwrap_or_default();
+            let out_path = out_dir.join(file_name).with_extension("rs");
+            let code = match self.split {
+                Some(split) => {
+                    let tree_dir = out_dir.join(file_name);
+                    let (tree, _) = self.generate_tree(schema, split)?;
+                    fs::create_dir_all(&tree_dir).map_err(|e| Error::Io(tree_dir.clone(), e))?;
+                    for module in &tree.modules {
+                        let path = tree_dir.join(module.file_name());
+                        fs::write(&path, &module.code).map_err(|e| Error::Io(path.clone(), e))?;
+                        written.push(path);
+                    }
+                    tree.include_rs(&tree_dir)
+                }
+                None => self.generate(schema)?,
+            };
+            fs::write(&out_path, code).map_err(|e| Error::Io(out_path.clone(), e))?;
+            written.push(out_path);
+        }
+        Ok(written)
+    }
+}
+
+/// Returns local files referenced by `xs:import`, `xs:include` and `xs:redefine`
+/// of the schema, transitively. Remote locations and missing files are skipped.
+pub fn schema_dependencies(path: &Path) -> Result<Vec<PathBuf>, Error> {
+    let mut result = vec![];
+    let mut visited = HashSet::new();
+    let mut queue = vec![path.to_path_buf()];
+    visited.insert(path.to_path_buf());
+
+    while let Some(current) = queue.pop() {
+        for location in schema_locations(&current)? {
+            let dep = match current.parent() {
+                Some(dir) => dir.join(location),
+                None => PathBuf::from(location),
+            };
+            if dep.is_file() && visited.insert(dep.clone()) {
+                result.push(dep.clone());
+                queue.push(dep);
+            }
+        }
+    }
+    Ok(result)
+}
+
+fn schema_locations(path: &Path) -> Result<Vec<String>, Error> {
+    let text = fs::read_to_string(path).map_err(|e| Error::Io(path.to_path_buf(), e))?;
+    let doc = roxmltree::Document::parse(&text).map_err(|e| Error::Xml(path.to_path_buf(), e))?;
+
+    Ok(doc
+        .root_element()
+        .children()
+        .filter(|n| {
+            matches!(
+                n.xsd_type(),
+                ElementType::Import | ElementType::Include | ElementType::Redefine
+            )
+        })
+        .filter_map(|n| n.attribute(attribute::SCHEMA_LOCATION))
+        .filter(|location| !location.contains("://"))
+        .map(|location| location.to_string())
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generator::naming::OriginalNamingStrategy;
+
+    fn test_case_dir(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join(name)
+    }
+
+    // A fresh output directory, distinct per test and per test run.
+    fn temp_out_dir(test: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("xsd_parser_build_{}_{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_schema_dependencies() {
+        let dir = test_case_dir("extension_base_two_files");
+        let deps = schema_dependencies(&dir.join("input.xsd")).unwrap();
+        assert_eq!(deps, vec![dir.join("input2.xsd")]);
+
+        assert!(schema_dependencies(&dir.join("input2.xsd")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_compile() {
+        let out_dir = temp_out_dir("test_compile");
+        let written = Builder::new()
+            .schema(test_case_dir("tuple_with_integer").join("input.xsd"))
+            .type_mapping("xs:integer", "i128")
+            .derive("Clone")
+            .visibility("pub(crate)")
+            .out_dir(&out_dir)
+            .emit_rerun_if_changed(false)
+            .compile()
+            .unwrap();
+
+        assert_eq!(written, vec![out_dir.join("input.rs")]);
+        let code = fs::read_to_string(&written[0]).unwrap();
+        assert!(code.contains("UtilsDefaultSerde, Clone)]"), "{}", code);
+        assert!(code.contains("pub(crate) struct FooType (pub i128);"), "{}", code);
+    }
+
+    #[test]
+    fn test_compile_split() {
+        let out_dir = temp_out_dir("test_compile_split");
+        let written = Builder::new()
+            .schema(test_case_dir("complex_type").join("input.xsd"))
+            .split(SplitStrategy::TypeGroup)
+            .out_dir(&out_dir)
+            .emit_rerun_if_changed(false)
+            .compile()
+            .unwrap();
+
+        let tree_dir = out_dir.join("input");
+        assert_eq!(written, vec![tree_dir.join("complex_types.rs"), out_dir.join("input.rs")]);
+        let code = fs::read_to_string(&written[0]).unwrap();
+        assert!(code.starts_with("use super::*;"), "{}", code);
+        assert!(code.contains("pub struct FooType {"), "{}", code);
+        let code = fs::read_to_string(&written[1]).unwrap();
+        assert!(code.contains(&format!("#[path = {:?}]", written[0])), "{}", code);
+        assert!(code.contains("pub use complex_types::*;"), "{}", code);
+    }
+
+    #[test]
+    fn test_compile_naming() {
+        let out_dir = temp_out_dir("test_compile_naming");
+        let written = Builder::new()
+            .schema(test_case_dir("complex_type").join("input.xsd"))
+            .naming(OriginalNamingStrategy)
+            .out_dir(&out_dir)
+            .emit_rerun_if_changed(false)
+            .compile()
+            .unwrap();
+
+        let code = fs::read_to_string(&written[0]).unwrap();
+        assert!(code.contains("pub struct FooType {"), "{}", code);
+        assert!(code.contains("pub Min: i32,"), "{}", code);
+    }
+
+    #[test]
+    fn test_missing_schema() {
+        let res = Builder::new().generate(Path::new("no/such/schema.xsd"));
+        assert!(matches!(res, Err(Error::Io(..))));
+    }
+
+    #[test]
+    fn test_unmatched_roots() {
+        let schema = test_case_dir("reachable_types").join("input.xsd");
+        let res = Builder::new().root("tns:Foo").root("tns:Missing").generate(&schema);
+        match res {
+            Err(Error::UnmatchedRoots(path, roots)) => {
+                assert_eq!(path, schema);
+                assert_eq!(roots, vec!["tns:Missing"]);
+            }
+            res => panic!("unexpected result: {:?}", res),
+        }
+    }
+}
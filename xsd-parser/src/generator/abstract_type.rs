@@ -0,0 +1,186 @@
+//! Abstract complex types, generated as a trait with accessors for their fields and an
+//! enum over their concrete derivations.
+
+use std::collections::HashMap;
+
+use crate::{
+    generator::{utils::split_name, Generator},
+    parser::types::{RsEntity, RsFile, Struct, StructField, TypeModifier},
+};
+
+/// An abstract complex type of the schema.
+#[derive(Debug, Clone, Default)]
+pub struct AbstractType {
+    pub name: String,
+    /// Nearest abstract base, whose trait is the supertrait of the one of this type.
+    pub base: Option<String>,
+    /// Fields the trait has accessors for, the ones of `base` excluded.
+    pub accessors: Vec<StructField>,
+    /// Concrete types deriving from this one, directly or not, in the order of the schema.
+    pub derivations: Vec<String>,
+}
+
+/// Collects the abstract types among the top-level structs of the schema.
+pub fn collect_abstract_types(schema: &RsFile) -> Vec<AbstractType> {
+    let structs = schema
+        .types
+        .iter()
+        .filter_map(|entity| match entity {
+            RsEntity::Struct(st) => Some((st.name.as_str(), st)),
+            _ => None,
+        })
+        .collect::<HashMap<&str, &Struct>>();
+
+    schema
+        .types
+        .iter()
+        .filter_map(|entity| match entity {
+            RsEntity::Struct(st) if st.is_abstract => Some(st),
+            _ => None,
+        })
+        .map(|st| {
+            let base = ancestors(st, &structs).into_iter().find(|s| s.is_abstract);
+            let inherited = base.map(|b| b.fields.borrow().clone()).unwrap_or_default();
+            AbstractType {
+                name: st.name.clone(),
+                base: base.map(|b| b.name.clone()),
+                accessors: st
+                    .fields
+                    .borrow()
+                    .iter()
+                    // Nested types of fields are generated for every struct having them.
+                    .filter(|f| f.subtypes.is_empty())
+                    .filter(|f| !f.type_modifiers.contains(&TypeModifier::Empty))
+                    .filter(|f| !inherited.iter().any(|i| i.name == f.name))
+                    .cloned()
+                    .collect(),
+                derivations: structs_in_order(schema)
+                    .filter(|s| !s.is_abstract)
+                    .filter(|s| ancestors(s, &structs).iter().any(|a| a.name == st.name))
+                    .map(|s| s.name.clone())
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+fn structs_in_order<'a>(schema: &'a RsFile) -> impl Iterator<Item = &'a Struct> {
+    schema.types.iter().filter_map(|entity| match entity {
+        RsEntity::Struct(st) => Some(st),
+        _ => None,
+    })
+}
+
+// Bases of a struct, the nearest first.
+fn ancestors<'a>(st: &Struct, structs: &HashMap<&str, &'a Struct>) -> Vec<&'a Struct> {
+    let mut res: Vec<&Struct> = vec![];
+    let mut base = st.base.as_ref();
+    while let Some(b) = base.and_then(|b| structs.get(split_name(&b.type_name).1)) {
+        if res.iter().any(|r| r.name == b.name) {
+            break;
+        }
+        res.push(b);
+        base = b.base.as_ref();
+    }
+    res
+}
+
+impl Generator<'_> {
+    /// The abstract type `name`, if it has concrete derivations to generate an enum over.
+    pub fn abstract_type(&self, name: &str) -> Option<AbstractType> {
+        self.abstract_types
+            .borrow()
+            .iter()
+            .find(|a| a.name == name && !a.derivations.is_empty())
+            .cloned()
+    }
+
+    /// Abstract types whose traits the type `name` implements.
+    pub fn implemented_traits(&self, name: &str) -> Vec<AbstractType> {
+        let types = self.abstract_types.borrow();
+        match types.iter().find(|a| a.name == name) {
+            Some(own) => {
+                let mut res = vec![own.clone()];
+                while let Some(base) = res.last().unwrap().base.clone() {
+                    match types
+                        .iter()
+                        .find(|a| a.name == base && res.iter().all(|r| r.name != base))
+                    {
+                        Some(a) => res.push(a.clone()),
+                        None => break,
+                    }
+                }
+                res
+            }
+            None => {
+                types.iter().filter(|a| a.derivations.iter().any(|d| d == name)).cloned().collect()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parser::parse;
+
+    #[test]
+    fn test_collect_abstract_types() {
+        let schema = parse(
+            r#"
+    <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"
+               xmlns:tns="http://example.com"
+               targetNamespace="http://example.com">
+        <xs:complexType name="Shape" abstract="true">
+            <xs:sequence>
+                <xs:element name="Name" type="xs:string"/>
+            </xs:sequence>
+        </xs:complexType>
+        <xs:complexType name="Polygon" abstract="true">
+            <xs:complexContent>
+                <xs:extension base="tns:Shape">
+                    <xs:sequence>
+                        <xs:element name="Corners" type="xs:int"/>
+                    </xs:sequence>
+                </xs:extension>
+            </xs:complexContent>
+        </xs:complexType>
+        <xs:complexType name="Circle">
+            <xs:complexContent>
+                <xs:extension base="tns:Shape">
+                    <xs:sequence>
+                        <xs:element name="Radius" type="xs:double"/>
+                    </xs:sequence>
+                </xs:extension>
+            </xs:complexContent>
+        </xs:complexType>
+        <xs:complexType name="Square">
+            <xs:complexContent>
+                <xs:extension base="tns:Polygon">
+                    <xs:sequence>
+                        <xs:element name="Side" type="xs:double"/>
+                    </xs:sequence>
+                </xs:extension>
+            </xs:complexContent>
+        </xs:complexType>
+    </xs:schema>
+            "#,
+        )
+        .unwrap();
+
+        let types = collect_abstract_types(&schema);
+        let names =
+            |fields: &[StructField]| fields.iter().map(|f| f.name.clone()).collect::<Vec<_>>();
+
+        assert_eq!(types.len(), 2);
+        assert_eq!(types[0].name, "Shape");
+        assert_eq!(types[0].base, None);
+        assert_eq!(names(&types[0].accessors), vec!["Name"]);
+        assert_eq!(types[0].derivations, vec!["Circle", "Square"]);
+
+        assert_eq!(types[1].name, "Polygon");
+        assert_eq!(types[1].base.as_deref(), Some("Shape"));
+        assert_eq!(names(&types[1].accessors), vec!["Corners"]);
+        assert_eq!(types[1].derivations, vec!["Square"]);
+    }
+}
@@ -2,9 +2,7 @@ use std::borrow::Cow;
 
 use crate::{
     generator::{
-        default::{
-            default_format_comment, default_format_name, default_format_type, default_modify_type,
-        },
+        default::{default_format_comment, default_modify_type},
         utils::match_built_in_type,
         Generator,
     },
@@ -21,14 +19,17 @@ pub trait BaseGenerator {
     }
 
     fn format_type_name(&self, type_name: &str, gen: &Generator) -> Cow<'_, str> {
+        if let Some(t) = gen.type_mapping(type_name) {
+            return t.to_string().into();
+        }
         if let Some(t) = match_built_in_type(type_name, &gen.xsd_ns.borrow()) {
             return t.into();
         }
-        default_format_type(type_name, &gen.target_ns.borrow())
+        gen.naming().type_path(type_name, gen.target_ns.borrow().as_ref()).into()
     }
 
-    fn format_name(&self, name: &str) -> Cow<'_, str> {
-        Cow::Owned(default_format_name(name))
+    fn format_name(&self, name: &str, gen: &Generator) -> Cow<'_, str> {
+        gen.naming().field_name(name).into()
     }
 
     fn format_comment(&self, comment: Option<&str>, indent: usize) -> String {
@@ -39,8 +40,12 @@ pub trait BaseGenerator {
         default_modify_type(type_name, modifiers)
     }
 
-    fn mod_name(&self, name: &str) -> String {
-        default_format_name(name)
+    fn mod_name(&self, name: &str, gen: &Generator) -> String {
+        gen.naming().module_name(name)
+    }
+
+    fn format_attributes(&self, type_name: &str, gen: &Generator) -> String {
+        gen.type_attributes(type_name).iter().map(|attr| format!("{}\n", attr)).collect()
     }
 
     fn join_subtypes(&self, subtypes: &[RsEntity], gen: &Generator) -> String {
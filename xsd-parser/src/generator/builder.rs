@@ -3,10 +3,14 @@ use crate::generator::{
     base::{BaseGenerator, DefaultBaseGenerator},
     enum_case::{DefaultEnumCaseGen, EnumCaseGenerator},
     import::{DefaultImportGen, ImportGenerator},
+    naming::{DefaultNamingStrategy, NamingStrategy},
+    order::ItemOrder,
     r#enum::{DefaultEnumGen, EnumGenerator},
     r#struct::{DefaultStructGen, StructGenerator},
+    root_element::{DefaultRootElementGen, RootElementGenerator},
     struct_field::{DefaultStructFieldGen, StructFieldGenerator},
     tuple_struct::{DefaultTupleStructGen, TupleStructGenerator},
+    type_config::TypeConfig,
     Generator,
 };
 
@@ -57,6 +61,95 @@ impl<'input> GeneratorBuilder<'input> {
         self
     }
 
+    pub fn with_root_element_gen(mut self, re: Box<dyn RootElementGenerator>) -> Self {
+        self.gen.root_element_gen = Some(re);
+        self
+    }
+
+    pub fn with_naming(mut self, naming: Box<dyn NamingStrategy + 'input>) -> Self {
+        self.gen.naming = Some(naming);
+        self
+    }
+
+    pub fn with_type_mapping(mut self, xsd_type: &str, rust_type: &str) -> Self {
+        self.gen.type_mappings.insert(xsd_type.to_string(), rust_type.to_string());
+        self
+    }
+
+    /// Adds a derive to every generated type. Standard traits (`Clone`, `Eq`, `Hash`, ...)
+    /// are skipped for types whose fields cannot implement them.
+    pub fn with_derive(mut self, derive: &str) -> Self {
+        self.gen.type_config.derives.push(derive.to_string());
+        self
+    }
+
+    pub fn with_type_derive(mut self, type_name: &str, derive: &str) -> Self {
+        self.type_config(type_name).derives.push(derive.to_string());
+        self
+    }
+
+    /// Adds an attribute, e.g. `#[non_exhaustive]`, to every generated type.
+    pub fn with_attribute(mut self, attribute: &str) -> Self {
+        self.gen.type_config.attributes.push(normalize_attribute(attribute));
+        self
+    }
+
+    pub fn with_type_attribute(mut self, type_name: &str, attribute: &str) -> Self {
+        self.type_config(type_name).attributes.push(normalize_attribute(attribute));
+        self
+    }
+
+    /// Sets the visibility of generated types, e.g. `pub(crate)`. Defaults to `pub`.
+    pub fn with_visibility(mut self, visibility: &str) -> Self {
+        self.gen.type_config.visibility = Some(visibility.trim().to_string());
+        self
+    }
+
+    pub fn with_type_visibility(mut self, type_name: &str, visibility: &str) -> Self {
+        self.type_config(type_name).visibility = Some(visibility.trim().to_string());
+        self
+    }
+
+    /// Generates a type extending a complex type as a struct holding its base in a flattened
+    /// `base` field, instead of copying the fields of the base into it. The struct derefs to
+    /// its base and converts into it.
+    pub fn with_composed_extensions(mut self) -> Self {
+        self.gen.compose_extensions = true;
+        self
+    }
+
+    /// Generates an `elements` module with functions reading and writing documents whose
+    /// root is a global element of the schema.
+    pub fn with_root_elements(mut self) -> Self {
+        self.gen.root_elements = true;
+        self
+    }
+
+    /// Generates only the types the element or type `name` reaches, along with the ones of
+    /// the other roots. The names of the types left out are in `Generator::pruned_types`, and
+    /// the roots matching nothing in `Generator::unmatched_roots`.
+    pub fn with_root(mut self, name: &str) -> Self {
+        self.gen.roots.push(name.to_string());
+        self
+    }
+
+    /// Sets the order of the items of generated files, the one of the schema by default.
+    pub fn with_item_order(mut self, order: ItemOrder) -> Self {
+        self.gen.item_order = order;
+        self
+    }
+
+    /// Documents types with the `xs:documentation` elements whose `xml:lang` is `lang`, or
+    /// has it as primary language, along with the ones without a language.
+    pub fn with_doc_lang(mut self, lang: &str) -> Self {
+        self.gen.doc_lang = Some(lang.to_string());
+        self
+    }
+
+    fn type_config(&mut self, type_name: &str) -> &mut TypeConfig {
+        self.gen.type_configs.entry(type_name.to_string()).or_default()
+    }
+
     pub fn build(self) -> Generator<'input> {
         let mut gen = self.gen;
         gen.base.get_or_insert_with(|| Box::new(DefaultBaseGenerator {})); //.set_target_ns(&gen.target_ns);
@@ -75,15 +168,31 @@ impl<'input> GeneratorBuilder<'input> {
 
         gen.import_gen.get_or_insert_with(|| Box::new(DefaultImportGen {}));
 
+        gen.root_element_gen.get_or_insert_with(|| Box::new(DefaultRootElementGen {}));
+
+        gen.naming.get_or_insert_with(|| Box::new(DefaultNamingStrategy {}));
+
         gen
     }
 }
 
+fn normalize_attribute(attribute: &str) -> String {
+    let attribute = attribute.trim();
+    if attribute.starts_with("#[") {
+        attribute.to_string()
+    } else {
+        format!("#[{}]", attribute)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
         generator::{builder::GeneratorBuilder, tuple_struct::TupleStructGenerator, Generator},
-        parser::types::{RsEntity, TupleStruct},
+        parser::{
+            parse,
+            types::{RsEntity, TupleStruct},
+        },
     };
 
     fn test_generator_state(gen: &Generator) {
@@ -95,6 +204,8 @@ mod test {
         assert!(gen.enum_gen.is_some());
         assert!(gen.alias_gen.is_some());
         assert!(gen.import_gen.is_some());
+        assert!(gen.root_element_gen.is_some());
+        assert!(gen.naming.is_some());
     }
 
     #[test]
@@ -122,4 +233,82 @@ mod test {
         let ts = RsEntity::TupleStruct(TupleStruct::default());
         assert_eq!(gen.generate(&ts), "Tuple struct");
     }
+
+    #[test]
+    fn test_builder_with_type_mapping() {
+        let gen = GeneratorBuilder::default()
+            .with_type_mapping("xs:dateTime", "chrono::DateTime<chrono::Utc>")
+            .build();
+
+        assert_eq!(
+            gen.base().format_type_name("xs:dateTime", &gen),
+            "chrono::DateTime<chrono::Utc>"
+        );
+        assert_eq!(gen.base().format_type_name("tt:Foo", &gen), "tt::Foo");
+    }
+
+    #[test]
+    fn test_builder_with_type_mapping_of_other_xsd_prefix() {
+        let schema = parse(
+            r#"
+    <xsd:schema xmlns:xsd="http://www.w3.org/2001/XMLSchema">
+        <xsd:complexType name="Event">
+            <xsd:sequence>
+                <xsd:element name="Time" type="xsd:dateTime"/>
+            </xsd:sequence>
+        </xsd:complexType>
+    </xsd:schema>
+            "#,
+        )
+        .unwrap();
+
+        let gen = GeneratorBuilder::default()
+            .with_type_mapping("xs:dateTime", "chrono::DateTime<chrono::Utc>")
+            .build();
+        let code = gen.generate_rs_file(&schema);
+
+        assert!(code.contains("pub time: chrono::DateTime<chrono::Utc>,"), "{}", code);
+    }
+
+    #[test]
+    fn test_builder_with_type_config() {
+        let schema = parse(
+            r#"
+    <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:complexType name="Point">
+            <xs:sequence>
+                <xs:element name="Name" type="xs:string"/>
+            </xs:sequence>
+        </xs:complexType>
+        <xs:complexType name="Measure">
+            <xs:sequence>
+                <xs:element name="Value" type="xs:double"/>
+                <xs:element name="Point" type="Point"/>
+            </xs:sequence>
+        </xs:complexType>
+    </xs:schema>
+            "#,
+        )
+        .unwrap();
+
+        let gen = GeneratorBuilder::default()
+            .with_derive("Clone")
+            .with_derive("Eq")
+            .with_type_derive("Point", "std::hash::Hash")
+            .with_attribute("non_exhaustive")
+            .with_type_attribute("Measure", "#[allow(dead_code)]")
+            .with_visibility("pub(crate)")
+            .with_type_visibility("Point", "pub")
+            .build();
+        let code = gen.generate_rs_file(&schema);
+
+        assert!(code.contains(
+            "#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize, Clone, Eq, std::hash::Hash)]"
+        ));
+        // f64 implements neither Eq nor Hash.
+        assert!(code
+            .contains("#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize, Clone)]"));
+        assert!(code.contains("#[yaserde()]\n#[non_exhaustive]\npub struct Point"));
+        assert!(code.contains("#[non_exhaustive]\n#[allow(dead_code)]\npub(crate) struct Measure"));
+    }
 }
@@ -1,12 +1,26 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, convert::TryFrom, ops::Add};
 
 use crate::{
-    generator::{validator::gen_validate_impl, Generator},
-    parser::types::{Enum, EnumSource},
+    generator::{
+        facets::effective_facets,
+        ident::disambiguate,
+        type_config::{enum_default_derives, trait_name},
+        utils::split_name,
+        validator::gen_validate_impl,
+        Generator,
+    },
+    parser::{
+        types::{Enum, EnumSource},
+        xsd_elements::WhiteSpace,
+    },
 };
 
 pub trait EnumGenerator {
     fn generate(&self, entity: &Enum, gen: &Generator) -> String {
+        if let Some(base) = typed_enum_base(entity, gen) {
+            return self.generate_typed(entity, &base, gen);
+        }
+
         let name = self.get_name(entity, gen);
         let default_case = format!(
             "impl Default for {name} {{\n\
@@ -20,7 +34,7 @@ pub trait EnumGenerator {
 
         format!(
             "{comment}{macros}\n\
-            pub enum {name} {{\n\
+            {vis} enum {name} {{\n\
                 {cases}\n\
                 {indent}__Unknown__({typename}),\n\
             }}\n\n\
@@ -30,6 +44,7 @@ pub trait EnumGenerator {
             indent = gen.base().indent(),
             comment = self.format_comment(entity, gen),
             macros = self.macros(entity, gen),
+            vis = gen.visibility(&entity.name),
             name = name,
             cases = self.cases(entity, gen),
             typename = self.get_type_name(entity, gen),
@@ -39,11 +54,179 @@ pub trait EnumGenerator {
         )
     }
 
+    /// Generates an enumeration over values of the Rust type `base`. Cases are matched
+    /// by parsed value and the enum gets `value()`, `TryFrom<base>` and an `ALL` constant.
+    fn generate_typed(&self, entity: &Enum, base: &str, gen: &Generator) -> String {
+        let name = self.get_name(entity, gen);
+        let indent = gen.base().indent();
+        let names = self.typed_case_names(entity, gen);
+        let values = entity
+            .cases
+            .iter()
+            .filter_map(|case| value_expression(&case.value, base, gen))
+            .collect::<Vec<String>>();
+        let conditions = entity
+            .cases
+            .iter()
+            .zip(&values)
+            .map(|(case, value)| match (base, case.value.trim()) {
+                // Strings compare with literals as they are.
+                ("String", _) => format!("value == {:?}", case.value),
+                // NaN equals nothing, itself included.
+                ("f32" | "f64", "NaN") => "value.is_nan()".to_string(),
+                _ => format!("value == {}", value),
+            })
+            .collect::<Vec<String>>();
+
+        let cases = entity
+            .cases
+            .iter()
+            .zip(&names)
+            .map(|(case, case_name)| {
+                format!(
+                    "{comment}{indent}{name},",
+                    comment = gen.enum_case_gen().format_comment(case, gen),
+                    indent = indent,
+                    name = case_name
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        let all = names
+            .iter()
+            .map(|case_name| format!("{}::{}", name, case_name))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let value_arms = names
+            .iter()
+            .zip(&values)
+            .map(|(case_name, value)| {
+                format!(
+                    "{indent}{indent}{indent}{name}::{case} => {value},\n",
+                    indent = indent,
+                    name = name,
+                    case = case_name,
+                    value = value
+                )
+            })
+            .collect::<String>();
+
+        let unknown_value = if is_copy(base) { "*value" } else { "value.clone()" };
+
+        let conversions = names
+            .iter()
+            .zip(&conditions)
+            .map(|(case_name, condition)| {
+                format!(
+                    "if {condition} {{\n\
+                    {indent}{indent}{indent}Ok({name}::{case})\n\
+                    {indent}{indent}}} else ",
+                    indent = indent,
+                    name = name,
+                    case = case_name,
+                    condition = condition
+                )
+            })
+            .collect::<String>();
+
+        let display_arms = names
+            .iter()
+            .zip(&entity.cases)
+            .map(|(case_name, case)| {
+                format!(
+                    "{indent}{indent}{indent}{name}::{case} => f.write_str({value:?}),\n",
+                    indent = indent,
+                    name = name,
+                    case = case_name,
+                    value = case.value
+                )
+            })
+            .collect::<String>();
+
+        format!(
+            "{comment}{macros}\n\
+            {vis} enum {name} {{\n\
+                {cases}\n\
+                {indent}__Unknown__({base}),\n\
+            }}\n\n\
+            impl {name} {{\n\
+                {indent}pub const ALL: &[{name}] = &[{all}];\n\n\
+                {indent}pub fn value(&self) -> {base} {{\n\
+                    {indent}{indent}match self {{\n\
+                        {value_arms}\
+                        {indent}{indent}{indent}{name}::__Unknown__(value) => {unknown_value},\n\
+                    {indent}{indent}}}\n\
+                {indent}}}\n\
+            }}\n\n\
+            impl std::convert::TryFrom<{base}> for {name} {{\n\
+                {indent}type Error = {base};\n\n\
+                {indent}fn try_from(value: {base}) -> Result<Self, Self::Error> {{\n\
+                    {indent}{indent}{conversions}{{\n\
+                        {indent}{indent}{indent}Err(value)\n\
+                    {indent}{indent}}}\n\
+                {indent}}}\n\
+            }}\n\n\
+            impl std::str::FromStr for {name} {{\n\
+                {indent}type Err = String;\n\n\
+                {indent}fn from_str(s: &str) -> Result<Self, Self::Err> {{\n\
+                    {indent}{indent}let value = {parse};\n\
+                    {indent}{indent}Ok(<Self as std::convert::TryFrom<{base}>>::try_from(value).unwrap_or_else(Self::__Unknown__))\n\
+                {indent}}}\n\
+            }}\n\n\
+            impl std::fmt::Display for {name} {{\n\
+                {indent}fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {{\n\
+                    {indent}{indent}match self {{\n\
+                        {display_arms}\
+                        {indent}{indent}{indent}{name}::__Unknown__(value) => write!(f, \"{{}}\", value),\n\
+                    {indent}{indent}}}\n\
+                {indent}}}\n\
+            }}\n\n\
+            impl Default for {name} {{\n\
+                {indent}fn default() -> {name} {{\n\
+                    {indent}{indent}Self::__Unknown__(Default::default())\n\
+                {indent}}}\n\
+            }}\n\n\
+            {validation}\n\n\
+            {subtypes}\n\n",
+            indent = indent,
+            comment = self.format_comment(entity, gen),
+            macros = self.macros(entity, gen),
+            vis = gen.visibility(&entity.name),
+            name = name,
+            cases = cases,
+            base = base,
+            all = all,
+            value_arms = value_arms,
+            unknown_value = unknown_value,
+            conversions = conversions,
+            parse = parse_expression(entity, base, gen),
+            display_arms = display_arms,
+            validation = self.validation(entity, gen),
+            subtypes = self.subtypes(entity, gen),
+        )
+    }
+
+    /// Names of the cases of a typed enumeration, derived from their value, without its
+    /// prefix for QNames.
+    fn typed_case_names(&self, entity: &Enum, gen: &Generator) -> Vec<String> {
+        let qnames = is_qname_enum(entity, gen);
+        disambiguate(entity.cases.iter().map(|case| match qnames {
+            true => gen.naming().enum_variant_name(split_name(&case.value).1),
+            false => gen.enum_case_gen().get_name(case, gen),
+        }))
+    }
+
     fn cases(&self, entity: &Enum, gen: &Generator) -> String {
+        let names =
+            disambiguate(entity.cases.iter().map(|case| gen.enum_case_gen().get_name(case, gen)));
+
         entity
             .cases
             .iter()
-            .map(|case| gen.enum_case_gen().generate(case, gen))
+            .zip(names)
+            .map(|(case, name)| gen.enum_case_gen().generate_with_name(case, &name, gen))
             .collect::<Vec<String>>()
             .join("\n")
     }
@@ -61,11 +244,23 @@ pub trait EnumGenerator {
     }
 
     fn macros(&self, entity: &Enum, gen: &Generator) -> Cow<'static, str> {
-        if entity.source == EnumSource::Union {
-            return "#[derive(PartialEq, Debug, UtilsUnionSerDe)]".into();
+        let derives = format!(
+            "#[derive({})]",
+            gen.derives(&entity.name, enum_default_derives(entity, gen))
+                .into_iter()
+                .filter(|d| trait_name(d) != "Default") // Implemented by hand
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+        let attributes = gen.base().format_attributes(&entity.name, gen);
+
+        if entity.source == EnumSource::Union || typed_enum_base(entity, gen).is_some() {
+            return match attributes.is_empty() {
+                true => derives.into(),
+                false => format!("{}\n{}", derives, attributes.trim_end()).into(),
+            };
         }
 
-        let derives = "#[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]";
         let tns = gen.target_ns.borrow();
         match tns.as_ref() {
             Some(tn) => match tn.name() {
@@ -83,11 +278,12 @@ pub trait EnumGenerator {
             },
             None => format!("{derives}#[yaserde()]\n", derives = derives),
         }
+        .add(&attributes)
         .into()
     }
 
     fn format_comment(&self, entity: &Enum, gen: &Generator) -> String {
-        gen.base().format_comment(entity.comment.as_deref(), 0)
+        gen.base().format_comment(gen.documentation(entity.comment.as_ref()).as_deref(), 0)
     }
 
     fn validation(&self, entity: &Enum, gen: &Generator) -> Cow<'static, str> {
@@ -98,3 +294,198 @@ pub trait EnumGenerator {
 
 pub struct DefaultEnumGen;
 impl EnumGenerator for DefaultEnumGen {}
+
+/// Rust type of the values of an enumeration derived from a non-string built-in type,
+/// e.g. `i32` for `xs:int`, from a string type with whitespace normalization, like
+/// `xs:token`, or from `xs:QName`. Other enumerations, and the ones with values the Rust
+/// type has no literal or constructor for, are matched by their lexical value.
+pub fn typed_enum_base(entity: &Enum, gen: &Generator) -> Option<String> {
+    if entity.source != EnumSource::Restriction || entity.cases.is_empty() {
+        return None;
+    }
+
+    let facets = effective_facets(&entity.type_name, &[], gen);
+    let base = match facets.rust_type(gen)?.as_str() {
+        _ if is_qname_enum(entity, gen) => QNAME.to_string(),
+        "Vec<String>" => return None,
+        "String" if facets.whitespace() == WhiteSpace::Preserve => return None,
+        rust_type => rust_type.to_string(),
+    };
+    match entity.cases.iter().all(|case| value_expression(&case.value, &base, gen).is_some()) {
+        true => Some(base),
+        false => None,
+    }
+}
+
+const QNAME: &str = "xs::QName";
+
+fn is_qname_enum(entity: &Enum, gen: &Generator) -> bool {
+    let facets = effective_facets(&entity.type_name, &[], gen);
+    let built_in = facets.built_in.as_deref().map(|name| split_name(name).1);
+    matches!(built_in, Some("QName" | "NOTATION"))
+}
+
+fn typed_enum_whitespace(entity: &Enum, gen: &Generator) -> WhiteSpace {
+    effective_facets(&entity.type_name, &[], gen).whitespace()
+}
+
+fn is_copy(rust_type: &str) -> bool {
+    matches!(
+        rust_type,
+        "bool"
+            | "i8"
+            | "i16"
+            | "i32"
+            | "i64"
+            | "i128"
+            | "u8"
+            | "u16"
+            | "u32"
+            | "u64"
+            | "u128"
+            | "f32"
+            | "f64"
+            | "char"
+    )
+}
+
+/// Rust expression for an enumeration value of type `base`, a literal or a constructor.
+fn value_expression(value: &str, base: &str, gen: &Generator) -> Option<String> {
+    let value = value.trim();
+    match base {
+        "i8" | "i16" | "i32" | "i64" | "i128" | "u8" | "u16" | "u32" | "u64" | "u128" => {
+            let n = value.trim_start_matches('+').parse::<i128>().ok()?;
+            let fits = match base {
+                "i8" => i8::try_from(n).is_ok(),
+                "i16" => i16::try_from(n).is_ok(),
+                "i32" => i32::try_from(n).is_ok(),
+                "i64" => i64::try_from(n).is_ok(),
+                "u8" => u8::try_from(n).is_ok(),
+                "u16" => u16::try_from(n).is_ok(),
+                "u32" => u32::try_from(n).is_ok(),
+                "u64" => u64::try_from(n).is_ok(),
+                "u128" => n >= 0,
+                _ => true,
+            };
+            fits.then(|| format!("{}{}", n, base))
+        }
+        "f32" | "f64" => match value {
+            "INF" => Some(format!("{}::INFINITY", base)),
+            "-INF" => Some(format!("{}::NEG_INFINITY", base)),
+            "NaN" => Some(format!("{}::NAN", base)),
+            _ if base == "f32" => {
+                value.parse::<f32>().ok().filter(|f| f.is_finite()).map(|f| format!("{:?}f32", f))
+            }
+            _ => value.parse::<f64>().ok().filter(|f| f.is_finite()).map(|f| format!("{:?}f64", f)),
+        },
+        "bool" => match value {
+            "true" | "1" => Some("true".into()),
+            "false" | "0" => Some("false".into()),
+            _ => None,
+        },
+        "String" => Some(format!("{:?}.to_string()", value)),
+        "xs::Integer" | "xs::NonPositiveInteger" | "xs::NegativeInteger" => {
+            let n = value.trim_start_matches('+').parse::<i128>().ok()?;
+            Some(format!("{}::from_bigint(({}i128).into())", base, n))
+        }
+        "xs::NonNegativeInteger" | "xs::PositiveInteger" => {
+            let n = value.trim_start_matches('+').parse::<u128>().ok()?;
+            Some(format!("{}::from_biguint({}u128.into())", base, n))
+        }
+        "xs::Decimal" => {
+            let (digits, scale) = decimal_parts(value)?;
+            Some(format!("xs::Decimal::new({}, {})", digits, scale))
+        }
+        QNAME => {
+            let (prefix, local_name) = split_name(value);
+            let namespace = gen.namespace_uri(prefix);
+            if prefix.is_some() && namespace.is_none() {
+                return None;
+            }
+            Some(format!(
+                "xs::QName::new({}, {}, {:?})",
+                quote_option(prefix),
+                quote_option(namespace.as_deref()),
+                local_name
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn quote_option(s: Option<&str>) -> String {
+    match s {
+        Some(s) => format!("Some({:?})", s),
+        None => "None".to_string(),
+    }
+}
+
+// Digits and scale of a decimal, e.g. `(-125, 2)` for `-1.25`.
+fn decimal_parts(value: &str) -> Option<(i128, usize)> {
+    let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+    let (sign, integer) = match integer.strip_prefix('-') {
+        Some(integer) => ("-", integer),
+        None => ("", integer.trim_start_matches('+')),
+    };
+    let digits = format!("{}{}", integer, fraction);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some((format!("{}{}", sign, digits).parse().ok()?, fraction.len()))
+}
+
+/// Rust expression parsing `s` into a value of type `base`.
+fn parse_expression(entity: &Enum, base: &str, gen: &Generator) -> String {
+    match base {
+        "String" => match typed_enum_whitespace(entity, gen) {
+            WhiteSpace::Preserve => "s.to_string()".into(),
+            WhiteSpace::Replace => "xs::whitespace::replace(s).into_owned()".into(),
+            WhiteSpace::Collapse => "xs::whitespace::collapse(s).into_owned()".into(),
+        },
+        "bool" => "match s.trim() { \"true\" | \"1\" => true, \"false\" | \"0\" => false, \
+            other => return Err(format!(\"Invalid boolean: {}\", other)) }"
+            .into(),
+        // Prefixes are the ones of the schema, the empty one for its default namespace.
+        QNAME => {
+            let mut namespaces = vec![];
+            for case in &entity.cases {
+                let prefix = split_name(case.value.trim()).0;
+                if let Some(uri) = gen.namespace_uri(prefix) {
+                    let namespace = format!("({:?}, {:?})", prefix.unwrap_or_default(), uri);
+                    if !namespaces.contains(&namespace) {
+                        namespaces.push(namespace);
+                    }
+                }
+            }
+            format!("xs::QName::parse(s, &[{}])?", namespaces.join(", "))
+        }
+        _ => format!("s.trim().parse::<{}>().map_err(|e| e.to_string())?", base),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generator::builder::GeneratorBuilder;
+
+    #[test]
+    fn test_value_expression() {
+        let gen = GeneratorBuilder::default().build();
+        let value = |value, base| value_expression(value, base, &gen);
+        assert_eq!(value("01", "i32").as_deref(), Some("1i32"));
+        assert_eq!(value("+5", "u8").as_deref(), Some("5u8"));
+        assert_eq!(value("-1", "i64").as_deref(), Some("-1i64"));
+        assert_eq!(value("300", "u8"), None);
+        assert_eq!(value("1", "f64").as_deref(), Some("1.0f64"));
+        assert_eq!(value("-INF", "f32").as_deref(), Some("f32::NEG_INFINITY"));
+        assert_eq!(value("1", "bool").as_deref(), Some("true"));
+        assert_eq!(value("-1.25", "xs::Decimal").as_deref(), Some("xs::Decimal::new(-125, 2)"));
+        assert_eq!(
+            value("-3", "xs::Integer").as_deref(),
+            Some("xs::Integer::from_bigint((-3i128).into())")
+        );
+        assert_eq!(value("1", "xs::Duration"), None);
+        assert_eq!(value("a:On", "xs::QName"), None);
+        assert_eq!(value("On", "xs::QName").as_deref(), Some("xs::QName::new(None, None, \"On\")"));
+    }
+}
@@ -1,10 +1,15 @@
 use crate::{
-    generator::{default::default_format_type, utils::split_name, Generator},
+    generator::{utils::split_name, Generator},
     parser::types::{EnumCase, EnumSource},
 };
 
 pub trait EnumCaseGenerator {
     fn generate(&self, entity: &EnumCase, gen: &Generator) -> String {
+        self.generate_with_name(entity, &self.get_name(entity, gen), gen)
+    }
+
+    /// Generates the case as `name`, which is unique among the cases of its enum.
+    fn generate_with_name(&self, entity: &EnumCase, name: &str, gen: &Generator) -> String {
         let typename = if entity.type_name.is_some() {
             format!("({})", self.get_type_name(entity, gen))
         } else {
@@ -13,19 +18,18 @@ pub trait EnumCaseGenerator {
         format!(
             "{comment}{macros}{indent}{name}{typename},",
             indent = gen.base().indent(),
-            name = self.get_name(entity, gen),
+            name = name,
             comment = self.format_comment(entity, gen),
-            macros = self.macros(entity, gen),
+            macros = self.macros(entity, name, gen),
             typename = typename
         )
     }
 
     fn get_name(&self, entity: &EnumCase, gen: &Generator) -> String {
-        default_format_type(entity.name.as_str(), &gen.target_ns.borrow())
-            .split("::")
-            .last()
-            .unwrap()
-            .to_string()
+        match entity.source {
+            EnumSource::Restriction => gen.naming().enum_variant_name(&entity.value),
+            _ => gen.naming().enum_variant_name(split_name(&entity.name).1),
+        }
     }
 
     fn get_type_name(&self, entity: &EnumCase, gen: &Generator) -> String {
@@ -34,12 +38,24 @@ pub trait EnumCaseGenerator {
     }
 
     fn format_comment(&self, entity: &EnumCase, gen: &Generator) -> String {
-        gen.base().format_comment(entity.comment.as_deref(), gen.base().indent_size())
+        gen.base().format_comment(
+            gen.documentation(entity.comment.as_ref()).as_deref(),
+            gen.base().indent_size(),
+        )
     }
 
-    fn macros(&self, entity: &EnumCase, gen: &Generator) -> String {
-        if entity.source == EnumSource::Union {
-            return "".into();
+    fn macros(&self, entity: &EnumCase, name: &str, gen: &Generator) -> String {
+        match entity.source {
+            EnumSource::Union => return "".into(),
+            EnumSource::Restriction if entity.value == name => return "".into(),
+            EnumSource::Restriction => {
+                return format!(
+                    "{indent}#[yaserde(rename = {rename:?})]\n",
+                    indent = gen.base().indent(),
+                    rename = entity.value
+                )
+            }
+            _ => {}
         }
 
         let (prefix, field_name) = split_name(entity.name.as_str());
@@ -51,7 +67,7 @@ pub trait EnumCaseGenerator {
                 rename = field_name
             ),
             None => {
-                if field_name == self.get_name(entity, gen) {
+                if field_name == name {
                     "".into()
                 } else {
                     format!(
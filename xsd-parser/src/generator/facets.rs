@@ -0,0 +1,245 @@
+//! Effective facets of simple types, collected along their derivation chains.
+
+use std::{collections::HashMap, mem::discriminant};
+
+use crate::{
+    generator::{
+        utils::{local_name, match_built_in_type, split_name},
+        Generator,
+    },
+    parser::{
+        types::{EnumSource, RsEntity, RsFile, TypeModifier},
+        xsd_elements::{FacetType, WhiteSpace},
+    },
+};
+
+/// A named simple type of the schema: its base and the facets it declares.
+#[derive(Debug, Clone)]
+pub struct SimpleType {
+    pub base: String,
+    pub facets: Vec<FacetType>,
+    pub is_enum: bool,
+}
+
+pub fn collect_simple_types(schema: &RsFile) -> HashMap<String, SimpleType> {
+    schema
+        .types
+        .iter()
+        .filter_map(|entity| match entity {
+            RsEntity::TupleStruct(ts) if !ts.type_modifiers.contains(&TypeModifier::Array) => {
+                Some((
+                    ts.name.clone(),
+                    SimpleType {
+                        base: ts.type_name.clone(),
+                        facets: ts.facets.iter().map(|f| f.facet_type.clone()).collect(),
+                        is_enum: false,
+                    },
+                ))
+            }
+            RsEntity::Enum(en) if en.source == EnumSource::Restriction => Some((
+                en.name.clone(),
+                SimpleType {
+                    base: en.type_name.clone(),
+                    facets: en
+                        .cases
+                        .iter()
+                        .map(|c| FacetType::Enumeration(c.value.clone()))
+                        .collect(),
+                    is_enum: true,
+                },
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Facets which apply to a simple type, its own ones followed by the inherited ones.
+#[derive(Debug, Clone, Default)]
+pub struct EffectiveFacets {
+    pub facets: Vec<FacetType>,
+    /// Built-in type the derivation chain ends with, if it is known.
+    pub built_in: Option<String>,
+    /// Number of tuple structs between the type and its built-in value,
+    /// unless the chain goes through an enumeration.
+    pub depth: Option<usize>,
+}
+
+impl EffectiveFacets {
+    pub fn whitespace(&self) -> WhiteSpace {
+        self.facets
+            .iter()
+            .find_map(|f| match f {
+                FacetType::WhiteSpace(ws) => Some(ws.clone()),
+                _ => None,
+            })
+            .unwrap_or(WhiteSpace::Preserve)
+    }
+
+    /// Rust type of the built-in value.
+    pub fn rust_type(&self, gen: &Generator) -> Option<String> {
+        let built_in = self.built_in.as_ref()?;
+        gen.type_mapping(built_in)
+            .map(String::from)
+            .or_else(|| match_built_in_type(built_in, &gen.xsd_ns.borrow()).map(String::from))
+    }
+}
+
+/// Computes the facets of a type restricting `base` with `own` facets. A facet declared
+/// by a type overrides the inherited facet of the same kind, except for patterns,
+/// which all apply.
+pub fn effective_facets(base: &str, own: &[FacetType], gen: &Generator) -> EffectiveFacets {
+    let simple_types = gen.simple_types.borrow();
+    let mut res = EffectiveFacets { facets: own.to_vec(), built_in: None, depth: Some(0) };
+    let mut current = base.to_string();
+    let mut visited = vec![];
+
+    loop {
+        if is_built_in(&current, gen) {
+            inherit(&mut res.facets, &implicit_facets(&current, gen));
+            res.built_in = Some(current);
+            break;
+        }
+
+        let key = local_name(&current, gen).to_string();
+        let simple_type = match simple_types.get(&key) {
+            Some(st) if !visited.contains(&key) => st,
+            _ => break,
+        };
+        inherit(&mut res.facets, &simple_type.facets);
+        res.depth = match simple_type.is_enum {
+            true => None,
+            false => res.depth.map(|d| d + 1),
+        };
+        visited.push(key);
+        current = simple_type.base.clone();
+    }
+    res
+}
+
+fn inherit(facets: &mut Vec<FacetType>, inherited: &[FacetType]) {
+    let declared = facets.iter().map(discriminant).collect::<Vec<_>>();
+    facets.extend(
+        inherited
+            .iter()
+            .filter(|f| matches!(f, FacetType::Pattern(_)) || !declared.contains(&discriminant(f)))
+            .cloned(),
+    );
+}
+
+/// Whether `type_name` is a built-in or a named simple type of the schema.
+pub fn is_simple_type(type_name: &str, gen: &Generator) -> bool {
+    is_built_in(type_name, gen)
+        || gen.simple_types.borrow().contains_key(local_name(type_name, gen))
+}
+
+fn is_built_in(type_name: &str, gen: &Generator) -> bool {
+    gen.type_mapping(type_name).is_some()
+        || match_built_in_type(type_name, &gen.xsd_ns.borrow()).is_some()
+}
+
+// Facets of built-in types. Value ranges are only needed when the type is mapped to
+// a Rust type other than the default one, which enforces them when parsing.
+fn implicit_facets(type_name: &str, gen: &Generator) -> Vec<FacetType> {
+    let (prefix, name) = split_name(type_name);
+    let xsd_ns = gen.xsd_ns.borrow();
+    if prefix != xsd_ns.as_ref().and_then(|ns| ns.name()) {
+        return vec![];
+    }
+
+    let whitespace = match name {
+        "string" | "anySimpleType" => WhiteSpace::Preserve,
+        "normalizedString" => WhiteSpace::Replace,
+        _ => WhiteSpace::Collapse,
+    };
+    let mut facets = vec![FacetType::WhiteSpace(whitespace)];
+
+    if gen.type_mapping(type_name).is_some() {
+        let (min, max) = match name {
+            "byte" => (Some("-128"), Some("127")),
+            "short" => (Some("-32768"), Some("32767")),
+            "int" => (Some("-2147483648"), Some("2147483647")),
+            "long" => (Some("-9223372036854775808"), Some("9223372036854775807")),
+            "unsignedByte" => (Some("0"), Some("255")),
+            "unsignedShort" => (Some("0"), Some("65535")),
+            "unsignedInt" => (Some("0"), Some("4294967295")),
+            "unsignedLong" => (Some("0"), Some("18446744073709551615")),
+            "positiveInteger" => (Some("1"), None),
+            "nonNegativeInteger" => (Some("0"), None),
+            "negativeInteger" => (None, Some("-1")),
+            "nonPositiveInteger" => (None, Some("0")),
+            _ => (None, None),
+        };
+        facets.extend(min.map(|v| FacetType::MinInclusive(v.to_string())));
+        facets.extend(max.map(|v| FacetType::MaxInclusive(v.to_string())));
+    }
+    facets
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{generator::builder::GeneratorBuilder, parser::parse};
+
+    const SCHEMA: &str = r#"
+    <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"
+               xmlns:tns="http://example.com"
+               targetNamespace="http://example.com">
+        <xs:simpleType name="Code">
+            <xs:restriction base="xs:token">
+                <xs:maxLength value="8"/>
+                <xs:pattern value="[A-Z]+"/>
+            </xs:restriction>
+        </xs:simpleType>
+        <xs:simpleType name="ShortCode">
+            <xs:restriction base="tns:Code">
+                <xs:maxLength value="4"/>
+                <xs:pattern value="[A-F]+"/>
+            </xs:restriction>
+        </xs:simpleType>
+    </xs:schema>
+    "#;
+
+    #[test]
+    fn test_effective_facets() {
+        let schema = parse(SCHEMA).unwrap();
+        let gen = GeneratorBuilder::default().build();
+        *gen.xsd_ns.borrow_mut() = schema.xsd_ns.clone();
+        *gen.target_ns.borrow_mut() = schema.target_ns.clone();
+        *gen.simple_types.borrow_mut() = collect_simple_types(&schema);
+
+        let facets = effective_facets("tns:Code", &[FacetType::MaxLength("4".into())], &gen);
+        assert_eq!(
+            facets.facets,
+            vec![
+                FacetType::MaxLength("4".into()),
+                FacetType::Pattern("[A-Z]+".into()),
+                FacetType::WhiteSpace(WhiteSpace::Collapse),
+            ]
+        );
+        assert_eq!(facets.built_in.as_deref(), Some("xs:token"));
+        assert_eq!(facets.depth, Some(1));
+        assert_eq!(facets.whitespace(), WhiteSpace::Collapse);
+        assert_eq!(facets.rust_type(&gen).as_deref(), Some("String"));
+    }
+
+    #[test]
+    fn test_implicit_ranges_of_mapped_types() {
+        let schema = parse(SCHEMA).unwrap();
+        let gen = GeneratorBuilder::default().with_type_mapping("xs:unsignedByte", "i32").build();
+        *gen.xsd_ns.borrow_mut() = schema.xsd_ns.clone();
+
+        let facets = effective_facets("xs:unsignedByte", &[], &gen);
+        assert_eq!(
+            facets.facets,
+            vec![
+                FacetType::WhiteSpace(WhiteSpace::Collapse),
+                FacetType::MinInclusive("0".into()),
+                FacetType::MaxInclusive("255".into()),
+            ]
+        );
+        assert_eq!(facets.rust_type(&gen).as_deref(), Some("i32"));
+
+        let facets = effective_facets("xs:string", &[], &gen);
+        assert_eq!(facets.facets, vec![FacetType::WhiteSpace(WhiteSpace::Preserve)]);
+    }
+}
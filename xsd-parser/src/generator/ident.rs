@@ -0,0 +1,307 @@
+//! Building blocks for turning arbitrary XSD names and enumeration values into
+//! Rust identifiers.
+
+use std::collections::HashSet;
+
+use inflector::cases::{pascalcase::to_pascal_case, snakecase::to_snake_case};
+
+/// Splits `name` into ASCII words.
+///
+/// Whitespace, `_`, `.`, `:` and `/` separate words, as does `-` between two
+/// alphanumeric characters. Other symbols are spelled out (`+` is `Plus`, a leading
+/// `-` is `Minus`), non-ASCII letters are transliterated and anything else becomes
+/// its code point, e.g. `U2764`. A name made of separators only is spelled out too.
+pub fn words(name: &str) -> Vec<String> {
+    let chars = name.chars().collect::<Vec<char>>();
+    let mut words = vec![];
+    let mut current = String::new();
+
+    fn flush(current: &mut String, words: &mut Vec<String>) {
+        if !current.is_empty() {
+            words.push(std::mem::take(current));
+        }
+    }
+
+    for (i, &c) in chars.iter().enumerate() {
+        let between_alphanumerics = || {
+            i > 0
+                && chars[i - 1].is_alphanumeric()
+                && chars.get(i + 1).is_some_and(|n| n.is_alphanumeric())
+        };
+
+        if c.is_ascii_alphanumeric() {
+            current.push(c);
+        } else if let Some(s) = transliterate(c) {
+            current.push_str(&s);
+        } else if is_separator(c) || (c == '-' && between_alphanumerics()) {
+            flush(&mut current, &mut words);
+        } else if let Some(s) = spell(c) {
+            flush(&mut current, &mut words);
+            words.push(s.to_string());
+        } else {
+            flush(&mut current, &mut words);
+            words.push(format!("U{:04X}", c as u32));
+        }
+    }
+    flush(&mut current, &mut words);
+
+    if words.is_empty() {
+        return chars.iter().filter_map(|c| spell(*c)).map(|s| s.to_string()).collect();
+    }
+    words
+}
+
+pub fn pascal_case(name: &str) -> String {
+    to_pascal_case(&words(name).join("_"))
+}
+
+pub fn snake_case(name: &str) -> String {
+    to_snake_case(&words(name).join("_"))
+}
+
+/// Makes `ident` a valid identifier: keywords become raw identifiers, so a field named
+/// `type` is `r#type`. Names starting with a digit and the keywords which cannot be raw
+/// (`self`, `Self`, `super` and `crate`) get a `_` prefix instead.
+pub fn escape(ident: String) -> String {
+    match ident.chars().next() {
+        None => ident,
+        Some(c) if c.is_numeric() || NON_RAW_KEYWORDS.contains(&ident.as_str()) => {
+            format!("_{}", ident)
+        }
+        _ if RS_KEYWORDS.contains(&ident.as_str()) => format!("r#{}", ident),
+        _ => ident,
+    }
+}
+
+/// Makes names unique by appending the smallest free number to repeated ones.
+/// The first occurrence of a name keeps it, so the result only depends on the order.
+pub fn disambiguate<I: IntoIterator<Item = String>>(names: I) -> Vec<String> {
+    let names = names.into_iter().collect::<Vec<String>>();
+    let mut taken = names.iter().cloned().collect::<HashSet<String>>();
+    let mut seen = HashSet::new();
+
+    names
+        .into_iter()
+        .map(|name| {
+            if name.is_empty() || seen.insert(name.clone()) {
+                return name;
+            }
+            let unique = (2..)
+                .map(|n| format!("{}{}", name, n))
+                .find(|candidate| !taken.contains(candidate))
+                .unwrap();
+            taken.insert(unique.clone());
+            seen.insert(unique.clone());
+            unique
+        })
+        .collect()
+}
+
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '_' | '.' | ':' | '/')
+}
+
+fn spell(c: char) -> Option<&'static str> {
+    Some(match c {
+        '+' => "Plus",
+        '-' => "Minus",
+        '*' => "Star",
+        '/' => "Slash",
+        '\\' => "Backslash",
+        '.' => "Dot",
+        ',' => "Comma",
+        ':' => "Colon",
+        ';' => "Semicolon",
+        '=' => "Equals",
+        '<' => "Lt",
+        '>' => "Gt",
+        '!' => "Bang",
+        '?' => "Question",
+        '@' => "At",
+        '#' => "Hash",
+        '$' => "Dollar",
+        '%' => "Percent",
+        '&' => "Amp",
+        '|' => "Pipe",
+        '~' => "Tilde",
+        '^' => "Caret",
+        '(' => "LParen",
+        ')' => "RParen",
+        '[' => "LBracket",
+        ']' => "RBracket",
+        '{' => "LBrace",
+        '}' => "RBrace",
+        '\'' => "Apostrophe",
+        '"' => "Quote",
+        '`' => "Backtick",
+        '_' => "Underscore",
+        _ => return None,
+    })
+}
+
+const TRANSLITERATIONS: &[(&str, &str)] = &[
+    ("àáâãäåāăą", "a"),
+    ("çćĉċč", "c"),
+    ("ďđð", "d"),
+    ("èéêëēĕėęě", "e"),
+    ("ĝğġģ", "g"),
+    ("ĥħ", "h"),
+    ("ìíîïĩīĭįı", "i"),
+    ("ĵ", "j"),
+    ("ķ", "k"),
+    ("ĺļľŀł", "l"),
+    ("ñńņň", "n"),
+    ("òóôõöøōŏő", "o"),
+    ("ŕŗř", "r"),
+    ("śŝşš", "s"),
+    ("ţťŧ", "t"),
+    ("ùúûüũūŭůűų", "u"),
+    ("ŵ", "w"),
+    ("ýÿŷ", "y"),
+    ("źżž", "z"),
+    ("ß", "ss"),
+    ("æ", "ae"),
+    ("œ", "oe"),
+    ("þ", "th"),
+    // Greek
+    ("αά", "a"),
+    ("β", "b"),
+    ("γ", "g"),
+    ("δ", "d"),
+    ("εέ", "e"),
+    ("ζ", "z"),
+    ("ηή", "i"),
+    ("θ", "th"),
+    ("ιίϊ", "i"),
+    ("κ", "k"),
+    ("λ", "l"),
+    ("μ", "m"),
+    ("ν", "n"),
+    ("ξ", "x"),
+    ("οό", "o"),
+    ("π", "p"),
+    ("ρ", "r"),
+    ("σς", "s"),
+    ("τ", "t"),
+    ("υύϋ", "y"),
+    ("φ", "f"),
+    ("χ", "ch"),
+    ("ψ", "ps"),
+    ("ωώ", "o"),
+    // Cyrillic
+    ("а", "a"),
+    ("б", "b"),
+    ("в", "v"),
+    ("г", "g"),
+    ("д", "d"),
+    ("еэ", "e"),
+    ("ё", "yo"),
+    ("ж", "zh"),
+    ("з", "z"),
+    ("иі", "i"),
+    ("йы", "y"),
+    ("к", "k"),
+    ("л", "l"),
+    ("м", "m"),
+    ("н", "n"),
+    ("о", "o"),
+    ("п", "p"),
+    ("р", "r"),
+    ("с", "s"),
+    ("т", "t"),
+    ("у", "u"),
+    ("ф", "f"),
+    ("х", "kh"),
+    ("ц", "ts"),
+    ("ч", "ch"),
+    ("ш", "sh"),
+    ("щ", "shch"),
+    ("ъь", ""),
+    ("ю", "yu"),
+    ("я", "ya"),
+    ("є", "ye"),
+    ("ї", "yi"),
+];
+
+fn transliterate(c: char) -> Option<String> {
+    if c.is_ascii() {
+        return None;
+    }
+    let lower = c.to_lowercase().next()?;
+    let (_, latin) = TRANSLITERATIONS.iter().find(|(chars, _)| chars.contains(lower))?;
+
+    let mut res = latin.to_string();
+    if c.is_uppercase() {
+        if let Some(first) = res.get_mut(0..1) {
+            first.make_ascii_uppercase();
+        }
+    }
+    Some(res)
+}
+
+// Strict and reserved keywords of all editions.
+const RS_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+// Keywords which are not allowed as raw identifiers.
+const NON_RAW_KEYWORDS: &[&str] = &["self", "Self", "super", "crate"];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_words() {
+        assert_eq!(words("SomeType"), vec!["SomeType"]);
+        assert_eq!(words("Some-Type"), vec!["Some", "Type"]);
+        assert_eq!(words("Some Type"), vec!["Some", "Type"]);
+        assert_eq!(words("a.b_c:d/e"), vec!["a", "b", "c", "d", "e"]);
+        assert_eq!(words("Some@Type"), vec!["Some", "At", "Type"]);
+        assert_eq!(words("Some❤Type"), vec!["Some", "U2764", "Type"]);
+        assert_eq!(words("+"), vec!["Plus"]);
+        assert_eq!(words("-"), vec!["Minus"]);
+        assert_eq!(words("-1"), vec!["Minus", "1"]);
+        assert_eq!(words("a+b"), vec!["a", "Plus", "b"]);
+        assert_eq!(words("."), vec!["Dot"]);
+        assert_eq!(words("Größe"), vec!["Grosse"]);
+        assert_eq!(words("Ärger"), vec!["Arger"]);
+        assert_eq!(words("Привет"), vec!["Privet"]);
+        assert_eq!(words(""), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_cases() {
+        assert_eq!(pascal_case("IANA_IfTypes"), "IanaIfTypes");
+        assert_eq!(pascal_case("a+b"), "APlusB");
+        assert_eq!(pascal_case("-"), "Minus");
+        assert_eq!(snake_case("StructName"), "struct_name");
+        assert_eq!(snake_case("+"), "plus");
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("type".into()), "r#type");
+        assert_eq!(escape("gen".into()), "r#gen");
+        assert_eq!(escape("Self".into()), "_Self");
+        assert_eq!(escape("self".into()), "_self");
+        assert_eq!(escape("crate".into()), "_crate");
+        assert_eq!(escape("0fst".into()), "_0fst");
+        assert_eq!(escape("name".into()), "name");
+        assert_eq!(escape("proc".into()), "proc");
+        assert_eq!(escape("sizeof".into()), "sizeof");
+    }
+
+    #[test]
+    fn test_disambiguate() {
+        let names = |v: &[&str]| disambiguate(v.iter().map(|s| s.to_string()));
+        assert_eq!(names(&["AB", "AB", "AB"]), vec!["AB", "AB2", "AB3"]);
+        assert_eq!(names(&["A", "A", "A2"]), vec!["A", "A3", "A2"]);
+        assert_eq!(names(&["_Self", "_Self"]), vec!["_Self", "_Self2"]);
+        assert_eq!(names(&["A", "B"]), vec!["A", "B"]);
+    }
+}
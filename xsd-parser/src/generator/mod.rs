@@ -1,33 +1,82 @@
+pub mod abstract_type;
 pub mod alias;
 pub mod base;
 pub mod builder;
 pub mod default;
 pub mod r#enum;
 pub mod enum_case;
+pub mod facets;
+pub mod ident;
 pub mod import;
+pub mod naming;
+pub mod order;
+pub mod reachability;
+pub mod root_element;
+pub mod split;
 pub mod r#struct;
 pub mod struct_field;
 pub mod tuple_struct;
+pub mod type_config;
 mod utils;
 pub mod validator;
 
-use std::{borrow::Borrow, cell::RefCell};
+pub use utils::match_built_in_type;
+
+use std::{
+    borrow::Borrow,
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+};
 
 use roxmltree::Namespace;
 
 use crate::{
     generator::{
-        alias::AliasGenerator, base::BaseGenerator, enum_case::EnumCaseGenerator,
-        import::ImportGenerator, r#enum::EnumGenerator, r#struct::StructGenerator,
-        struct_field::StructFieldGenerator, tuple_struct::TupleStructGenerator,
+        abstract_type::{collect_abstract_types, AbstractType},
+        alias::AliasGenerator,
+        base::BaseGenerator,
+        enum_case::EnumCaseGenerator,
+        facets::{collect_simple_types, SimpleType},
+        import::ImportGenerator,
+        naming::{rename_nested_types, NamingStrategy},
+        order::{sort_types, ItemOrder},
+        r#enum::EnumGenerator,
+        r#struct::StructGenerator,
+        reachability::prune_unreachable,
+        root_element::RootElementGenerator,
+        split::{module_of, GeneratedModule, GeneratedTree, SplitStrategy},
+        struct_field::StructFieldGenerator,
+        tuple_struct::TupleStructGenerator,
+        type_config::{boxed_key, boxed_types, derive_support, trait_name, TypeConfig, BOX_DERIVE},
+        utils::split_name,
     },
-    parser::types::{RsEntity, RsFile},
+    parser::types::{Documentation, RsEntity, RsFile},
 };
 
 #[derive(Default)]
 pub struct Generator<'input> {
     pub target_ns: RefCell<Option<Namespace<'input>>>,
     pub xsd_ns: RefCell<Option<Namespace<'input>>>,
+    /// Prefixed namespaces declared on the schema.
+    pub namespaces: RefCell<Vec<Namespace<'input>>>,
+    pub type_mappings: HashMap<String, String>,
+    pub type_config: TypeConfig,
+    pub type_configs: HashMap<String, TypeConfig>,
+    pub derive_support: RefCell<HashMap<String, HashSet<String>>>,
+    pub simple_types: RefCell<HashMap<String, SimpleType>>,
+    pub boxed_types: RefCell<HashSet<String>>,
+    pub abstract_types: RefCell<Vec<AbstractType>>,
+    pub compose_extensions: bool,
+    pub root_elements: bool,
+    /// Elements or types to generate along with what they reach, everything if empty.
+    pub roots: Vec<String>,
+    /// Types left out of the last generated file because no root reaches them.
+    pub pruned_types: RefCell<Vec<String>>,
+    /// Roots matching no element or type of the last generated file.
+    pub unmatched_roots: RefCell<Vec<String>>,
+    pub item_order: ItemOrder,
+    /// Language of the `xs:documentation` elements to use, all of them if not set.
+    pub doc_lang: Option<String>,
 
     pub tuple_struct_gen: Option<Box<dyn TupleStructGenerator>>,
     pub struct_gen: Option<Box<dyn StructGenerator>>,
@@ -37,13 +86,70 @@ pub struct Generator<'input> {
     pub enum_gen: Option<Box<dyn EnumGenerator>>,
     pub alias_gen: Option<Box<dyn AliasGenerator>>,
     pub import_gen: Option<Box<dyn ImportGenerator>>,
+    pub root_element_gen: Option<Box<dyn RootElementGenerator>>,
+    pub naming: Option<Box<dyn NamingStrategy + 'input>>,
 }
 
 impl<'input> Generator<'input> {
     pub fn generate_rs_file(&self, schema: &RsFile<'input>) -> String {
+        let schema = self.prepare(schema);
+        let code = schema.types.iter().map(|entity| self.generate(entity)).collect::<String>();
+        code + &self.generate_root_elements(&schema)
+    }
+
+    /// Generates the code of a schema split into modules as `split` requires.
+    pub fn generate_rs_tree(&self, schema: &RsFile<'input>, split: SplitStrategy) -> GeneratedTree {
+        let schema = self.prepare(schema);
+        let prefix = schema.target_ns.as_ref().and_then(|ns| ns.name());
+
+        let mut tree = GeneratedTree::default();
+        let mut index = 0;
+        for entity in &schema.types {
+            let code = self.generate(entity);
+            match module_of(entity, index, split, prefix) {
+                Some(name) => {
+                    index += 1;
+                    match tree.modules.iter_mut().find(|m| m.name == name) {
+                        Some(module) => module.code.push_str(&code),
+                        None => tree.modules.push(GeneratedModule {
+                            name,
+                            code: format!("use super::*;\n\n{}", code),
+                        }),
+                    }
+                }
+                None => tree.root.push_str(&code),
+            }
+        }
+        tree.root.push_str(&self.generate_root_elements(&schema));
+        tree
+    }
+
+    // Sets the state of the generator for the schema and returns the schema to generate.
+    fn prepare(&self, schema: &RsFile<'input>) -> RsFile<'input> {
+        let mut schema = schema.clone();
+        rename_nested_types(&mut schema.types, self.naming());
+
         *self.target_ns.borrow_mut() = schema.target_ns.clone();
         *self.xsd_ns.borrow_mut() = schema.xsd_ns.clone();
-        schema.types.iter().map(|entity| self.generate(entity)).collect()
+        *self.namespaces.borrow_mut() = schema.namespaces.clone();
+        if !self.roots.is_empty() {
+            let pruned = prune_unreachable(&mut schema, &self.roots, self);
+            *self.pruned_types.borrow_mut() = pruned.dropped;
+            *self.unmatched_roots.borrow_mut() = pruned.unmatched_roots;
+        }
+        sort_types(&mut schema.types, self.item_order, self);
+        *self.simple_types.borrow_mut() = collect_simple_types(&schema);
+        *self.boxed_types.borrow_mut() = boxed_types(&schema, self);
+        *self.abstract_types.borrow_mut() = collect_abstract_types(&schema);
+        *self.derive_support.borrow_mut() = derive_support(&schema, self);
+        schema
+    }
+
+    fn generate_root_elements(&self, schema: &RsFile) -> String {
+        match self.root_elements {
+            true => self.root_element_gen.as_ref().unwrap().generate(schema, self),
+            false => String::new(),
+        }
     }
 
     pub fn generate(&self, entity: &RsEntity) -> String {
@@ -70,6 +176,94 @@ impl<'input> Generator<'input> {
     pub fn enum_case_gen(&self) -> &dyn EnumCaseGenerator {
         self.enum_case_gen.as_ref().unwrap().borrow()
     }
+
+    pub fn naming(&self) -> &dyn NamingStrategy {
+        self.naming.as_ref().unwrap().borrow()
+    }
+
+    /// Text of the documentation in the configured language.
+    pub fn documentation(&self, doc: Option<&Documentation>) -> Option<String> {
+        doc.map(|doc| doc.text(self.doc_lang.as_deref()))
+    }
+
+    /// Default derives of a type followed by the configured ones, without duplicates.
+    pub fn requested_derives(&self, type_name: &str, defaults: &[&str]) -> Vec<String> {
+        let mut seen = HashSet::new();
+        let boxed = Some(BOX_DERIVE)
+            .filter(|_| self.boxed_types.borrow().contains(&boxed_key(type_name, self)));
+        defaults
+            .iter()
+            .chain(boxed.iter())
+            .map(|d| d.to_string())
+            .chain(self.type_config.derives.iter().cloned())
+            .chain(self.type_configs.get(type_name).into_iter().flat_map(|c| c.derives.clone()))
+            .filter(|d| seen.insert(trait_name(d).to_string()))
+            .collect()
+    }
+
+    /// Requested derives of a type, except for the standard traits its fields cannot support.
+    pub fn derives(&self, type_name: &str, defaults: &[&str]) -> Vec<String> {
+        let support = self.derive_support.borrow();
+        self.requested_derives(type_name, defaults)
+            .into_iter()
+            .filter(|d| match support.get(type_name) {
+                Some(traits) => {
+                    defaults.contains(&d.as_str())
+                        || traits.contains(trait_name(d))
+                        || !type_config::is_checked_trait(trait_name(d))
+                }
+                None => true,
+            })
+            .collect()
+    }
+
+    /// Namespace the schema binds `prefix` to, `None` for the default namespace. The default
+    /// namespace is only known when it is the target or the XSD namespace.
+    pub fn namespace_uri(&self, prefix: Option<&str>) -> Option<String> {
+        let target_ns = self.target_ns.borrow();
+        let xsd_ns = self.xsd_ns.borrow();
+        self.namespaces
+            .borrow()
+            .iter()
+            .chain(target_ns.iter())
+            .chain(xsd_ns.iter())
+            .find(|ns| ns.name() == prefix)
+            .map(|ns| ns.uri().to_string())
+    }
+
+    /// Rust type `type_name` is mapped to. Types of the XML Schema namespace are matched by
+    /// local name, so a mapping of `xs:dateTime` applies to schemas calling it `xsd:dateTime`.
+    pub fn type_mapping(&self, type_name: &str) -> Option<&str> {
+        if let Some(rust_type) = self.type_mappings.get(type_name) {
+            return Some(rust_type);
+        }
+        let (prefix, name) = split_name(type_name);
+        if prefix != self.xsd_ns.borrow().as_ref().and_then(|ns| ns.name()) {
+            return None;
+        }
+        self.type_mappings
+            .iter()
+            .filter(|(key, _)| split_name(key).1 == name)
+            .min_by_key(|(key, _)| key.as_str())
+            .map(|(_, rust_type)| rust_type.as_str())
+    }
+
+    pub fn type_attributes(&self, type_name: &str) -> Vec<String> {
+        self.type_config
+            .attributes
+            .iter()
+            .chain(self.type_configs.get(type_name).into_iter().flat_map(|c| c.attributes.iter()))
+            .cloned()
+            .collect()
+    }
+
+    pub fn visibility(&self, type_name: &str) -> &str {
+        self.type_configs
+            .get(type_name)
+            .and_then(|c| c.visibility.as_deref())
+            .or(self.type_config.visibility.as_deref())
+            .unwrap_or("pub")
+    }
 }
 
 #[cfg(test)]
@@ -91,7 +285,7 @@ mod test {
             type_name: "type".to_string(),
             ..Default::default()
         }));
-        let comment = "// comment\n";
+        let comment = "/// comment\n";
         let macros = "#[derive(Default, PartialEq, Debug, UtilsTupleIo, UtilsDefaultSerde)]\n";
         let validation = "impl Validate for Name {}\n";
         let expected =
@@ -0,0 +1,374 @@
+use std::{collections::HashMap, rc::Rc};
+
+use roxmltree::Namespace;
+
+use crate::{
+    generator::{
+        ident::{escape, pascal_case, snake_case, words},
+        utils::split_name,
+    },
+    parser::types::{EnumSource, RsEntity, StructField},
+};
+
+/// Kind of a type the parser creates for anonymous content and names after its parent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NestedTypeKind {
+    /// Anonymous type of an element, `{}Type` by default.
+    Type,
+    /// Enumeration of a simple type restriction, `{}Enum` by default.
+    Enum,
+    /// Choice or union, `{}Choice` by default.
+    Choice,
+}
+
+impl NestedTypeKind {
+    pub fn default_suffix(self) -> &'static str {
+        match self {
+            NestedTypeKind::Type => "Type",
+            NestedTypeKind::Enum => "Enum",
+            NestedTypeKind::Choice => "Choice",
+        }
+    }
+}
+
+/// Turns XSD names into Rust identifiers.
+///
+/// Names passed to the methods may carry a namespace prefix (`tt:Name`), which is
+/// not part of the identifier.
+pub trait NamingStrategy {
+    fn type_name(&self, name: &str) -> String {
+        let (_, name) = split_name(name);
+        escape(pascal_case(name))
+    }
+
+    fn field_name(&self, name: &str) -> String {
+        let (_, name) = split_name(name);
+        escape(snake_case(name))
+    }
+
+    /// Name of an enumeration case. Unlike other names, `name` may be any
+    /// enumeration value, including the empty string.
+    fn enum_variant_name(&self, name: &str) -> String {
+        match escape(pascal_case(name)) {
+            s if s.is_empty() => "Empty".to_string(),
+            s => s,
+        }
+    }
+
+    /// Name of the module holding the nested types of a struct.
+    fn module_name(&self, name: &str) -> String {
+        self.field_name(name)
+    }
+
+    fn function_name(&self, name: &str) -> String {
+        self.field_name(name)
+    }
+
+    /// Name of the trait generated for an abstract type, whose name goes to the enum
+    /// over its derivations.
+    fn trait_name(&self, name: &str) -> String {
+        format!("{}Trait", self.type_name(name))
+    }
+
+    fn nested_type_name(&self, parent: &str, kind: NestedTypeKind) -> String {
+        format!("{}{}", parent, kind.default_suffix())
+    }
+
+    /// Path to a type: its name, prefixed with the module of its namespace
+    /// for types from namespaces other than the target one.
+    fn type_path(&self, type_name: &str, target_ns: Option<&Namespace>) -> String {
+        // Nested types are already qualified with the module of their parent.
+        if let Some((module, name)) = type_name.rsplit_once("::") {
+            return format!("{}::{}", module, self.type_name(name));
+        }
+
+        let name = self.type_name(type_name);
+        match split_name(type_name).0 {
+            Some(prefix) if target_ns.and_then(|ns| ns.name()) != Some(prefix) => {
+                format!("{}::{}", prefix, name)
+            }
+            _ => name,
+        }
+    }
+}
+
+pub struct DefaultNamingStrategy;
+impl NamingStrategy for DefaultNamingStrategy {}
+
+/// Keeps the casing of the names of the schema, only replacing the characters identifiers
+/// cannot hold. Modules are still snake case, as they share a namespace with types.
+pub struct OriginalNamingStrategy;
+impl NamingStrategy for OriginalNamingStrategy {
+    fn type_name(&self, name: &str) -> String {
+        escape(words(split_name(name).1).join("_"))
+    }
+
+    fn field_name(&self, name: &str) -> String {
+        self.type_name(name)
+    }
+
+    fn enum_variant_name(&self, name: &str) -> String {
+        match escape(words(name).join("_")) {
+            s if s.is_empty() => "Empty".to_string(),
+            s => s,
+        }
+    }
+
+    fn module_name(&self, name: &str) -> String {
+        escape(snake_case(split_name(name).1))
+    }
+}
+
+// Strategies behind references and `Rc`s, to share a strategy between generators.
+macro_rules! forward_naming_strategy {
+    ($($ty:ty),*) => {$(
+        impl<N: NamingStrategy + ?Sized> NamingStrategy for $ty {
+            fn type_name(&self, name: &str) -> String {
+                (**self).type_name(name)
+            }
+
+            fn field_name(&self, name: &str) -> String {
+                (**self).field_name(name)
+            }
+
+            fn enum_variant_name(&self, name: &str) -> String {
+                (**self).enum_variant_name(name)
+            }
+
+            fn module_name(&self, name: &str) -> String {
+                (**self).module_name(name)
+            }
+
+            fn function_name(&self, name: &str) -> String {
+                (**self).function_name(name)
+            }
+
+            fn trait_name(&self, name: &str) -> String {
+                (**self).trait_name(name)
+            }
+
+            fn nested_type_name(&self, parent: &str, kind: NestedTypeKind) -> String {
+                (**self).nested_type_name(parent, kind)
+            }
+
+            fn type_path(&self, type_name: &str, target_ns: Option<&Namespace>) -> String {
+                (**self).type_path(type_name, target_ns)
+            }
+        }
+    )*};
+}
+
+forward_naming_strategy!(&N, Rc<N>);
+
+/// Renames the types created for anonymous content with `NamingStrategy::nested_type_name`
+/// and updates the references to them.
+pub fn rename_nested_types(entities: &mut [RsEntity], naming: &dyn NamingStrategy) {
+    entities.iter_mut().for_each(|entity| rename_in_entity(entity, naming));
+}
+
+fn rename_in_entity(entity: &mut RsEntity, naming: &dyn NamingStrategy) {
+    match entity {
+        RsEntity::Struct(st) => {
+            let renames = rename_subtypes(&mut st.subtypes, naming, |_| None);
+            for field in st.fields.get_mut() {
+                rename_in_field(field, naming);
+                apply(&mut field.type_name, &renames);
+            }
+        }
+        RsEntity::StructField(field) => rename_in_field(field, naming),
+        RsEntity::TupleStruct(ts) => {
+            let renames = rename_subtypes(&mut ts.subtypes, naming, |_| None);
+            apply(&mut ts.type_name, &renames);
+        }
+        RsEntity::Enum(en) => {
+            let renames = rename_subtypes(&mut en.subtypes, naming, |_| None);
+            apply(&mut en.type_name, &renames);
+            for case in &mut en.cases {
+                if let Some(type_name) = &mut case.type_name {
+                    apply(type_name, &renames);
+                }
+            }
+        }
+        RsEntity::Alias(al) => {
+            let renames = rename_subtypes(&mut al.subtypes, naming, |_| None);
+            apply(&mut al.original, &renames);
+        }
+        RsEntity::EnumCase(_) | RsEntity::Import(_) => {}
+    }
+}
+
+fn rename_in_field(field: &mut StructField, naming: &dyn NamingStrategy) {
+    let anonymous_type = format!("{}{}", field.name, NestedTypeKind::Type.default_suffix());
+    let field_name = field.name.clone();
+    let renames = rename_subtypes(&mut field.subtypes, naming, |name| {
+        if name == anonymous_type {
+            Some((field_name.as_str(), NestedTypeKind::Type))
+        } else {
+            None
+        }
+    });
+    apply(&mut field.type_name, &renames);
+}
+
+fn rename_subtypes<'a>(
+    subtypes: &mut [RsEntity],
+    naming: &dyn NamingStrategy,
+    origin: impl Fn(&str) -> Option<(&'a str, NestedTypeKind)>,
+) -> HashMap<String, String> {
+    let mut renames = HashMap::new();
+    for subtype in subtypes {
+        rename_in_entity(subtype, naming);
+
+        let name = subtype.name().to_string();
+        let nested = origin(&name).map(|(parent, kind)| (parent.to_string(), kind)).or_else(|| {
+            let kind = match subtype {
+                RsEntity::Enum(en) if en.source == EnumSource::Restriction => NestedTypeKind::Enum,
+                RsEntity::Enum(_) => NestedTypeKind::Choice,
+                _ => return None,
+            };
+            name.strip_suffix(kind.default_suffix()).map(|parent| (parent.to_string(), kind))
+        });
+
+        if let Some((parent, kind)) = nested {
+            let new_name = naming.nested_type_name(&parent, kind);
+            if new_name != name {
+                subtype.set_name(&new_name);
+                renames.insert(name, new_name);
+            }
+        }
+    }
+    renames
+}
+
+fn apply(type_name: &mut String, renames: &HashMap<String, String>) {
+    if let Some(new_name) = renames.get(type_name.as_str()) {
+        *type_name = new_name.clone();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_field_name() {
+        let naming = DefaultNamingStrategy;
+        assert_eq!(naming.field_name("Struct").as_str(), "r#struct");
+        assert_eq!(naming.field_name("StructName").as_str(), "struct_name");
+        assert_eq!(naming.field_name("0fst").as_str(), "_0fst");
+
+        // Name and type from 'ref' attribute
+        // <xs:element ref="xop:Include"/>
+        assert_eq!(naming.field_name("xop:Include").as_str(), "include");
+    }
+
+    #[test]
+    fn test_type_path_without_target_ns() {
+        let naming = DefaultNamingStrategy;
+        assert_eq!(naming.type_path("Type", None), "Type");
+        assert_eq!(naming.type_path("TyName", None), "TyName");
+        assert_eq!(naming.type_path("Ty_Name", None), "TyName");
+        assert_eq!(naming.type_path("Ty-Name", None), "TyName");
+        assert_eq!(naming.type_path("tt:TyName", None), "tt::TyName");
+        assert_eq!(naming.type_path("0type_name", None), "_0TypeName");
+        assert_eq!(naming.type_path("Enum", None), "Enum");
+    }
+
+    #[test]
+    fn test_type_path_with_target_ns() {
+        let naming = DefaultNamingStrategy;
+        let ns = Some(
+            roxmltree::Document::parse(
+                r#"<schema
+            xmlns:tt="http://www.onvif.org/ver10/schema"
+            targetNamespace="http://www.onvif.org/ver10/schema"/>"#,
+            )
+            .unwrap()
+            .root_element()
+            .namespaces()
+            .next()
+            .cloned()
+            .unwrap(),
+        );
+        assert_eq!(naming.type_path("tt:Type", ns.as_ref()), "Type");
+        assert_eq!(naming.type_path("tt:TyName", ns.as_ref()), "TyName");
+        assert_eq!(naming.type_path("tt:Ty_Name", ns.as_ref()), "TyName");
+        assert_eq!(naming.type_path("tt:Ty-Name", ns.as_ref()), "TyName");
+        assert_eq!(naming.type_path("tt:0type_name", ns.as_ref()), "_0TypeName");
+        assert_eq!(naming.type_path("tt:0_type-Name", ns.as_ref()), "_0TypeName");
+        assert_eq!(naming.type_path("tt:IANA_IfTypes ", ns.as_ref()), "IanaIfTypes");
+        assert_eq!(naming.type_path("tt:Enum", ns.as_ref()), "Enum");
+        assert_eq!(naming.type_path("ttEnum", ns.as_ref()), "TtEnum");
+        assert_eq!(naming.type_path("xs:TyName", ns.as_ref()), "xs::TyName");
+
+        assert_eq!(
+            naming.type_path("http://www.w3.org/2005/08/addressing/reply", ns.as_ref()),
+            "http::WwwW3Org200508AddressingReply"
+        );
+    }
+
+    #[test]
+    fn test_original_naming_strategy() {
+        let naming = OriginalNamingStrategy;
+        assert_eq!(naming.type_name("tt:fooType"), "fooType");
+        assert_eq!(naming.type_name("Ty-Name"), "Ty_Name");
+        assert_eq!(naming.field_name("VideoSource"), "VideoSource");
+        assert_eq!(naming.field_name("type"), "r#type");
+        assert_eq!(naming.enum_variant_name("1.5"), "_1_5");
+        assert_eq!(naming.module_name("VideoSource"), "video_source");
+    }
+
+    #[test]
+    fn test_custom_naming_strategy() {
+        struct Prefixed;
+        impl NamingStrategy for Prefixed {
+            fn type_name(&self, name: &str) -> String {
+                format!("Xml{}", split_name(name).1)
+            }
+
+            fn field_name(&self, name: &str) -> String {
+                split_name(name).1.to_string()
+            }
+
+            fn nested_type_name(&self, parent: &str, _: NestedTypeKind) -> String {
+                format!("Anonymous{}", parent)
+            }
+        }
+
+        let schema = crate::parser::parse(
+            r#"
+    <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:simpleType name="Color">
+            <xs:restriction base="xs:string">
+                <xs:enumeration value="Red"/>
+            </xs:restriction>
+        </xs:simpleType>
+        <xs:complexType name="Car">
+            <xs:sequence>
+                <xs:element name="Engine">
+                    <xs:complexType>
+                        <xs:sequence>
+                            <xs:element name="Power" type="xs:int"/>
+                        </xs:sequence>
+                    </xs:complexType>
+                </xs:element>
+            </xs:sequence>
+        </xs:complexType>
+    </xs:schema>
+            "#,
+        )
+        .unwrap();
+
+        let gen = crate::generator::builder::GeneratorBuilder::default()
+            .with_naming(Box::new(Prefixed))
+            .build();
+        let code = gen.generate_rs_file(&schema);
+
+        assert!(code.contains("pub enum XmlColor {"), "{}", code);
+        assert!(code.contains("    Red,"), "{}", code);
+        assert!(code.contains("pub Engine: Car::XmlAnonymousEngine,"), "{}", code);
+        assert!(code.contains("pub mod Car {"), "{}", code);
+        assert!(code.contains("pub struct XmlAnonymousEngine {"), "{}", code);
+        assert!(code.contains("pub Power: i32,"), "{}", code);
+    }
+}
@@ -0,0 +1,157 @@
+//! Order of the items of generated files.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    str::FromStr,
+};
+
+use crate::{
+    generator::{reachability::references, utils::local_name, Generator},
+    parser::types::RsEntity,
+};
+
+/// Order of the top-level items of a generated file. Imports always come first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ItemOrder {
+    /// Order of the definitions in the schema.
+    #[default]
+    Document,
+    /// Items sorted by name.
+    Alphabetical,
+    /// Types after the ones they refer to, in the order of the schema otherwise. Items generated
+    /// from WSDL do not refer to each other, so they keep the order of the document.
+    Dependency,
+}
+
+impl FromStr for ItemOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "document" => Ok(ItemOrder::Document),
+            "alphabetical" => Ok(ItemOrder::Alphabetical),
+            "dependency" => Ok(ItemOrder::Dependency),
+            _ => Err(format!(
+                "unknown order `{}`, expected `document`, `alphabetical` or `dependency`",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ItemOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ItemOrder::Document => "document",
+            ItemOrder::Alphabetical => "alphabetical",
+            ItemOrder::Dependency => "dependency",
+        })
+    }
+}
+
+/// Sorts the top-level types of a schema in `order`.
+pub fn sort_types(types: &mut Vec<RsEntity>, order: ItemOrder, gen: &Generator) {
+    let is_import = |entity: &RsEntity| matches!(entity, RsEntity::Import(_));
+    match order {
+        ItemOrder::Document => {}
+        ItemOrder::Alphabetical => {
+            // Stable, so items sharing a name keep the order of the schema.
+            types.sort_by(|a, b| (!is_import(a), a.name()).cmp(&(!is_import(b), b.name())));
+        }
+        ItemOrder::Dependency => {
+            let mut by_name: HashMap<&str, Vec<usize>> = HashMap::new();
+            for (i, entity) in types.iter().enumerate() {
+                by_name.entry(entity.name()).or_default().push(i);
+            }
+
+            let mut order = vec![];
+            let mut visited = HashSet::new();
+            for i in (0..types.len()).filter(|i| is_import(&types[*i])) {
+                visited.insert(i);
+                order.push(i);
+            }
+            for i in 0..types.len() {
+                visit(i, types, &by_name, gen, &mut visited, &mut order);
+            }
+
+            let mut entities = types.drain(..).map(Some).collect::<Vec<_>>();
+            types.extend(order.into_iter().filter_map(|i| entities[i].take()));
+        }
+    }
+}
+
+// Depth-first post-order, a cycle is entered at the type of the schema coming first.
+fn visit(
+    i: usize,
+    types: &[RsEntity],
+    by_name: &HashMap<&str, Vec<usize>>,
+    gen: &Generator,
+    visited: &mut HashSet<usize>,
+    order: &mut Vec<usize>,
+) {
+    if !visited.insert(i) {
+        return;
+    }
+    let mut targets = vec![];
+    references(&types[i], &mut |type_name| {
+        if let Some(ids) = by_name.get(local_name(type_name, gen)) {
+            targets.extend(ids.iter().copied());
+        }
+    });
+    for target in targets {
+        visit(target, types, by_name, gen, visited, order);
+    }
+    order.push(i);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{generator::builder::GeneratorBuilder, parser::parse};
+
+    const SCHEMA: &str = r#"
+    <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"
+               xmlns:tns="http://example.com"
+               targetNamespace="http://example.com">
+        <xs:element name="Foo" type="tns:FooType"/>
+        <xs:complexType name="FooType">
+            <xs:sequence>
+                <xs:element name="Bar" type="tns:BarType"/>
+                <xs:element name="Id" type="tns:Id"/>
+            </xs:sequence>
+        </xs:complexType>
+        <xs:complexType name="BarType">
+            <xs:sequence>
+                <xs:element name="Foo" type="tns:FooType" minOccurs="0"/>
+            </xs:sequence>
+        </xs:complexType>
+        <xs:simpleType name="Id">
+            <xs:restriction base="xs:string"/>
+        </xs:simpleType>
+    </xs:schema>
+    "#;
+
+    fn sorted(order: ItemOrder) -> Vec<String> {
+        let mut schema = parse(SCHEMA).unwrap();
+        let gen = GeneratorBuilder::default().build();
+        *gen.target_ns.borrow_mut() = schema.target_ns.clone();
+        sort_types(&mut schema.types, order, &gen);
+        schema.types.iter().map(|t| t.name().to_string()).collect()
+    }
+
+    #[test]
+    fn test_sort_types() {
+        assert_eq!(sorted(ItemOrder::Document), vec!["Foo", "FooType", "BarType", "Id"]);
+        assert_eq!(sorted(ItemOrder::Alphabetical), vec!["BarType", "Foo", "FooType", "Id"]);
+        assert_eq!(sorted(ItemOrder::Dependency), vec!["BarType", "Id", "FooType", "Foo"]);
+    }
+
+    #[test]
+    fn test_parse_item_order() {
+        for order in [ItemOrder::Document, ItemOrder::Alphabetical, ItemOrder::Dependency] {
+            assert_eq!(order.to_string().parse::<ItemOrder>(), Ok(order));
+        }
+        assert!("random".parse::<ItemOrder>().is_err());
+    }
+}
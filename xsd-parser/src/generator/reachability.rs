@@ -0,0 +1,259 @@
+//! Pruning of the types not reachable from a set of root elements or types.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    generator::{abstract_type::collect_abstract_types, utils::split_name, Generator},
+    parser::types::{RsEntity, RsFile},
+};
+
+/// Types of the schema left out by `prune_unreachable`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Pruned {
+    /// Names of the removed types.
+    pub dropped: Vec<String>,
+    /// Roots matching no element or type of the schema.
+    pub unmatched_roots: Vec<String>,
+}
+
+// Elements and types have separate symbol spaces, so an element and a type may share a name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Kind {
+    Element,
+    Type,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Key {
+    kind: Kind,
+    namespace: Option<String>,
+    name: String,
+}
+
+/// Removes the top-level types of the schema which the roots do not reach through fields,
+/// bases, unions, lists or substitution groups. Derivations of reachable abstract types are
+/// kept, they are the cases of the enums of the latter. Names are compared by namespace and
+/// local name, whatever their prefix.
+pub fn prune_unreachable(schema: &mut RsFile, roots: &[String], gen: &Generator) -> Pruned {
+    let target_ns = schema.target_ns.as_ref().map(|ns| ns.uri().to_string());
+    let key = |kind, name: &str| Key { kind, namespace: target_ns.clone(), name: name.into() };
+
+    // Global elements are aliases of their type, unless the type is anonymous.
+    let elements = schema
+        .root_elements
+        .iter()
+        .map(|el| el.name.as_str())
+        .chain(schema.root_elements.iter().filter_map(|el| el.substitution_group.as_deref()))
+        .map(|head| split_name(head).1)
+        .collect::<HashSet<&str>>();
+    let kind_of = |entity: &RsEntity| match entity {
+        RsEntity::Alias(_) => Kind::Element,
+        _ if elements.contains(entity.name()) => Kind::Element,
+        _ => Kind::Type,
+    };
+    let nodes = schema.types.iter().map(|e| key(kind_of(e), e.name())).collect::<HashSet<Key>>();
+
+    // Keys `name` may refer to, of either kind if `kind` is not known.
+    let resolve = |kind: Option<Kind>, name: &str| -> Vec<Key> {
+        let (prefix, local_name) = split_name(name);
+        let namespace = match prefix {
+            Some(_) => match gen.namespace_uri(prefix) {
+                Some(uri) => Some(uri),
+                None => return vec![],
+            },
+            None => target_ns.clone(),
+        };
+        [Kind::Element, Kind::Type]
+            .iter()
+            .filter(|k| kind.is_none_or(|kind| kind == **k))
+            .map(|k| Key { kind: *k, namespace: namespace.clone(), name: local_name.to_string() })
+            .filter(|key| nodes.contains(key))
+            .collect()
+    };
+
+    let mut edges: HashMap<Key, Vec<Key>> = HashMap::new();
+    for entity in &schema.types {
+        let targets = edges.entry(key(kind_of(entity), entity.name())).or_default();
+        references(entity, &mut |type_name| {
+            // Nested types are reached along with their parent. Fields may refer to elements.
+            if !type_name.contains("::") {
+                targets.extend(resolve(None, type_name));
+            }
+        });
+    }
+    for element in &schema.root_elements {
+        if let Some(head) = &element.substitution_group {
+            for head in resolve(Some(Kind::Element), head) {
+                edges.entry(head).or_default().push(key(Kind::Element, &element.name));
+            }
+        }
+    }
+    for abstract_type in collect_abstract_types(schema) {
+        let derivations = edges.entry(key(Kind::Type, &abstract_type.name)).or_default();
+        derivations.extend(abstract_type.derivations.iter().map(|d| key(Kind::Type, d)));
+    }
+
+    let mut unmatched_roots = vec![];
+    let mut queue = vec![];
+    for root in roots {
+        match resolve(None, root) {
+            keys if keys.is_empty() => unmatched_roots.push(root.clone()),
+            keys => queue.extend(keys),
+        }
+    }
+
+    let mut reachable = HashSet::new();
+    while let Some(key) = queue.pop() {
+        if let Some(targets) = edges.get(&key) {
+            queue.extend(targets.iter().filter(|t| !reachable.contains(*t)).cloned());
+        }
+        reachable.insert(key);
+    }
+
+    let mut dropped = vec![];
+    schema.types.retain(|entity| match entity {
+        RsEntity::Import(_) => true,
+        _ if reachable.contains(&key(kind_of(entity), entity.name())) => true,
+        _ => {
+            dropped.push(entity.name().to_string());
+            false
+        }
+    });
+    schema.root_elements.retain(|el| {
+        reachable.contains(&key(Kind::Element, &el.name))
+            || resolve(Some(Kind::Type), &el.type_name).iter().any(|k| reachable.contains(k))
+    });
+    Pruned { dropped, unmatched_roots }
+}
+
+// Calls `f` with every type name `entity` and its nested types refer to.
+pub(crate) fn references(entity: &RsEntity, f: &mut dyn FnMut(&str)) {
+    match entity {
+        RsEntity::Struct(st) => {
+            st.base.iter().for_each(|b| f(&b.type_name));
+            st.attribute_groups.borrow().iter().for_each(|ag| f(&ag.original));
+            for field in st.fields.borrow().iter() {
+                f(&field.type_name);
+                field.subtypes.iter().for_each(|s| references(s, f));
+            }
+            st.subtypes.iter().for_each(|s| references(s, f));
+        }
+        RsEntity::TupleStruct(ts) => {
+            f(&ts.type_name);
+            ts.subtypes.iter().for_each(|s| references(s, f));
+        }
+        RsEntity::Enum(en) => {
+            f(&en.type_name);
+            en.cases.iter().filter_map(|c| c.type_name.as_deref()).for_each(&mut *f);
+            en.subtypes.iter().for_each(|s| references(s, f));
+        }
+        RsEntity::Alias(al) => {
+            f(&al.original);
+            al.subtypes.iter().for_each(|s| references(s, f));
+        }
+        RsEntity::StructField(sf) => {
+            f(&sf.type_name);
+            sf.subtypes.iter().for_each(|s| references(s, f));
+        }
+        RsEntity::EnumCase(ec) => ec.type_name.as_deref().into_iter().for_each(f),
+        RsEntity::Import(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{generator::builder::GeneratorBuilder, parser::parse};
+
+    #[test]
+    fn test_prune_unreachable() {
+        let mut schema = parse(
+            r#"
+    <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"
+               xmlns:tns="http://example.com"
+               targetNamespace="http://example.com">
+        <xs:complexType name="FooType">
+            <xs:complexContent>
+                <xs:extension base="tns:BaseType">
+                    <xs:sequence>
+                        <xs:element name="Id" type="tns:IdList"/>
+                    </xs:sequence>
+                </xs:extension>
+            </xs:complexContent>
+        </xs:complexType>
+        <xs:complexType name="BaseType">
+            <xs:sequence>
+                <xs:element ref="tns:Head"/>
+            </xs:sequence>
+        </xs:complexType>
+        <xs:simpleType name="IdList">
+            <xs:list itemType="tns:Id"/>
+        </xs:simpleType>
+        <xs:simpleType name="Id">
+            <xs:restriction base="xs:string"/>
+        </xs:simpleType>
+        <xs:complexType name="UnusedType"/>
+        <xs:element name="Head" type="xs:string"/>
+        <xs:element name="Member" type="tns:MemberType" substitutionGroup="tns:Head"/>
+        <xs:simpleType name="MemberType">
+            <xs:restriction base="xs:string"/>
+        </xs:simpleType>
+        <xs:element name="Foo" type="tns:FooType"/>
+        <xs:element name="Unused" type="tns:UnusedType"/>
+    </xs:schema>
+            "#,
+        )
+        .unwrap();
+
+        let gen = GeneratorBuilder::default().build();
+        *gen.target_ns.borrow_mut() = schema.target_ns.clone();
+        *gen.namespaces.borrow_mut() = schema.namespaces.clone();
+        let roots = ["tns:Foo".to_string(), "tns:Missing".to_string()];
+        let pruned = prune_unreachable(&mut schema, &roots, &gen);
+
+        assert_eq!(pruned.dropped, vec!["UnusedType", "Unused"]);
+        assert_eq!(pruned.unmatched_roots, vec!["tns:Missing"]);
+        let kept = schema.types.iter().map(|t| t.name()).collect::<Vec<_>>();
+        assert_eq!(
+            kept,
+            vec!["FooType", "BaseType", "IdList", "Id", "Head", "Member", "MemberType", "Foo"]
+        );
+        let roots = schema.root_elements.iter().map(|r| r.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(roots, vec!["Head", "Member", "Foo"]);
+    }
+
+    #[test]
+    fn test_prune_unreachable_by_namespace() {
+        let mut schema = parse(
+            r#"
+    <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"
+               xmlns:tns="http://example.com"
+               xmlns:ex="http://example.com"
+               xmlns:o="http://example.com/other"
+               targetNamespace="http://example.com">
+        <xs:complexType name="Foo">
+            <xs:sequence>
+                <xs:element name="A" type="ex:AType"/>
+                <xs:element name="B" type="o:BType"/>
+            </xs:sequence>
+        </xs:complexType>
+        <xs:complexType name="AType"/>
+        <xs:complexType name="BType"/>
+        <xs:element name="Foo" type="xs:string"/>
+    </xs:schema>
+            "#,
+        )
+        .unwrap();
+
+        let gen = GeneratorBuilder::default().build();
+        *gen.target_ns.borrow_mut() = schema.target_ns.clone();
+        *gen.namespaces.borrow_mut() = schema.namespaces.clone();
+        let pruned = prune_unreachable(&mut schema, &["ex:Foo".to_string()], &gen);
+
+        // The type `Foo` is reached along with the element, `o:BType` is not the `BType` here.
+        assert_eq!(pruned.dropped, vec!["BType"]);
+        assert!(pruned.unmatched_roots.is_empty());
+        let roots = schema.root_elements.iter().map(|r| r.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(roots, vec!["Foo"]);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{
+    generator::{utils::match_built_in_type, Generator},
+    parser::types::{RootElement, RsFile},
+};
+
+const DOCUMENT: &str = "xsd_types::utils::document";
+
+/// Generates the `elements` module, with functions reading and writing documents whose root
+/// is a global element of the schema.
+pub trait RootElementGenerator {
+    fn generate(&self, schema: &RsFile, gen: &Generator) -> String {
+        let elements = schema
+            .root_elements
+            .iter()
+            .filter(|el| match_built_in_type(&el.type_name, &gen.xsd_ns.borrow()).is_none())
+            .map(|el| self.generate_element(el, schema, gen))
+            .collect::<Vec<String>>();
+        if elements.is_empty() {
+            return String::new();
+        }
+
+        let indent = gen.base().indent();
+        let namespaces = schema
+            .namespaces
+            .iter()
+            .map(|ns| format!("(\"{}\", \"{}\")", ns.name().unwrap_or_default(), ns.uri()))
+            .collect::<Vec<String>>()
+            .join(", ");
+        format!(
+            "\n{vis} mod elements {{\n{indent}use super::*;\n\n\
+             {indent}const NAMESPACES: &[(&str, &str)] = &[{namespaces}];\n\n{elements}}}\n",
+            vis = gen.type_config.visibility.as_deref().unwrap_or("pub"),
+            indent = indent,
+            namespaces = namespaces,
+            elements = elements.join("\n"),
+        )
+    }
+
+    fn generate_element(&self, element: &RootElement, schema: &RsFile, gen: &Generator) -> String {
+        let indent = gen.base().indent();
+        let target_ns = schema.target_ns.as_ref();
+        let quote = |s: Option<&str>| match s {
+            Some(s) => format!("Some(\"{}\")", s),
+            None => "None".to_string(),
+        };
+        let code = format!(
+            r#"pub mod {module} {{
+    use super::*;
+
+    const ELEMENT: {doc}::RootElement = {doc}::RootElement {{
+        name: "{name}",
+        namespace: {namespace},
+        prefix: {prefix},
+        namespaces: super::NAMESPACES,
+    }};
+
+    pub fn from_xml_str(s: &str) -> Result<{ty}, {doc}::DocumentError> {{
+        ELEMENT.from_xml_str(s)
+    }}
+
+    pub fn from_reader<R: std::io::Read>(reader: R) -> Result<{ty}, {doc}::DocumentError> {{
+        ELEMENT.from_reader(reader)
+    }}
+
+    pub fn to_xml_string(value: &{ty}) -> Result<String, {doc}::DocumentError> {{
+        ELEMENT.to_xml_string(value)
+    }}
+
+    pub fn to_writer<W: std::io::Write>(value: &{ty}, writer: W) -> Result<(), {doc}::DocumentError> {{
+        ELEMENT.to_writer(value, writer)
+    }}
+}}
+"#,
+            module = gen.base().mod_name(&element.name, gen),
+            doc = DOCUMENT,
+            name = element.name,
+            namespace = quote(target_ns.map(|ns| ns.uri())),
+            prefix = quote(target_ns.and_then(|ns| ns.name())),
+            ty = gen.base().format_type_name(&element.type_name, gen),
+        );
+        code.lines()
+            .map(|line| match line {
+                "" => "\n".to_string(),
+                line => format!("{}{}\n", indent, line),
+            })
+            .collect()
+    }
+}
+
+pub struct DefaultRootElementGen;
+impl RootElementGenerator for DefaultRootElementGen {}
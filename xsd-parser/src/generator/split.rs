@@ -0,0 +1,175 @@
+//! Generation of a tree of files instead of a single one.
+
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::parser::types::RsEntity;
+
+/// How the items of a schema are split into files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitStrategy {
+    /// A single module named after the prefix of the target namespace.
+    Namespace,
+    /// A module per kind of type: `complex_types`, `simple_types` and `enums`.
+    TypeGroup,
+    /// Modules `types_1`, `types_2`, ... of at most this number of types each.
+    Chunks(usize),
+}
+
+impl FromStr for SplitStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "namespace" => Ok(SplitStrategy::Namespace),
+            "type-group" => Ok(SplitStrategy::TypeGroup),
+            _ => match s.parse::<usize>() {
+                Ok(n) if n > 0 => Ok(SplitStrategy::Chunks(n)),
+                _ => Err(format!(
+                    "unknown split `{}`, expected `namespace`, `type-group` or a number of types",
+                    s
+                )),
+            },
+        }
+    }
+}
+
+impl fmt::Display for SplitStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SplitStrategy::Namespace => f.write_str("namespace"),
+            SplitStrategy::TypeGroup => f.write_str("type-group"),
+            SplitStrategy::Chunks(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+/// A file of a `GeneratedTree`, `<name>.rs`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedModule {
+    pub name: String,
+    pub code: String,
+}
+
+impl GeneratedModule {
+    /// `<name>.rs`, without the `r#` of a raw identifier, as `mod r#type;` loads `type.rs`.
+    pub fn file_name(&self) -> String {
+        format!("{}.rs", self.name.trim_start_matches("r#"))
+    }
+}
+
+/// Code of a schema split into modules, wired together by a `mod.rs`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GeneratedTree {
+    /// Items of `mod.rs` besides the module declarations.
+    pub root: String,
+    pub modules: Vec<GeneratedModule>,
+}
+
+impl GeneratedTree {
+    /// Code of `mod.rs`. Items in scope of the parent module, like the `yaserde` derives,
+    /// are visible to the modules, whose items are re-exported.
+    pub fn mod_rs(&self) -> String {
+        let declarations = self
+            .modules
+            .iter()
+            .map(|m| format!("mod {name};\npub use {name}::*;\n", name = m.name))
+            .collect::<String>();
+        format!("use super::*;\n\n{}\n{}", declarations, self.root)
+    }
+
+    /// Code to `include!` in place of a single generated file, declaring the modules written
+    /// to `dir` with their absolute paths.
+    pub fn include_rs(&self, dir: &Path) -> String {
+        let declarations = self
+            .modules
+            .iter()
+            .map(|m| {
+                format!(
+                    "#[path = {path:?}]\nmod {name};\npub use {name}::*;\n",
+                    path = dir.join(m.file_name()),
+                    name = m.name
+                )
+            })
+            .collect::<String>();
+        format!("{}\n{}", declarations, self.root)
+    }
+
+    /// Writes the modules and `mod.rs` to `dir` and returns the paths of the written files.
+    pub fn write(&self, dir: &Path) -> io::Result<Vec<PathBuf>> {
+        fs::create_dir_all(dir)?;
+        let mut written = vec![];
+        for module in &self.modules {
+            let path = dir.join(module.file_name());
+            fs::write(&path, &module.code)?;
+            written.push(path);
+        }
+        let path = dir.join("mod.rs");
+        fs::write(&path, self.mod_rs())?;
+        written.push(path);
+        Ok(written)
+    }
+}
+
+/// Name of the module `entity` goes to, `None` for items kept in `mod.rs`.
+pub fn module_of(
+    entity: &RsEntity,
+    index: usize,
+    split: SplitStrategy,
+    target_prefix: Option<&str>,
+) -> Option<String> {
+    // Aliases and imports are generated as comments.
+    if matches!(entity, RsEntity::Alias(_) | RsEntity::Import(_)) {
+        return None;
+    }
+    Some(match split {
+        SplitStrategy::Namespace => target_prefix.unwrap_or("types").to_string(),
+        SplitStrategy::TypeGroup => match entity {
+            RsEntity::Struct(_) => "complex_types".to_string(),
+            RsEntity::Enum(_) => "enums".to_string(),
+            _ => "simple_types".to_string(),
+        },
+        SplitStrategy::Chunks(n) => format!("types_{}", index / n + 1),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_split_strategy() {
+        assert_eq!("namespace".parse(), Ok(SplitStrategy::Namespace));
+        assert_eq!("type-group".parse(), Ok(SplitStrategy::TypeGroup));
+        assert_eq!("100".parse(), Ok(SplitStrategy::Chunks(100)));
+        assert!("0".parse::<SplitStrategy>().is_err());
+        assert!("types".parse::<SplitStrategy>().is_err());
+    }
+
+    #[test]
+    fn test_mod_rs() {
+        let tree = GeneratedTree {
+            root: "// pub type Foo = FooType;\n".to_string(),
+            modules: vec![GeneratedModule { name: "types_1".to_string(), code: String::new() }],
+        };
+        assert_eq!(
+            tree.mod_rs(),
+            "use super::*;\n\nmod types_1;\npub use types_1::*;\n\n// pub type Foo = FooType;\n"
+        );
+        assert_eq!(
+            tree.include_rs(Path::new("/out")),
+            "#[path = \"/out/types_1.rs\"]\nmod types_1;\npub use types_1::*;\n\n\
+             // pub type Foo = FooType;\n"
+        );
+    }
+
+    #[test]
+    fn test_module_file_name() {
+        let module = |name: &str| GeneratedModule { name: name.to_string(), code: String::new() };
+        assert_eq!(module("types_1").file_name(), "types_1.rs");
+        assert_eq!(module("r#type").file_name(), "type.rs");
+    }
+}
@@ -1,23 +1,216 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, ops::Add};
 
 use crate::{
-    generator::{validator::gen_validate_impl, Generator},
-    parser::types::Struct,
+    generator::{
+        abstract_type::AbstractType,
+        ident::disambiguate,
+        type_config::{trait_name, ABSTRACT_DERIVES, STRUCT_DERIVES},
+        utils::split_name,
+        validator::gen_validate_impl,
+        Generator,
+    },
+    parser::types::{Struct, StructField, TypeModifier},
 };
 
 pub trait StructGenerator {
     fn generate(&self, entity: &Struct, gen: &Generator) -> String {
+        if let Some(abstract_type) = gen.abstract_type(&entity.name) {
+            return self.generate_abstract(entity, &abstract_type, gen);
+        }
+
         format!(
-            "{comment}{macros}pub struct {name} {{{fields}}}\n\n{validation}\n{subtypes}\n",
+            "{trait_definition}{comment}{macros}{vis} struct {name} {{{fields}}}\n\n{validation}{deserialization}{base_impls}{trait_impls}\n{subtypes}\n",
+            trait_definition = gen
+                .implemented_traits(&entity.name)
+                .iter()
+                .filter(|t| t.name == entity.name)
+                .map(|t| format!("{}\n\n", self.trait_definition(entity, t, gen)))
+                .collect::<String>(),
             comment = self.format_comment(entity, gen),
             macros = self.macros(entity, gen),
+            vis = gen.visibility(&entity.name),
             name = self.get_type_name(entity, gen),
             fields = self.fields(entity, gen),
             subtypes = self.subtypes(entity, gen),
             validation = self.validation(entity, gen),
+            deserialization = self.deserialization(entity, gen),
+            base_impls = self.base_impls(entity, gen),
+            trait_impls = self.trait_impls(entity, gen),
         )
     }
 
+    /// Generates an abstract type as a trait with accessors for its fields, and an enum
+    /// over its concrete derivations named after the type.
+    fn generate_abstract(
+        &self,
+        entity: &Struct,
+        abstract_type: &AbstractType,
+        gen: &Generator,
+    ) -> String {
+        let indent = gen.base().indent();
+        let name = self.get_type_name(entity, gen);
+        let variants = abstract_type
+            .derivations
+            .iter()
+            .map(|d| {
+                (gen.naming().type_name(d), gen.base().format_type_name(d, gen).to_string(), d)
+            })
+            .collect::<Vec<_>>();
+
+        let derives = gen
+            .derives(&entity.name, ABSTRACT_DERIVES)
+            .into_iter()
+            .filter(|d| trait_name(d) != "Default")
+            .collect::<Vec<String>>();
+        // Derivations are types of the schema, in its target namespace.
+        let namespace = match gen.target_ns.borrow().as_ref() {
+            Some(tn) => match tn.name() {
+                Some(prefix) => format!(", namespace = \"{}\", prefix = \"{}\"", tn.uri(), prefix),
+                None => format!(", namespace = \"{}\"", tn.uri()),
+            },
+            None => String::new(),
+        };
+        let cases = variants
+            .iter()
+            .map(|(variant, type_name, xsd_name)| {
+                format!(
+                    "{indent}#[xsd(type_name = \"{xsd_name}\"{namespace})]\n{indent}{variant}({type_name}),\n",
+                    indent = indent,
+                    xsd_name = xsd_name,
+                    namespace = namespace,
+                    variant = variant,
+                    type_name = type_name,
+                )
+            })
+            .collect::<String>();
+
+        let delegate = |field: &StructField, trait_name: &str| {
+            let method = gen.struct_field_gen().get_name(field, gen);
+            let arms = variants
+                .iter()
+                .map(|(variant, _, _)| {
+                    format!(
+                        "{0}{0}{0}Self::{1}(value) => {2}::{3}(value),\n",
+                        indent, variant, trait_name, method
+                    )
+                })
+                .collect::<String>();
+            format!("{0}{0}match self {{\n{1}{0}{0}}}\n", indent, arms)
+        };
+        let trait_impls = gen
+            .implemented_traits(&entity.name)
+            .iter()
+            .map(|t| {
+                let trait_name = gen.naming().trait_name(&t.name);
+                self.trait_impl(&name, &trait_name, t, gen, &|f| delegate(f, &trait_name))
+            })
+            .collect::<String>();
+
+        format!(
+            "{trait_definition}\n\n\
+             {comment}#[derive({derives})]\n{attributes}{vis} enum {name} {{\n{cases}}}\n\n\
+             impl Default for {name} {{\n\
+             {indent}fn default() -> Self {{\n{indent}{indent}Self::{first}(Default::default())\n{indent}}}\n\
+             }}\n\n\
+             {validation}{trait_impls}\n\n",
+            trait_definition = self.trait_definition(entity, abstract_type, gen),
+            comment = self.format_comment(entity, gen),
+            vis = gen.visibility(&entity.name),
+            derives = derives.join(", "),
+            attributes = gen.base().format_attributes(&entity.name, gen),
+            name = name,
+            cases = cases,
+            indent = indent,
+            first = variants[0].0,
+            validation = self.validation(entity, gen),
+            trait_impls = trait_impls,
+        )
+    }
+
+    fn trait_definition(
+        &self,
+        entity: &Struct,
+        abstract_type: &AbstractType,
+        gen: &Generator,
+    ) -> String {
+        format!(
+            "{comment}{vis} trait {name}{supertrait} {{{methods}}}",
+            comment = self.format_comment(entity, gen),
+            vis = gen.visibility(&entity.name),
+            name = gen.naming().trait_name(&entity.name),
+            supertrait = abstract_type
+                .base
+                .as_ref()
+                .map(|b| format!(": {}", gen.naming().trait_name(b)))
+                .unwrap_or_default(),
+            methods = self.accessors(abstract_type, gen, None),
+        )
+    }
+
+    /// Accessors for the fields of an abstract type, declarations only unless `body`
+    /// is given.
+    fn accessors(
+        &self,
+        abstract_type: &AbstractType,
+        gen: &Generator,
+        body: Option<&dyn Fn(&StructField) -> String>,
+    ) -> String {
+        let indent = gen.base().indent();
+        let methods = abstract_type
+            .accessors
+            .iter()
+            .map(|f| {
+                let signature = format!(
+                    "{}fn {}(&self) -> &{}",
+                    indent,
+                    gen.struct_field_gen().get_name(f, gen),
+                    gen.struct_field_gen().get_type_name(f, gen)
+                );
+                match body {
+                    Some(body) => format!("{} {{\n{}{}}}\n", signature, body(f), indent),
+                    None => format!("{};\n", signature),
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        if methods.is_empty() {
+            methods
+        } else {
+            format!("\n{}", methods)
+        }
+    }
+
+    fn trait_impl(
+        &self,
+        type_name: &str,
+        trait_name: &str,
+        abstract_type: &AbstractType,
+        gen: &Generator,
+        body: &dyn Fn(&StructField) -> String,
+    ) -> String {
+        format!(
+            "\n\nimpl {} for {} {{{}}}",
+            trait_name,
+            type_name,
+            self.accessors(abstract_type, gen, Some(body))
+        )
+    }
+
+    /// Implementations of the traits of the abstract types the struct derives from.
+    fn trait_impls(&self, entity: &Struct, gen: &Generator) -> String {
+        let indent = gen.base().indent();
+        let name = self.get_type_name(entity, gen);
+        gen.implemented_traits(&entity.name)
+            .iter()
+            .map(|t| {
+                self.trait_impl(&name, &gen.naming().trait_name(&t.name), t, gen, &|f| {
+                    format!("{0}{0}&self.{1}\n", indent, gen.struct_field_gen().get_name(f, gen))
+                })
+            })
+            .collect()
+    }
+
     fn fields(&self, entity: &Struct, gen: &Generator) -> String {
         let mod_name = self.mod_name(entity, gen);
 
@@ -27,11 +220,10 @@ pub trait StructGenerator {
             }
         });
 
-        let fields = entity
-            .fields
-            .borrow()
+        let fields = self
+            .named_fields(entity, gen)
             .iter()
-            .map(|f| gen.struct_field_gen().generate(f, gen))
+            .map(|(f, name)| gen.struct_field_gen().generate_with_name(f, name, gen))
             .filter(|s| !s.is_empty())
             .collect::<Vec<String>>()
             .join("\n\n");
@@ -43,11 +235,26 @@ pub trait StructGenerator {
         }
     }
 
+    /// Generated fields, the composed base first, with their names.
+    fn named_fields(&self, entity: &Struct, gen: &Generator) -> Vec<(StructField, String)> {
+        let fields = entity.fields.borrow();
+        let fields = self
+            .composed_base(entity, gen)
+            .into_iter()
+            .chain(fields.iter().filter(|f| self.is_generated(f, entity, gen)))
+            .filter(|f| !f.type_modifiers.contains(&TypeModifier::Empty))
+            .cloned()
+            .collect::<Vec<StructField>>();
+        let names = disambiguate(fields.iter().map(|f| gen.struct_field_gen().get_name(f, gen)));
+        fields.into_iter().zip(names).collect()
+    }
+
     fn subtypes(&self, entity: &Struct, gen: &Generator) -> String {
         let field_subtypes = entity
             .fields
             .borrow()
             .iter()
+            .filter(|f| self.is_generated(f, entity, gen))
             .map(|f| gen.base().join_subtypes(f.subtypes.as_ref(), gen))
             .collect::<Vec<String>>()
             .join("");
@@ -67,6 +274,118 @@ pub trait StructGenerator {
         }
     }
 
+    /// Base of the struct if it is generated as a field, see
+    /// `GeneratorBuilder::with_composed_extensions`.
+    fn composed_base<'a>(&self, entity: &'a Struct, gen: &Generator) -> Option<&'a StructField> {
+        // Abstract bases are enums over their derivations, their fields are copied instead.
+        entity.base.as_ref().filter(|base| {
+            gen.compose_extensions && gen.abstract_type(split_name(&base.type_name).1).is_none()
+        })
+    }
+
+    /// Inherited fields are only generated when the base is not a field.
+    fn is_generated(&self, field: &StructField, entity: &Struct, gen: &Generator) -> bool {
+        !field.inherited || self.composed_base(entity, gen).is_none()
+    }
+
+    /// Elements of the composed base the struct declares again.
+    fn shadowed(&self, entity: &Struct, gen: &Generator) -> Vec<String> {
+        match self.composed_base(entity, gen) {
+            Some(_) => entity.shadowed.borrow().clone(),
+            None => vec![],
+        }
+    }
+
+    /// Deserialization of a struct declaring elements of its composed base again. Both
+    /// would read them by name, so the first one of each goes to the base, the others to
+    /// the fields of the struct, which are read by a struct of their own.
+    fn deserialization(&self, entity: &Struct, gen: &Generator) -> String {
+        let shadowed = self.shadowed(entity, gen);
+        if shadowed.is_empty() {
+            return String::new();
+        }
+        let indent = gen.base().indent();
+        let name = self.get_type_name(entity, gen);
+        let extension = format!("{}Extension", name);
+        let fields = self.named_fields(entity, gen);
+        let (base, fields) = fields.split_first().unwrap();
+
+        let declarations = fields
+            .iter()
+            .map(|(f, name)| {
+                let field_gen = gen.struct_field_gen();
+                let declaration = format!(
+                    "{}{}pub {}: {},",
+                    field_gen.macros(f, gen),
+                    indent,
+                    name,
+                    field_gen.get_type_name(f, gen)
+                );
+                declaration.lines().map(|line| format!("{}{}\n", indent.repeat(2), line)).collect()
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        let values = fields
+            .iter()
+            .map(|(_, name)| format!("{0}: extension.{0}", name))
+            .collect::<Vec<String>>();
+        let names = shadowed.iter().map(|n| format!("\"{}\"", n)).collect::<Vec<String>>();
+
+        format!(
+            "\n\nimpl yaserde::YaDeserialize for {name} {{\n\
+             {i}fn deserialize<R: std::io::Read>(\n\
+             {i}{i}reader: &mut yaserde::de::Deserializer<R>,\n\
+             {i}) -> Result<Self, String> {{\n\
+             {i}{i}#[derive(YaDeserialize)]\n\
+             {i}{i}{yaserde}\
+             {i}{i}struct {extension} {{\n{declarations}{i}{i}}}\n\n\
+             {i}{i}let (base, extension) =\n\
+             {i}{i}{i}xsd_types::utils::yaserde::split_extension(reader, &[{names}])?;\n\
+             {i}{i}let extension: {extension} = yaserde::de::from_str(&extension)?;\n\
+             {i}{i}Ok(Self {{ {base}: yaserde::de::from_str(&base)?, {values} }})\n\
+             {i}}}\n\
+             }}",
+            name = name,
+            i = indent,
+            yaserde = self.yaserde_attribute(gen),
+            extension = extension,
+            declarations = declarations,
+            names = names.join(", "),
+            base = base.1,
+            values = values.join(", "),
+        )
+    }
+
+    fn base_impls(&self, entity: &Struct, gen: &Generator) -> String {
+        let base = match self.composed_base(entity, gen) {
+            Some(base) => base,
+            None => return String::new(),
+        };
+        let indent = gen.base().indent();
+        let body = |line: &str| format!("{0}{0}{1}\n", indent, line);
+
+        let name = self.get_type_name(entity, gen);
+        let field = gen.struct_field_gen().get_name(base, gen);
+        let base_type = gen.struct_field_gen().get_type_name(base, gen);
+        format!(
+            "\n\nimpl std::ops::Deref for {name} {{\n\
+             {indent}type Target = {base_type};\n\n\
+             {indent}fn deref(&self) -> &Self::Target {{\n{deref}{indent}}}\n}}\n\n\
+             impl std::ops::DerefMut for {name} {{\n\
+             {indent}fn deref_mut(&mut self) -> &mut Self::Target {{\n{deref_mut}{indent}}}\n}}\n\n\
+             impl AsRef<{base_type}> for {name} {{\n\
+             {indent}fn as_ref(&self) -> &{base_type} {{\n{deref}{indent}}}\n}}\n\n\
+             impl From<{name}> for {base_type} {{\n\
+             {indent}fn from(value: {name}) -> Self {{\n{from}{indent}}}\n}}",
+            name = name,
+            base_type = base_type,
+            indent = indent,
+            deref = body(&format!("&self.{}", field)),
+            deref_mut = body(&format!("&mut self.{}", field)),
+            from = body(&format!("value.{}", field)),
+        )
+    }
+
     fn shift(&self, text: &str, indent: &str) -> String {
         text.replace("\n\n\n", "\n") // TODO: fix this workaround replace
             .split('\n')
@@ -78,34 +397,41 @@ pub trait StructGenerator {
         gen.base().format_type_name(entity.name.as_str(), gen).into()
     }
 
-    fn macros(&self, _entity: &Struct, gen: &Generator) -> Cow<'static, str> {
-        let derives = "#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]\n";
+    fn macros(&self, entity: &Struct, gen: &Generator) -> Cow<'static, str> {
+        // Structs declaring elements of their base again deserialize by hand.
+        let shadowed = !self.shadowed(entity, gen).is_empty();
+        let derives = gen
+            .derives(&entity.name, STRUCT_DERIVES)
+            .into_iter()
+            .filter(|d| !shadowed || trait_name(d) != "YaDeserialize")
+            .collect::<Vec<String>>();
+        let attributes = gen.base().format_attributes(&entity.name, gen);
+        format!("#[derive({})]\n{}", derives.join(", "), self.yaserde_attribute(gen))
+            .add(&attributes)
+            .into()
+    }
+
+    fn yaserde_attribute(&self, gen: &Generator) -> String {
         let tns = gen.target_ns.borrow();
         match tns.as_ref() {
             Some(tn) => match tn.name() {
                 Some(name) => format!(
-                    "{derives}#[yaserde(prefix = \"{prefix}\", namespace = \"{prefix}: {uri}\")]\n",
-                    derives = derives,
+                    "#[yaserde(prefix = \"{prefix}\", namespace = \"{prefix}: {uri}\")]\n",
                     prefix = name,
                     uri = tn.uri()
                 ),
-                None => format!(
-                    "{derives}#[yaserde(namespace = \"{uri}\")]\n",
-                    derives = derives,
-                    uri = tn.uri()
-                ),
+                None => format!("#[yaserde(namespace = \"{uri}\")]\n", uri = tn.uri()),
             },
-            None => format!("{derives}#[yaserde()]\n", derives = derives),
+            None => "#[yaserde()]\n".to_string(),
         }
-        .into()
     }
 
     fn format_comment(&self, entity: &Struct, gen: &Generator) -> String {
-        gen.base().format_comment(entity.comment.as_deref(), 0)
+        gen.base().format_comment(gen.documentation(entity.comment.as_ref()).as_deref(), 0)
     }
 
     fn mod_name(&self, entity: &Struct, gen: &Generator) -> String {
-        gen.base().mod_name(entity.name.as_str())
+        gen.base().mod_name(entity.name.as_str(), gen)
     }
 
     fn validation(&self, entity: &Struct, gen: &Generator) -> Cow<'static, str> {
@@ -1,6 +1,10 @@
 use crate::{
     generator::{
-        default::{yaserde_for_attribute, yaserde_for_element, yaserde_for_flatten_element},
+        default::{
+            yaserde_for_attribute, yaserde_for_element, yaserde_for_flatten_element,
+            yaserde_for_text,
+        },
+        facets::is_simple_type,
         Generator,
     },
     parser::types::{StructField, StructFieldSource, TypeModifier},
@@ -8,6 +12,11 @@ use crate::{
 
 pub trait StructFieldGenerator {
     fn generate(&self, entity: &StructField, gen: &Generator) -> String {
+        self.generate_with_name(entity, &self.get_name(entity, gen), gen)
+    }
+
+    /// Generates the field as `name`, which is unique among the fields of its struct.
+    fn generate_with_name(&self, entity: &StructField, name: &str, gen: &Generator) -> String {
         if entity.type_modifiers.contains(&TypeModifier::Empty) {
             return "".into();
         }
@@ -16,7 +25,7 @@ pub trait StructFieldGenerator {
             comment = self.format_comment(entity, gen),
             macros = self.macros(entity, gen),
             indent = gen.base().indent(),
-            name = self.get_name(entity, gen),
+            name = name,
             typename = self.get_type_name(entity, gen),
         )
     }
@@ -31,11 +40,14 @@ pub trait StructFieldGenerator {
     }
 
     fn get_name(&self, entity: &StructField, gen: &Generator) -> String {
-        gen.base().format_name(entity.name.as_str()).into()
+        gen.base().format_name(entity.name.as_str(), gen).into()
     }
 
     fn format_comment(&self, entity: &StructField, gen: &Generator) -> String {
-        gen.base().format_comment(entity.comment.as_deref(), gen.base().indent_size())
+        gen.base().format_comment(
+            gen.documentation(entity.comment.as_ref()).as_deref(),
+            gen.base().indent_size(),
+        )
     }
 
     fn macros(&self, entity: &StructField, gen: &Generator) -> String {
@@ -50,6 +62,10 @@ pub trait StructFieldGenerator {
                 gen.target_ns.borrow().as_ref(),
                 indent.as_str(),
             ),
+            StructFieldSource::Base if is_simple_type(&entity.type_name, gen) => {
+                yaserde_for_text(indent.as_str())
+            }
+            StructFieldSource::Base => yaserde_for_flatten_element(indent.as_str()),
             _ => "".into(),
         }
     }
@@ -2,17 +2,23 @@ use std::borrow::Cow;
 
 use crate::{
     generator::{
+        facets::{effective_facets, EffectiveFacets},
+        type_config::TUPLE_STRUCT_DERIVES,
         validator::{gen_facet_validation, gen_validate_impl},
         Generator,
     },
-    parser::types::TupleStruct,
+    parser::{
+        types::{TupleStruct, TypeModifier},
+        xsd_elements::WhiteSpace,
+    },
 };
 
 pub trait TupleStructGenerator {
     fn generate(&self, entity: &TupleStruct, gen: &Generator) -> String {
         format!(
-            "{comment}{macros}pub struct {name} (pub {typename});\n{subtypes}\n{validation}\n",
+            "{comment}{macros}{vis} struct {name} (pub {typename});\n{subtypes}\n{validation}\n",
             comment = self.format_comment(entity, gen),
+            vis = gen.visibility(&entity.name),
             name = self.get_name(entity, gen),
             macros = self.macros(entity, gen),
             typename = self.get_type_name(entity, gen),
@@ -38,19 +44,50 @@ pub trait TupleStructGenerator {
         gen.base().format_type_name(entity.name.as_str(), gen).into()
     }
 
-    fn macros(&self, _entity: &TupleStruct, _gen: &Generator) -> Cow<'static, str> {
-        "#[derive(Default, PartialEq, Debug, UtilsTupleIo, UtilsDefaultSerde)]\n".into()
+    fn macros(&self, entity: &TupleStruct, gen: &Generator) -> Cow<'static, str> {
+        // Lists are split on whitespace anyway.
+        let whitespace = match self.facets(entity, gen).whitespace() {
+            _ if entity.type_modifiers.contains(&TypeModifier::Array) => "",
+            WhiteSpace::Preserve => "",
+            WhiteSpace::Replace => "#[xsd(whitespace = \"replace\")]\n",
+            WhiteSpace::Collapse => "#[xsd(whitespace = \"collapse\")]\n",
+        };
+        format!(
+            "#[derive({})]\n{}{}",
+            gen.derives(&entity.name, TUPLE_STRUCT_DERIVES).join(", "),
+            whitespace,
+            gen.base().format_attributes(&entity.name, gen)
+        )
+        .into()
+    }
+
+    /// Facets of the type and the types it is derived from.
+    fn facets(&self, entity: &TupleStruct, gen: &Generator) -> EffectiveFacets {
+        let own = entity.facets.iter().map(|f| f.facet_type.clone()).collect::<Vec<_>>();
+        effective_facets(&entity.type_name, &own, gen)
     }
 
     fn format_comment(&self, entity: &TupleStruct, gen: &Generator) -> String {
-        gen.base().format_comment(entity.comment.as_deref(), 0)
+        gen.base().format_comment(gen.documentation(entity.comment.as_ref()).as_deref(), 0)
     }
 
     fn validation(&self, entity: &TupleStruct, gen: &Generator) -> Cow<'static, str> {
-        let body = entity
-            .facets
+        let facets = self.facets(entity, gen);
+        // Inherited facets apply to the built-in value wrapped by the base types.
+        let (value, typename, facets) = match (facets.depth, facets.rust_type(gen)) {
+            (Some(0), _) => ("0".to_string(), self.get_type_name(entity, gen), facets.facets),
+            (Some(depth), Some(rust_type)) => {
+                (format!("0{}", ".0".repeat(depth)), rust_type, facets.facets)
+            }
+            _ => (
+                "0".to_string(),
+                self.get_type_name(entity, gen),
+                entity.facets.iter().map(|f| f.facet_type.clone()).collect(),
+            ),
+        };
+        let body = facets
             .iter()
-            .map(|f| gen_facet_validation(&f.facet_type, "0", &self.get_type_name(entity, gen)))
+            .map(|f| gen_facet_validation(f, &value, &typename))
             .fold(String::new(), |x, y| (x + &y));
         Cow::Owned(gen_validate_impl(self.get_name(entity, gen).as_str(), body.as_str()))
     }
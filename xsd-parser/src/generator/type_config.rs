@@ -0,0 +1,343 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    generator::{
+        r#enum::typed_enum_base,
+        utils::{local_name, match_built_in_type},
+        Generator,
+    },
+    parser::types::{Enum, EnumSource, RsEntity, RsFile, Struct, TypeModifier},
+};
+
+pub const STRUCT_DERIVES: &[&str] =
+    &["Default", "PartialEq", "Debug", "YaSerialize", "YaDeserialize"];
+pub const TUPLE_STRUCT_DERIVES: &[&str] =
+    &["Default", "PartialEq", "Debug", "UtilsTupleIo", "UtilsDefaultSerde"];
+pub const ENUM_DERIVES: &[&str] = &["PartialEq", "Debug", "YaSerialize", "YaDeserialize"];
+pub const TYPED_ENUM_DERIVES: &[&str] = &["PartialEq", "Debug", "UtilsDefaultSerde"];
+/// Derives of the enumerations of QNames, whose prefixes are resolved with the document.
+pub const QNAME_ENUM_DERIVES: &[&str] = &["PartialEq", "Debug", "UtilsQNameSerDe"];
+/// Added to the derives of types referenced through a `Box`.
+pub const BOX_DERIVE: &str = "UtilsBoxSerDe";
+pub const UNION_DERIVES: &[&str] = &["PartialEq", "Debug", "UtilsUnionSerDe"];
+/// Derives of the enums over the derivations of abstract types.
+pub const ABSTRACT_DERIVES: &[&str] = &["PartialEq", "Debug", "UtilsAbstractSerDe"];
+
+// Standard traits whose derive requires every field to implement the same trait.
+// Anything else (serde, custom derives) is passed through as is.
+const CHECKED_TRAITS: &[&str] =
+    &["Clone", "Copy", "Debug", "Default", "PartialEq", "Eq", "Hash", "PartialOrd", "Ord"];
+
+pub fn is_checked_trait(name: &str) -> bool {
+    CHECKED_TRAITS.contains(&name)
+}
+
+/// Extra derives, attributes and visibility of generated types.
+#[derive(Debug, Clone, Default)]
+pub struct TypeConfig {
+    pub derives: Vec<String>,
+    pub attributes: Vec<String>,
+    pub visibility: Option<String>,
+}
+
+pub fn enum_default_derives(entity: &Enum, gen: &Generator) -> &'static [&'static str] {
+    if entity.source == EnumSource::Union {
+        UNION_DERIVES
+    } else if let Some(base) = typed_enum_base(entity, gen) {
+        match base.as_str() {
+            "xs::QName" => QNAME_ENUM_DERIVES,
+            _ => TYPED_ENUM_DERIVES,
+        }
+    } else {
+        ENUM_DERIVES
+    }
+}
+
+/// Last path segment of a derive, e.g. `Hash` for `std::hash::Hash`.
+pub fn trait_name(derive: &str) -> &str {
+    derive.rsplit("::").next().unwrap_or(derive).trim()
+}
+
+/// Computes which of the checked traits every type of the file can derive,
+/// given the derives requested for it and the types of its fields.
+pub fn derive_support(schema: &RsFile, gen: &Generator) -> HashMap<String, HashSet<String>> {
+    let mut nodes = HashMap::new();
+    for entity in &schema.types {
+        collect_nodes(entity, gen, &mut nodes);
+    }
+
+    let mut support: HashMap<String, HashSet<String>> =
+        nodes.iter().map(|(name, node)| (name.clone(), node.derives.clone())).collect();
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (name, node) in &nodes {
+            let unsupported = support[name]
+                .iter()
+                .filter(|tr| {
+                    !node.dependencies.iter().all(|dep| dep.implements(tr, &support, &nodes, gen))
+                })
+                .cloned()
+                .collect::<Vec<String>>();
+
+            if !unsupported.is_empty() {
+                let set = support.get_mut(name).unwrap();
+                unsupported.iter().for_each(|tr| {
+                    set.remove(tr);
+                });
+                changed = true;
+            }
+        }
+    }
+    support
+}
+
+struct Node {
+    derives: HashSet<String>,
+    manual_impls: &'static [&'static str],
+    dependencies: Vec<Dependency>,
+}
+
+struct Dependency {
+    type_name: String,
+    modifiers: Vec<TypeModifier>,
+}
+
+impl Dependency {
+    fn implements(
+        &self,
+        tr: &str,
+        support: &HashMap<String, HashSet<String>>,
+        nodes: &HashMap<String, Node>,
+        gen: &Generator,
+    ) -> bool {
+        let is_vec = self
+            .modifiers
+            .iter()
+            .any(|m| matches!(m, TypeModifier::Array | TypeModifier::Recursive));
+        if is_vec && tr == "Copy" {
+            return false;
+        }
+
+        if let Some(rust_type) = gen.type_mapping(&self.type_name) {
+            return built_in_implements(rust_type, tr).unwrap_or(true);
+        }
+
+        if let Some(rust_type) = match_built_in_type(&self.type_name, &gen.xsd_ns.borrow()) {
+            return built_in_implements(rust_type, tr).unwrap_or(true);
+        }
+
+        let key = local_name(&self.type_name, gen);
+        match (support.get(key), nodes.get(key)) {
+            (Some(set), Some(node)) => set.contains(tr) || node.manual_impls.contains(&tr),
+            // Types from other schemas are generated separately, assume the best.
+            _ => true,
+        }
+    }
+}
+
+fn built_in_implements(rust_type: &str, tr: &str) -> Option<bool> {
+    const ALL: &[&str] = CHECKED_TRAITS;
+    const NO_COPY: &[&str] =
+        &["Clone", "Debug", "Default", "PartialEq", "Eq", "Hash", "PartialOrd", "Ord"];
+    const FLOAT: &[&str] = &["Clone", "Copy", "Debug", "Default", "PartialEq", "PartialOrd"];
+    const ORDERED_XS: &[&str] =
+        &["Clone", "Debug", "Default", "PartialEq", "Eq", "Hash", "PartialOrd"];
+    const XS: &[&str] = &["Clone", "Debug", "Default", "PartialEq", "Eq", "Hash"];
+    const DURATION: &[&str] = &["Clone", "Debug", "Default", "PartialEq", "PartialOrd"];
+
+    let traits = match rust_type {
+        "bool" | "i8" | "i16" | "i32" | "i64" | "i128" | "u8" | "u16" | "u32" | "u64" | "u128"
+        | "char" => ALL,
+        "f32" | "f64" => FLOAT,
+        "String" | "Vec<String>" => NO_COPY,
+        "xs::Integer"
+        | "xs::NonNegativeInteger"
+        | "xs::PositiveInteger"
+        | "xs::NonPositiveInteger"
+        | "xs::NegativeInteger"
+        | "xs::Decimal"
+        | "xs::DateTime"
+        | "xs::DateTimeStamp" => ORDERED_XS,
+        "xs::Date" | "xs::Time" | "xs::GDay" | "xs::GMonth" | "xs::GMonthDay" | "xs::GYear"
+        | "xs::GYearMonth" => XS,
+        "xs::Duration" => DURATION,
+        _ => return None,
+    };
+    Some(!CHECKED_TRAITS.contains(&tr) || traits.contains(&tr))
+}
+
+fn requested_derives(name: &str, defaults: &[&str], gen: &Generator) -> HashSet<String> {
+    gen.requested_derives(name, defaults)
+        .iter()
+        .map(|d| trait_name(d))
+        .filter(|d| CHECKED_TRAITS.contains(d))
+        .map(|d| d.to_string())
+        .collect()
+}
+
+fn collect_nodes(entity: &RsEntity, gen: &Generator, nodes: &mut HashMap<String, Node>) {
+    let node = match entity {
+        RsEntity::Struct(st) => Some(struct_node(st, gen)),
+        RsEntity::TupleStruct(ts) => Some(Node {
+            derives: requested_derives(&ts.name, TUPLE_STRUCT_DERIVES, gen),
+            manual_impls: &[],
+            dependencies: vec![Dependency {
+                type_name: ts.type_name.clone(),
+                modifiers: ts.type_modifiers.clone(),
+            }],
+        }),
+        RsEntity::Enum(en) => Some(enum_node(en, gen)),
+        _ => None,
+    };
+
+    if let Some(node) = node {
+        let name = entity.name().to_string();
+        match nodes.get_mut(&name) {
+            // Nested types may share names with others, be conservative on clashes.
+            Some(existing) => {
+                existing.derives.retain(|d| node.derives.contains(d));
+                existing.dependencies.extend(node.dependencies);
+            }
+            None => {
+                nodes.insert(name, node);
+            }
+        }
+    }
+
+    let mut collect =
+        |subtypes: &[RsEntity]| subtypes.iter().for_each(|s| collect_nodes(s, gen, nodes));
+    match entity {
+        RsEntity::Struct(st) => {
+            collect(&st.subtypes);
+            st.fields.borrow().iter().for_each(|f| collect(&f.subtypes));
+        }
+        RsEntity::TupleStruct(ts) => collect(&ts.subtypes),
+        RsEntity::Enum(en) => collect(&en.subtypes),
+        RsEntity::Alias(al) => collect(&al.subtypes),
+        _ => {}
+    }
+}
+
+fn struct_node(st: &Struct, gen: &Generator) -> Node {
+    if let Some(abstract_type) = gen.abstract_type(&st.name) {
+        return Node {
+            derives: requested_derives(&st.name, ABSTRACT_DERIVES, gen),
+            manual_impls: &["Default"],
+            dependencies: abstract_type
+                .derivations
+                .into_iter()
+                .map(|type_name| Dependency { type_name, modifiers: vec![] })
+                .collect(),
+        };
+    }
+
+    Node {
+        derives: requested_derives(&st.name, STRUCT_DERIVES, gen),
+        manual_impls: &[],
+        dependencies: st
+            .fields
+            .borrow()
+            .iter()
+            .filter(|f| !f.type_modifiers.contains(&TypeModifier::Empty))
+            .map(|f| Dependency {
+                type_name: f.type_name.clone(),
+                modifiers: f.type_modifiers.clone(),
+            })
+            .collect(),
+    }
+}
+
+fn enum_node(en: &Enum, gen: &Generator) -> Node {
+    let mut dependencies = en
+        .cases
+        .iter()
+        .filter_map(|c| {
+            c.type_name
+                .as_ref()
+                .map(|t| Dependency { type_name: t.clone(), modifiers: c.type_modifiers.clone() })
+        })
+        .collect::<Vec<Dependency>>();
+    dependencies.push(Dependency { type_name: en.type_name.clone(), modifiers: vec![] });
+
+    let mut derives = requested_derives(&en.name, enum_default_derives(en, gen), gen);
+    // Enums always implement Default by hand.
+    derives.remove("Default");
+
+    Node { derives, manual_impls: &["Default"], dependencies }
+}
+
+/// Name of the generated type `type_name` refers to, without its module. References to a
+/// type, prefixed or qualified with the module of a nested type, and the name of the type
+/// itself all give the same key.
+pub fn boxed_key(type_name: &str, gen: &Generator) -> String {
+    let path = gen.base().format_type_name(type_name, gen);
+    path.rsplit("::").next().unwrap_or_default().to_string()
+}
+
+/// Types referenced through a `Box` by recursive types, by `boxed_key`.
+pub fn boxed_types(schema: &RsFile, gen: &Generator) -> HashSet<String> {
+    fn collect(entity: &RsEntity, gen: &Generator, res: &mut HashSet<String>) {
+        let mut add = |type_name: &str, modifiers: &[TypeModifier]| {
+            if modifiers.contains(&TypeModifier::Recursive)
+                && !modifiers.contains(&TypeModifier::Array)
+            {
+                res.insert(boxed_key(type_name, gen));
+            }
+        };
+        match entity {
+            RsEntity::Struct(st) => {
+                st.fields.borrow().iter().for_each(|f| add(&f.type_name, &f.type_modifiers));
+            }
+            RsEntity::Enum(en) => en.cases.iter().for_each(|c| {
+                if let Some(type_name) = &c.type_name {
+                    add(type_name, &c.type_modifiers);
+                }
+            }),
+            _ => {}
+        }
+
+        let mut collect_all =
+            |subtypes: &[RsEntity]| subtypes.iter().for_each(|s| collect(s, gen, res));
+        match entity {
+            RsEntity::Struct(st) => {
+                collect_all(&st.subtypes);
+                st.fields.borrow().iter().for_each(|f| collect_all(&f.subtypes));
+            }
+            RsEntity::TupleStruct(ts) => collect_all(&ts.subtypes),
+            RsEntity::Enum(en) => collect_all(&en.subtypes),
+            RsEntity::Alias(al) => collect_all(&al.subtypes),
+            _ => {}
+        }
+    }
+
+    let mut res = HashSet::new();
+    schema.types.iter().for_each(|entity| collect(entity, gen, &mut res));
+    res
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{generator::builder::GeneratorBuilder, parser::parse};
+
+    #[test]
+    fn test_boxed_key() {
+        let schema = parse(
+            r#"
+    <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"
+               xmlns:tns="http://example.com"
+               targetNamespace="http://example.com"/>
+            "#,
+        )
+        .unwrap();
+        let gen = GeneratorBuilder::default().build();
+        *gen.target_ns.borrow_mut() = schema.target_ns.clone();
+
+        assert_eq!(boxed_key("BranchType", &gen), "BranchType");
+        assert_eq!(boxed_key("tns:BranchType", &gen), "BranchType");
+        assert_eq!(boxed_key("tree::BranchType", &gen), "BranchType");
+        assert_eq!(boxed_key("tns:branch-type", &gen), "BranchType");
+    }
+}
@@ -1,21 +1,102 @@
 use roxmltree::Namespace;
 
-pub fn split_comment_line(s: &str, max_len: usize, indent: usize) -> String {
-    let indent_str = " ".repeat(indent);
-
-    let mut splitted = format!("{}//", indent_str);
-    let mut current_line_length = indent + 2;
-    for word in s.split_whitespace() {
-        let len = word.len();
-        if current_line_length + len < max_len {
-            splitted = format!("{} {}", splitted, word);
-            current_line_length += 1 + len;
-        } else {
-            splitted = format!("{}\n{}// {}", splitted, indent_str, word);
-            current_line_length = indent + 3 + len;
+use crate::generator::Generator;
+
+/// Lines of `doc` commented with `prefix` and wrapped at `max_len`. Paragraphs and the markers
+/// of list items are kept, the continuation lines of an item are aligned with its text.
+/// With `escape`, the text is escaped for rustdoc.
+pub fn format_comment_lines(
+    doc: &str,
+    prefix: &str,
+    max_len: usize,
+    indent: usize,
+    escape: bool,
+) -> String {
+    let line_start = format!("{}{} ", " ".repeat(indent), prefix);
+    let mut result = String::new();
+    let mut new_paragraph = false;
+    let mut item_indent = 0;
+    for line in doc.lines().map(str::trim) {
+        if line.is_empty() {
+            new_paragraph = !result.is_empty();
+            continue;
+        }
+        if new_paragraph {
+            result.push_str(line_start.trim_end());
+            result.push('\n');
+            new_paragraph = false;
+            item_indent = 0;
+        }
+
+        let (mut current, text) = match list_marker(line) {
+            Some((marker, text)) => {
+                item_indent = marker.chars().count() + 1;
+                (format!("{}{} ", line_start, marker), text)
+            }
+            None => (format!("{}{}", line_start, " ".repeat(item_indent)), line),
+        };
+        let continuation = format!("{}{}", line_start, " ".repeat(item_indent));
+        let mut is_empty = true;
+        for word in text.split_whitespace() {
+            let word = if escape { escape_markdown(word) } else { word.to_string() };
+            if !is_empty && current.chars().count() + word.chars().count() >= max_len {
+                result.push_str(&current);
+                result.push('\n');
+                current = continuation.clone();
+                is_empty = true;
+            }
+            if !is_empty {
+                current.push(' ');
+            }
+            current.push_str(&word);
+            is_empty = false;
+        }
+        result.push_str(&current);
+        result.push('\n');
+    }
+    result
+}
+
+// Marker of a list item starting `line`, e.g. `-` or `1.`, and the text of the item.
+fn list_marker(line: &str) -> Option<(&str, &str)> {
+    let (marker, text) = line.split_once(char::is_whitespace)?;
+    let is_marker = match marker {
+        "-" | "*" | "+" => true,
+        _ => {
+            let number = marker.trim_end_matches(['.', ')']);
+            marker.len() == number.len() + 1
+                && (1..=3).contains(&number.len())
+                && number.chars().all(|c| c.is_ascii_digit())
         }
+    };
+    match is_marker {
+        true => Some((marker, text.trim_start())),
+        false if marker == "\u{2022}" => Some(("-", text.trim_start())),
+        false => None,
     }
-    format!("{}\n", splitted)
+}
+
+// Escapes the characters of a word rustdoc would take for markup, and makes URLs links.
+fn escape_markdown(word: &str) -> String {
+    if word.starts_with("http://") || word.starts_with("https://") {
+        let url = word.trim_end_matches(|c| ".,;:)".contains(c));
+        return format!("<{}>{}", url, &word[url.len()..]);
+    }
+    let last = word.chars().count().saturating_sub(1);
+    let mut escaped = String::with_capacity(word.len());
+    for (i, c) in word.chars().enumerate() {
+        let is_markup = match c {
+            '\\' | '`' | '[' | ']' | '<' | '>' | '*' | '|' => true,
+            '_' => i == 0 || i == last,
+            '#' => i == 0,
+            _ => false,
+        };
+        if is_markup {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
 }
 
 pub fn match_built_in_type(type_name: &str, xsd_ns: &Option<Namespace>) -> Option<&'static str> {
@@ -95,24 +176,21 @@ pub fn match_built_in_type(type_name: &str, xsd_ns: &Option<Namespace>) -> Optio
     }
 }
 
-pub fn sanitize(s: String) -> String {
-    if s.is_empty() {
-        s
-    } else if s.chars().next().unwrap().is_numeric() || RS_KEYWORDS.contains(&s.as_str()) {
-        format!("_{}", s)
-    } else {
-        s
+/// Name of a type of the target namespace without its prefix, the full name otherwise.
+pub fn local_name<'a>(type_name: &'a str, gen: &Generator) -> &'a str {
+    match type_name.split_once(':') {
+        Some((prefix, name)) => {
+            let tns = gen.target_ns.borrow();
+            if tns.as_ref().and_then(|ns| ns.name()) == Some(prefix) {
+                name
+            } else {
+                type_name
+            }
+        }
+        None => type_name,
     }
 }
 
-pub fn filter_type_name(name: &str) -> String {
-    fn is_valid_symbol(c: char) -> bool {
-        (c.is_alphanumeric() || c == '_') && c.is_ascii() && !c.is_whitespace()
-    }
-
-    name.chars().filter(|c| is_valid_symbol(*c)).collect()
-}
-
 pub fn split_name(name: &str) -> (Option<&str>, &str) {
     match name.find(':') {
         Some(index) => (Some(&name[0..index]), &name[index + 1..]),
@@ -120,88 +198,11 @@ pub fn split_name(name: &str) -> (Option<&str>, &str) {
     }
 }
 
-const RS_KEYWORDS: &[&str] = &[
-    "abstract",
-    "alignof",
-    "as",
-    "async",
-    "await",
-    "become",
-    "box",
-    "break",
-    "const",
-    "continue",
-    "crate",
-    "do",
-    "dyn",
-    "else",
-    "enum",
-    "extern crate",
-    "extern",
-    "false",
-    "final",
-    "fn",
-    "for",
-    "if let",
-    "if",
-    "impl",
-    "in",
-    "let",
-    "loop",
-    "macro",
-    "match",
-    "mod",
-    "move",
-    "mut",
-    "offsetof",
-    "override",
-    "priv",
-    "proc",
-    "pub",
-    "pure",
-    "ref",
-    "return",
-    "Self",
-    "self",
-    "sizeof",
-    "static",
-    "struct",
-    "super",
-    "trait",
-    "true",
-    "type",
-    "typeof",
-    "unsafe",
-    "unsized",
-    "use",
-    "virtual",
-    "where",
-    "while",
-    "yield",
-];
-
 #[cfg(test)]
 mod test {
-    use crate::generator::utils::{filter_type_name, match_built_in_type, split_name};
-
-    #[test]
-    fn test_filter_type_name() {
-        assert_eq!(filter_type_name("SomeType"), "SomeType");
-        assert_eq!(filter_type_name("Some-Type"), "SomeType");
-        assert_eq!(filter_type_name("Some Type"), "SomeType");
-        assert_eq!(filter_type_name("Some@Type"), "SomeType");
-        assert_eq!(filter_type_name("Some❤Type"), "SomeType");
-        assert_eq!(filter_type_name("Some_Type"), "Some_Type");
-
-        assert_eq!(
-            filter_type_name("http://www.w3.org/2005/08/addressing/reply"),
-            "httpwwww3org200508addressingreply"
-        );
-        assert_eq!(
-            filter_type_name("https://www.youtube.com/watch?v=dQw4w9WgXcQ"),
-            "httpswwwyoutubecomwatchvdQw4w9WgXcQ"
-        );
-    }
+    use crate::generator::utils::{
+        escape_markdown, format_comment_lines, list_marker, match_built_in_type, split_name,
+    };
 
     #[test]
     fn test_split_name() {
@@ -228,4 +229,42 @@ mod test {
         assert_eq!(match_type("xsd:string"), Some("String"));
         assert!(match_type("xs:string").is_none());
     }
+
+    #[test]
+    fn test_format_comment_lines() {
+        let doc = "
+            Values of the priority:
+              - 1 is a low priority, handled after all the others
+              - 2 is a high one
+
+            See http://example.com.";
+        let expected = "  /// Values of the priority:
+  /// - 1 is a low priority, handled
+  ///   after all the others
+  /// - 2 is a high one
+  ///
+  /// See <http://example.com>.
+";
+        assert_eq!(format_comment_lines(doc, "///", 40, 2, true), expected);
+        assert_eq!(format_comment_lines("a\n\n\nb", "//", 80, 0, false), "// a\n//\n// b\n");
+    }
+
+    #[test]
+    fn test_list_marker() {
+        assert_eq!(list_marker("- item"), Some(("-", "item")));
+        assert_eq!(list_marker("12.  item"), Some(("12.", "item")));
+        assert_eq!(list_marker("\u{2022} item"), Some(("-", "item")));
+        assert_eq!(list_marker("2014. was a year"), None);
+        assert_eq!(list_marker("-1 is invalid"), None);
+    }
+
+    #[test]
+    fn test_escape_markdown() {
+        assert_eq!(escape_markdown("Vec<[u8]>"), "Vec\\<\\[u8\\]\\>");
+        assert_eq!(escape_markdown("_snake_case_"), "\\_snake_case\\_");
+        assert_eq!(escape_markdown("`a*b`"), "\\`a\\*b\\`");
+        assert_eq!(escape_markdown("#1"), "\\#1");
+        assert_eq!(escape_markdown("(https://example.com),"), "(https://example.com),");
+        assert_eq!(escape_markdown("https://example.com),"), "<https://example.com>),");
+    }
 }
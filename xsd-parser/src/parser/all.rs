@@ -4,7 +4,7 @@ use roxmltree::Node;
 
 use crate::parser::{
     node_parser::parse_node,
-    types::{RsEntity, Struct, StructField, TypeModifier},
+    types::{RsEntity, Struct, StructField},
     utils::{enum_to_field, get_documentation, get_parent_name},
     xsd_elements::{ElementType, XsdNode},
 };
@@ -25,12 +25,7 @@ fn elements_to_fields(choice: &Node, parent_name: &str) -> Vec<StructField> {
         .children()
         .filter(|n| n.is_element() && n.xsd_type() != ElementType::Annotation)
         .map(|n| match parse_node(&n, choice) {
-            RsEntity::StructField(mut sf) => {
-                if sf.type_name.ends_with(parent_name) {
-                    sf.type_modifiers.push(TypeModifier::Recursive)
-                }
-                sf
-            }
+            RsEntity::StructField(sf) => sf,
             RsEntity::Enum(mut en) => {
                 en.name = format!("{}Choice", parent_name);
                 enum_to_field(en)
@@ -33,6 +33,7 @@ pub fn parse_attribute(node: &Node, parent: &Node) -> RsEntity {
         name,
         source: StructFieldSource::Attribute,
         type_modifiers: vec![type_modifier],
+        inherited: false,
     })
 }
 
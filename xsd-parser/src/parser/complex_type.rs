@@ -3,6 +3,7 @@ use std::cell::RefCell;
 use roxmltree::Node;
 
 use crate::parser::{
+    constants::attribute,
     node_parser::parse_node,
     types::{RsEntity, Struct, StructField, StructFieldSource},
     utils::{
@@ -30,6 +31,7 @@ pub fn parse_complex_type(node: &Node, parent: &Node) -> RsEntity {
         get_parent_name(node)
     };
 
+    let is_abstract = node.attribute(attribute::ABSTRACT) == Some("true");
     let mut fields = attributes_to_fields(node);
 
     let content = node
@@ -46,6 +48,9 @@ pub fn parse_complex_type(node: &Node, parent: &Node) -> RsEntity {
             comment: get_documentation(node),
             subtypes: vec![],
             name: name.to_string(),
+            base: None,
+            is_abstract,
+            ..Default::default()
         });
     }
     let content_node = content.unwrap();
@@ -55,6 +60,7 @@ pub fn parse_complex_type(node: &Node, parent: &Node) -> RsEntity {
         RsEntity::Struct(st) => {
             st.fields.borrow_mut().append(&mut fields);
             st.name = name.to_string();
+            st.is_abstract = is_abstract;
         }
         RsEntity::Enum(en) => {
             en.name = format!("{}Choice", name);
@@ -70,6 +76,9 @@ pub fn parse_complex_type(node: &Node, parent: &Node) -> RsEntity {
                 comment: get_documentation(node),
                 fields: RefCell::new(fields),
                 attribute_groups: RefCell::new(attribute_groups_to_aliases(node)),
+                base: None,
+                is_abstract,
+                ..Default::default()
             })];
         }
         _ => (),
@@ -107,6 +107,7 @@ fn parse_field_of_sequence(node: &Node, _: &Node) -> RsEntity {
         subtypes: vec![field_type],
         source: StructFieldSource::Element,
         type_modifiers: vec![element_modifier(node)],
+        inherited: false,
     })
 }
 
@@ -190,7 +191,7 @@ mod test {
         match parse_global_element(&element) {
             RsEntity::Struct(st) => {
                 assert_eq!(st.name, "ChangedOnly");
-                assert_eq!(st.comment.unwrap().trim(), "Doc Text");
+                assert_eq!(st.comment.unwrap().text(None).trim(), "Doc Text");
                 assert!(st.subtypes.is_empty());
                 assert!(st.fields.borrow().is_empty());
             }
@@ -30,22 +30,14 @@ pub fn parse_extension(node: &Node, _: &Node) -> RsEntity {
 
 fn simple_content_extension(node: &Node) -> RsEntity {
     let base = get_base(node);
-    let mut fields = attributes_to_fields(node);
-
-    fields.push(StructField {
-        name: tag::BASE.to_string(),
-        type_name: base.to_string(),
-        comment: get_documentation(node),
-        source: StructFieldSource::Base,
-        ..Default::default()
-    });
+    let fields = attributes_to_fields(node);
 
     RsEntity::Struct(Struct {
-        name: String::default(),
-        subtypes: vec![],
         comment: get_documentation(node),
         fields: RefCell::new(fields),
         attribute_groups: RefCell::new(attribute_groups_to_aliases(node)),
+        base: Some(base_field(base, node)),
+        ..Default::default()
     })
 }
 
@@ -54,14 +46,6 @@ fn complex_content_extension(node: &Node) -> RsEntity {
 
     let mut fields = attributes_to_fields(node);
 
-    fields.push(StructField {
-        name: tag::BASE.to_string(),
-        type_name: base.to_string(),
-        comment: get_documentation(node),
-        source: StructFieldSource::Base,
-        ..Default::default()
-    });
-
     let content = node
         .children()
         .filter(|n| {
@@ -76,6 +60,7 @@ fn complex_content_extension(node: &Node) -> RsEntity {
         if let RsEntity::Struct(s) = &mut res {
             s.fields.borrow_mut().append(&mut fields);
             s.comment = get_documentation(node);
+            s.base = Some(base_field(base, node));
             return res;
         }
     }
@@ -84,6 +69,17 @@ fn complex_content_extension(node: &Node) -> RsEntity {
         comment: get_documentation(node),
         fields: RefCell::new(fields),
         attribute_groups: RefCell::new(attribute_groups_to_aliases(node)),
+        base: Some(base_field(base, node)),
         ..Default::default()
     })
 }
+
+fn base_field(base: &str, node: &Node) -> StructField {
+    StructField {
+        name: tag::BASE.to_string(),
+        type_name: base.to_string(),
+        comment: get_documentation(node),
+        source: StructFieldSource::Base,
+        ..Default::default()
+    }
+}
@@ -12,6 +12,7 @@ mod extension;
 mod import;
 mod list;
 mod node_parser;
+mod recursion;
 mod restriction;
 pub mod schema;
 mod sequence;
@@ -26,14 +27,19 @@ pub mod xsd_elements;
 use std::collections::HashMap;
 
 use crate::parser::{
+    recursion::mark_recursive_fields,
     schema::parse_schema,
     types::{RsEntity, RsFile},
 };
 
 // FIXME: Actually pass up errors
 #[allow(clippy::result_unit_err)]
-pub fn parse(text: &str) -> Result<RsFile, ()> {
+pub fn parse(text: &str) -> Result<RsFile<'_>, ()> {
     let doc = roxmltree::Document::parse(text).expect("Parse document error");
+    Ok(parse_document(&doc))
+}
+
+pub fn parse_document<'input>(doc: &roxmltree::Document<'input>) -> RsFile<'input> {
     let root = doc.root();
 
     let mut map = HashMap::new();
@@ -41,7 +47,7 @@ pub fn parse(text: &str) -> Result<RsFile, ()> {
     let schema =
         root.children().filter(|e| e.is_element()).last().expect("Schema element is required");
 
-    let schema_rs = parse_schema(&schema);
+    let mut schema_rs = parse_schema(&schema);
     for ty in &schema_rs.types {
         if let RsEntity::Struct(st) = ty {
             map.extend(st.get_types_map());
@@ -58,6 +64,7 @@ pub fn parse(text: &str) -> Result<RsFile, ()> {
             st.extend_attribute_group(&map);
         }
     }
+    mark_recursive_fields(&mut schema_rs);
 
-    Ok(schema_rs)
+    schema_rs
 }
@@ -0,0 +1,231 @@
+//! Detection of recursive types.
+//!
+//! Types referencing themselves by value, directly or through other types, would have
+//! an infinite size. The references closing such cycles get `TypeModifier::Recursive`
+//! and are boxed by the generator. References through `Vec` are heap-allocated
+//! already and never closing a cycle.
+
+use std::collections::HashMap;
+
+use crate::parser::types::{RsEntity, RsFile, TypeModifier};
+
+pub fn mark_recursive_fields(file: &mut RsFile) {
+    let prefix = file.target_ns.as_ref().and_then(|ns| ns.name()).map(|p| format!("{}:", p));
+
+    let mut nodes = vec![];
+    let globals = file
+        .types
+        .iter()
+        .filter_map(|entity| collect(entity, None, &mut nodes).map(|id| (entity.name(), id)))
+        .collect::<HashMap<&str, usize>>();
+
+    let edges = nodes
+        .iter()
+        .enumerate()
+        .map(|(id, node)| {
+            node.targets
+                .iter()
+                .map(|target| {
+                    let name = target.as_deref()?;
+                    let name = match &prefix {
+                        Some(p) => name.strip_prefix(p.as_str()).unwrap_or(name),
+                        None => name,
+                    };
+                    resolve(name, id, &nodes).or_else(|| globals.get(name).copied())
+                })
+                .collect::<Vec<Option<usize>>>()
+        })
+        .collect::<Vec<_>>();
+
+    let marks = back_edges(&edges);
+    let mut counter = 0;
+    for entity in &mut file.types {
+        apply(entity, &marks, &mut counter);
+    }
+}
+
+struct Node {
+    parent: Option<usize>,
+    // Ids of the nested types by name.
+    scope: HashMap<String, usize>,
+    // By-value references in the order of fields or cases.
+    targets: Vec<Option<String>>,
+}
+
+fn by_value(modifiers: &[TypeModifier]) -> bool {
+    !modifiers.iter().any(|m| matches!(m, TypeModifier::Array | TypeModifier::Empty))
+}
+
+fn target(type_name: &str, modifiers: &[TypeModifier]) -> Option<String> {
+    Some(type_name.to_string()).filter(|_| by_value(modifiers))
+}
+
+// Assigns ids to types in pre-order, `apply` visits them in the same order.
+fn collect(entity: &RsEntity, parent: Option<usize>, nodes: &mut Vec<Node>) -> Option<usize> {
+    let targets = match entity {
+        RsEntity::Struct(st) => {
+            st.fields.borrow().iter().map(|f| target(&f.type_name, &f.type_modifiers)).collect()
+        }
+        RsEntity::TupleStruct(ts) => vec![target(&ts.type_name, &ts.type_modifiers)],
+        RsEntity::Enum(en) => en
+            .cases
+            .iter()
+            .map(|c| c.type_name.as_ref().and_then(|t| target(t, &c.type_modifiers)))
+            .collect(),
+        RsEntity::Alias(al) => {
+            al.subtypes.iter().for_each(|s| {
+                collect(s, parent, nodes);
+            });
+            return None;
+        }
+        _ => return None,
+    };
+
+    let id = nodes.len();
+    nodes.push(Node { parent, scope: HashMap::new(), targets });
+
+    let mut visit = |subtype: &RsEntity| {
+        if let Some(sub_id) = collect(subtype, Some(id), nodes) {
+            nodes[id].scope.insert(subtype.name().to_string(), sub_id);
+        }
+    };
+    match entity {
+        RsEntity::Struct(st) => {
+            st.subtypes.iter().for_each(&mut visit);
+            st.fields.borrow().iter().flat_map(|f| f.subtypes.iter()).for_each(&mut visit);
+        }
+        RsEntity::TupleStruct(ts) => ts.subtypes.iter().for_each(&mut visit),
+        RsEntity::Enum(en) => en.subtypes.iter().for_each(&mut visit),
+        _ => {}
+    }
+    Some(id)
+}
+
+// Nested types shadow the ones of enclosing types and global ones.
+fn resolve(name: &str, id: usize, nodes: &[Node]) -> Option<usize> {
+    let mut current = Some(id);
+    while let Some(i) = current {
+        if let Some(found) = nodes[i].scope.get(name) {
+            return Some(*found);
+        }
+        current = nodes[i].parent;
+    }
+    None
+}
+
+// Depth-first search over the references, marking the ones to a type which is being
+// visited, i.e. the ones closing a cycle.
+fn back_edges(edges: &[Vec<Option<usize>>]) -> Vec<Vec<bool>> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum State {
+        New,
+        Active,
+        Done,
+    }
+
+    fn visit(
+        id: usize,
+        edges: &[Vec<Option<usize>>],
+        state: &mut [State],
+        marks: &mut [Vec<bool>],
+    ) {
+        state[id] = State::Active;
+        for (i, target) in edges[id].iter().enumerate() {
+            match target.map(|t| (t, state[t])) {
+                Some((_, State::Active)) => marks[id][i] = true,
+                Some((t, State::New)) => visit(t, edges, state, marks),
+                _ => {}
+            }
+        }
+        state[id] = State::Done;
+    }
+
+    let mut state = vec![State::New; edges.len()];
+    let mut marks = edges.iter().map(|e| vec![false; e.len()]).collect::<Vec<_>>();
+    for id in 0..edges.len() {
+        if state[id] == State::New {
+            visit(id, edges, &mut state, &mut marks);
+        }
+    }
+    marks
+}
+
+fn apply(entity: &mut RsEntity, marks: &[Vec<bool>], counter: &mut usize) {
+    let mark = |modifiers: &mut Vec<TypeModifier>, marked: bool| {
+        if marked && !modifiers.contains(&TypeModifier::Recursive) {
+            modifiers.push(TypeModifier::Recursive);
+        }
+    };
+
+    match entity {
+        RsEntity::Struct(st) => {
+            let id = *counter;
+            *counter += 1;
+            st.subtypes.iter_mut().for_each(|s| apply(s, marks, counter));
+            let mut fields = st.fields.borrow_mut();
+            for field in fields.iter_mut() {
+                field.subtypes.iter_mut().for_each(|s| apply(s, marks, counter));
+            }
+            for (field, marked) in fields.iter_mut().zip(&marks[id]) {
+                mark(&mut field.type_modifiers, *marked);
+            }
+        }
+        RsEntity::TupleStruct(ts) => {
+            let id = *counter;
+            *counter += 1;
+            ts.subtypes.iter_mut().for_each(|s| apply(s, marks, counter));
+            mark(&mut ts.type_modifiers, marks[id][0]);
+        }
+        RsEntity::Enum(en) => {
+            let id = *counter;
+            *counter += 1;
+            en.subtypes.iter_mut().for_each(|s| apply(s, marks, counter));
+            for (case, marked) in en.cases.iter_mut().zip(&marks[id]) {
+                mark(&mut case.type_modifiers, *marked);
+            }
+        }
+        RsEntity::Alias(al) => al.subtypes.iter_mut().for_each(|s| apply(s, marks, counter)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::parser::{parse, types::RsEntity};
+
+    fn modifiers(entity: &RsEntity, field: usize) -> Vec<super::TypeModifier> {
+        match entity {
+            RsEntity::Struct(st) => st.fields.borrow()[field].type_modifiers.clone(),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_mutual_recursion() {
+        let schema = parse(
+            r#"
+    <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"
+               xmlns:tns="http://example.com"
+               targetNamespace="http://example.com">
+        <xs:complexType name="A">
+            <xs:sequence>
+                <xs:element name="B" type="tns:B"/>
+            </xs:sequence>
+        </xs:complexType>
+        <xs:complexType name="B">
+            <xs:sequence>
+                <xs:element name="A" type="tns:A" minOccurs="0"/>
+                <xs:element name="Items" type="tns:B" maxOccurs="unbounded"/>
+            </xs:sequence>
+        </xs:complexType>
+    </xs:schema>
+            "#,
+        )
+        .unwrap();
+
+        use super::TypeModifier::*;
+        assert_eq!(modifiers(&schema.types[0], 0), vec![None]);
+        assert_eq!(modifiers(&schema.types[1], 0), vec![Option, Recursive]);
+        assert_eq!(modifiers(&schema.types[1], 1), vec![Array]);
+    }
+}
@@ -16,6 +16,8 @@ use crate::parser::{
     xsd_elements::{ElementType, FacetType, RestrictionType, XsdNode},
 };
 
+const XSD_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema";
+
 const AVAILABLE_CONTENT_TYPES: [ElementType; 7] = [
     ElementType::All, // Not presented in ONVIF
     ElementType::AnyAttribute,
@@ -127,14 +129,41 @@ fn cases(facets: &[Facet]) -> Vec<EnumCase> {
 }
 
 fn is_simple_enumerations(node: &Node) -> bool {
+    let matched_by_value = is_collapsed_string_type(node, get_base(node));
     node.children()
         .filter(|n| matches!(n.xsd_type(), ElementType::Facet(FacetType::Enumeration(_))))
-        .all(|n| is_simple_enumeration(&n))
+        .all(|n| is_simple_enumeration(&n, matched_by_value))
 }
 
-fn is_simple_enumeration(node: &Node) -> bool {
+fn is_simple_enumeration(node: &Node, matched_by_value: bool) -> bool {
     let val = node.attr_value().expect("Value required for xsd:enumeration");
-    !val.is_empty() && val.chars().all(|c| c.is_alphanumeric() || c == '-')
+    // Values which look like QNames depend on the namespace prefixes in scope, unless the
+    // enumeration compares them as they are.
+    !val.is_empty() && (matched_by_value || !val.contains(':'))
+}
+
+// Built-in string types whose whitespace is collapsed, like `xs:token` or `xs:QName`.
+// Their enumerations are typed, matched by value rather than renamed variants.
+fn is_collapsed_string_type(node: &Node, type_name: &str) -> bool {
+    let (prefix, name) = match type_name.split_once(':') {
+        Some((prefix, name)) => (Some(prefix), name),
+        None => (None, type_name),
+    };
+    node.lookup_namespace_uri(prefix) == Some(XSD_NAMESPACE)
+        && matches!(
+            name,
+            "token"
+                | "language"
+                | "Name"
+                | "NCName"
+                | "NMTOKEN"
+                | "ID"
+                | "IDREF"
+                | "ENTITY"
+                | "anyURI"
+                | "QName"
+                | "NOTATION"
+        )
 }
 
 #[cfg(test)]
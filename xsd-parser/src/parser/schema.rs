@@ -1,8 +1,9 @@
 use roxmltree::Node;
 
 use crate::parser::{
+    constants::attribute,
     node_parser::parse_node,
-    types::RsFile,
+    types::{RootElement, RsFile},
     utils::target_namespace,
     xsd_elements::{ElementType, XsdNode},
 };
@@ -35,6 +36,27 @@ pub fn parse_schema<'input>(schema: &Node<'_, 'input>) -> RsFile<'input> {
             .filter(|n| n.is_element() && n.xsd_type() == ElementType::AttributeGroup)
             .map(|node| parse_node(&node, schema))
             .collect(),
+        root_elements: schema
+            .children()
+            .filter(|n| n.is_element() && n.xsd_type() == ElementType::Element)
+            .filter(|n| n.attribute(attribute::ABSTRACT) != Some("true"))
+            .filter_map(|n| {
+                let name = n.attr_name()?;
+                Some(RootElement {
+                    name: name.to_string(),
+                    type_name: n.attr_type().unwrap_or(name).to_string(),
+                    substitution_group: n
+                        .attribute(attribute::SUBSTITUTION_GROUP)
+                        .map(|s| s.to_string()),
+                })
+            })
+            .collect(),
+        namespaces: schema
+            .namespaces()
+            .filter(|ns| ns.name().is_some() && ns.name() != Some("xml"))
+            .filter(|ns| ns.uri() != "http://www.w3.org/2001/XMLSchema")
+            .cloned()
+            .collect(),
     }
 }
 
@@ -79,4 +101,36 @@ mod test {
         let res = parse_schema(&doc.root_element());
         assert_eq!(res.xsd_ns.unwrap().name().unwrap(), "xs");
     }
+
+    #[test]
+    fn test_root_elements() {
+        let doc = roxmltree::Document::parse(
+            r#"
+    <xs:schema
+        xmlns:tns="http://example.com"
+        xmlns:xs="http://www.w3.org/2001/XMLSchema"
+        targetNamespace="http://example.com"
+        >
+        <xs:element name="Foo" type="tns:FooType"/>
+        <xs:element name="Bar">
+            <xs:complexType/>
+        </xs:element>
+        <xs:element name="Baz" type="tns:FooType" abstract="true"/>
+        <xs:complexType name="FooType"/>
+    </xs:schema>
+                "#,
+        )
+        .unwrap();
+
+        let res = parse_schema(&doc.root_element());
+        let roots = res
+            .root_elements
+            .iter()
+            .map(|r| (r.name.as_str(), r.type_name.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(roots, vec![("Foo", "tns:FooType"), ("Bar", "Bar")]);
+
+        let namespaces = res.namespaces.iter().map(|ns| ns.name().unwrap()).collect::<Vec<_>>();
+        assert_eq!(namespaces, vec!["tns"]);
+    }
 }
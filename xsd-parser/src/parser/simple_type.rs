@@ -67,7 +67,7 @@ mod test {
                 assert_eq!(ts.name, "SomeType");
                 assert_eq!(ts.type_name, "xs:SSD");
                 assert_eq!(ts.type_modifiers, vec![TypeModifier::Array, TypeModifier::Array]);
-                assert_eq!(ts.comment.unwrap().trim(), "Some text");
+                assert_eq!(ts.comment.unwrap().text(None).trim(), "Some text");
                 assert!(ts.subtypes.is_empty());
             }
             _ => unreachable!("Test failed!"),
@@ -106,7 +106,7 @@ mod test {
                 assert_eq!(ts.name, "SomeType");
                 assert_eq!(ts.type_name, "SomeTypeEnum");
                 assert_eq!(ts.type_modifiers, vec![TypeModifier::Array]);
-                assert_eq!(ts.comment.unwrap().trim(), "Some text");
+                assert_eq!(ts.comment.unwrap().text(None).trim(), "Some text");
                 assert_eq!(ts.subtypes.len(), 1);
                 match &ts.subtypes[0] {
                     RsEntity::Enum(en) => {
@@ -122,4 +122,34 @@ mod test {
             _ => unreachable!(),
         }
     }
+
+    #[test]
+    fn test_documentation_languages() {
+        use crate::parser::parse;
+
+        let text = r#"
+    <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:simpleType name="Priority">
+            <xs:annotation>
+                <xs:documentation xml:lang="en-US">Priority of a task.</xs:documentation>
+                <xs:documentation xml:lang="de">Priorität einer Aufgabe.</xs:documentation>
+                <xs:documentation>Values are <b>1</b> to 3.</xs:documentation>
+            </xs:annotation>
+            <xs:restriction base="xs:int"/>
+        </xs:simpleType>
+    </xs:schema>
+    "#;
+        let result = parse(text).unwrap();
+        let doc = match &result.types[0] {
+            crate::parser::types::RsEntity::TupleStruct(ts) => ts.comment.clone().unwrap(),
+            _ => unreachable!(),
+        };
+
+        assert_eq!(doc.parts.len(), 3);
+        assert_eq!(doc.parts[2].text, "Values are 1 to 3.");
+        assert_eq!(doc.text(Some("en")), "Priority of a task.\n\nValues are 1 to 3.");
+        assert_eq!(doc.text(Some("DE")), "Priorität einer Aufgabe.\n\nValues are 1 to 3.");
+        assert_eq!(doc.text(Some("fr")), "Values are 1 to 3.");
+        assert_eq!(doc.text(None).split("\n\n").count(), 3);
+    }
 }
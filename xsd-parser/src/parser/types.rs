@@ -12,15 +12,74 @@ pub struct RsFile<'input> {
     pub attribute_groups: Vec<RsEntity>,
     pub target_ns: Option<Namespace<'input>>,
     pub xsd_ns: Option<Namespace<'input>>,
+    /// Non-abstract global elements, which documents of the schema may have as root.
+    pub root_elements: Vec<RootElement>,
+    /// Prefixed namespaces declared on the schema, except for the XSD one.
+    pub namespaces: Vec<Namespace<'input>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RootElement {
+    pub name: String,
+    /// Type of the element, the element name for anonymous types.
+    pub type_name: String,
+    /// Head of the substitution group of the element.
+    pub substitution_group: Option<String>,
+}
+
+/// Text of the `xs:documentation` elements of an annotation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Documentation {
+    pub parts: Vec<DocumentationPart>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DocumentationPart {
+    /// Value of the `xml:lang` attribute.
+    pub lang: Option<String>,
+    pub text: String,
+}
+
+impl Documentation {
+    /// Text of the parts in `lang` and of the ones without a language, separated by blank
+    /// lines. Parts in other languages are used only when there are none of those, and all
+    /// parts are used without `lang`.
+    pub fn text(&self, lang: Option<&str>) -> String {
+        let in_lang = |part: &&DocumentationPart| match (lang, part.lang.as_deref()) {
+            (Some(lang), Some(part_lang)) => {
+                let part_lang = part_lang.to_ascii_lowercase();
+                let lang = lang.to_ascii_lowercase();
+                part_lang == lang || part_lang.starts_with(&format!("{}-", lang))
+            }
+            _ => true,
+        };
+        let mut parts = self.parts.iter().filter(in_lang).peekable();
+        let parts: Vec<&DocumentationPart> = match parts.peek() {
+            Some(_) => parts.collect(),
+            None => self.parts.iter().collect(),
+        };
+        parts.iter().map(|p| p.text.as_str()).collect::<Vec<&str>>().join("\n\n")
+    }
+}
+
+impl From<&str> for Documentation {
+    fn from(text: &str) -> Self {
+        Documentation { parts: vec![DocumentationPart { lang: None, text: text.to_string() }] }
+    }
 }
 
 #[derive(Debug, Default, Clone)]
 pub struct Struct {
     pub name: String,
-    pub comment: Option<String>,
+    pub comment: Option<Documentation>,
     pub fields: RefCell<Vec<StructField>>,
     pub attribute_groups: RefCell<Vec<Alias>>,
     pub subtypes: Vec<RsEntity>,
+    /// Base of a complex type extension. Its fields are copied into `fields` as inherited ones.
+    pub base: Option<StructField>,
+    /// Elements of the base the extension declares again, whose fields are not inherited.
+    pub shadowed: RefCell<Vec<String>>,
+    pub is_abstract: bool,
 }
 
 impl Struct {
@@ -43,14 +102,20 @@ impl Struct {
             .borrow()
             .iter()
             .filter(|f| f.name.as_str() == tag::BASE)
+            .chain(self.base.iter())
             .flat_map(|f| {
-                let key = f.type_name.split(':').last().unwrap().to_string();
+                let key = f.type_name.rsplit(':').next().unwrap().to_string();
                 types.get(&key).map(|s| s.fields.borrow().clone()).unwrap_or_default()
             })
             .filter(|f| {
                 //TODO: remove this workaround for fields names clash
-                !self.fields.borrow().iter().any(|field| field.name == f.name)
+                let clash = self.fields.borrow().iter().any(|field| field.name == f.name);
+                if clash && matches!(f.source, StructFieldSource::Element) {
+                    self.shadowed.borrow_mut().push(f.name.clone());
+                }
+                !clash
             })
+            .map(|f| StructField { inherited: true, ..f })
             .collect::<Vec<StructField>>();
 
         self.fields.borrow_mut().append(&mut fields);
@@ -70,7 +135,7 @@ impl Struct {
             .borrow()
             .iter()
             .flat_map(|f| {
-                let key = f.original.split(':').last().unwrap().to_string();
+                let key = f.original.rsplit(':').next().unwrap().to_string();
                 types.get(&key).map(|s| s.fields.borrow().clone()).unwrap_or_default()
             })
             .collect::<Vec<StructField>>();
@@ -83,10 +148,12 @@ impl Struct {
 pub struct StructField {
     pub name: String,
     pub type_name: String,
-    pub comment: Option<String>,
+    pub comment: Option<Documentation>,
     pub subtypes: Vec<RsEntity>,
     pub source: StructFieldSource,
     pub type_modifiers: Vec<TypeModifier>,
+    /// The field is copied from the base of an extension.
+    pub inherited: bool,
 }
 
 impl StructField {
@@ -113,13 +180,13 @@ pub enum StructFieldSource {
 #[derive(Debug, Clone)]
 pub struct Facet {
     pub facet_type: FacetType,
-    pub comment: Option<String>,
+    pub comment: Option<Documentation>,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct TupleStruct {
     pub name: String,
-    pub comment: Option<String>,
+    pub comment: Option<Documentation>,
     pub type_name: String,
     pub subtypes: Vec<RsEntity>,
     pub type_modifiers: Vec<TypeModifier>,
@@ -130,7 +197,7 @@ pub struct TupleStruct {
 pub struct Enum {
     pub name: String,
     pub cases: Vec<EnumCase>,
-    pub comment: Option<String>,
+    pub comment: Option<Documentation>,
     pub type_name: String,
     pub subtypes: Vec<RsEntity>,
     pub source: EnumSource,
@@ -158,7 +225,7 @@ pub enum TypeModifier {
 #[derive(Debug, Clone, Default)]
 pub struct EnumCase {
     pub name: String,
-    pub comment: Option<String>,
+    pub comment: Option<Documentation>,
     pub value: String,
     pub type_name: Option<String>,
     pub type_modifiers: Vec<TypeModifier>,
@@ -169,7 +236,7 @@ pub struct EnumCase {
 pub struct Alias {
     pub name: String,
     pub original: String,
-    pub comment: Option<String>,
+    pub comment: Option<Documentation>,
     pub subtypes: Vec<RsEntity>,
 }
 
@@ -177,7 +244,7 @@ pub struct Alias {
 pub struct Import {
     pub name: String,
     pub location: String,
-    pub comment: Option<String>,
+    pub comment: Option<Documentation>,
 }
 
 #[derive(Debug, Clone)]
@@ -218,7 +285,7 @@ impl RsEntity {
         }
     }
 
-    pub fn set_comment(&mut self, comment: Option<String>) {
+    pub fn set_comment(&mut self, comment: Option<Documentation>) {
         use RsEntity::*;
         match self {
             Struct(s) => s.comment = comment,
@@ -5,7 +5,9 @@ use roxmltree::{Namespace, Node};
 use crate::parser::{
     constants::attribute,
     node_parser::parse_node,
-    types::{Alias, Enum, RsEntity, StructField, StructFieldSource},
+    types::{
+        Alias, Documentation, DocumentationPart, Enum, RsEntity, StructField, StructFieldSource,
+    },
     xsd_elements::{ElementType, XsdNode},
 };
 
@@ -20,10 +22,25 @@ pub fn find_child<'a, 'input>(node: &Node<'a, 'input>, tag_name: &str) -> Option
     node.children().find(|e| e.is_element() && e.tag_name().name() == tag_name)
 }
 
-pub fn get_documentation(node: &Node<'_, '_>) -> Option<String> {
-    find_child(node, "annotation")
-        .and_then(|node| find_child(&node, "documentation"))
-        .and_then(|node| node.text().map(|s| s.to_string()))
+const XML_NS: &str = "http://www.w3.org/XML/1998/namespace";
+
+pub fn get_documentation(node: &Node<'_, '_>) -> Option<Documentation> {
+    let annotation = find_child(node, "annotation")?;
+    let parts = annotation
+        .children()
+        .filter(|e| e.is_element() && e.tag_name().name() == "documentation")
+        .map(|doc| DocumentationPart {
+            lang: doc.attribute((XML_NS, "lang")).map(|s| s.to_string()),
+            // Markup like XHTML is dropped, keeping its text.
+            text: doc.descendants().filter_map(|n| n.text().filter(|_| n.is_text())).collect(),
+        })
+        .filter(|part| !part.text.trim().is_empty())
+        .collect::<Vec<_>>();
+    if parts.is_empty() {
+        None
+    } else {
+        Some(Documentation { parts })
+    }
 }
 
 pub fn get_parent_name<'a>(node: &Node<'a, '_>) -> &'a str {
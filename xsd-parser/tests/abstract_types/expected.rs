@@ -0,0 +1,121 @@
+pub trait ShapeTrait {
+    fn name(&self) -> &String;
+}
+
+#[derive(PartialEq, Debug, UtilsAbstractSerDe)]
+pub enum Shape {
+    #[xsd(type_name = "Circle", namespace = "http://example.com", prefix = "tns")]
+    Circle(Circle),
+    #[xsd(type_name = "Square", namespace = "http://example.com", prefix = "tns")]
+    Square(Square),
+}
+
+impl Default for Shape {
+    fn default() -> Self {
+        Self::Circle(Default::default())
+    }
+}
+
+impl Validate for Shape {}
+
+impl ShapeTrait for Shape {
+    fn name(&self) -> &String {
+        match self {
+            Self::Circle(value) => ShapeTrait::name(value),
+            Self::Square(value) => ShapeTrait::name(value),
+        }
+    }
+}
+
+pub trait PolygonTrait: ShapeTrait {
+    fn corners(&self) -> &i32;
+}
+
+#[derive(PartialEq, Debug, UtilsAbstractSerDe)]
+pub enum Polygon {
+    #[xsd(type_name = "Square", namespace = "http://example.com", prefix = "tns")]
+    Square(Square),
+}
+
+impl Default for Polygon {
+    fn default() -> Self {
+        Self::Square(Default::default())
+    }
+}
+
+impl Validate for Polygon {}
+
+impl PolygonTrait for Polygon {
+    fn corners(&self) -> &i32 {
+        match self {
+            Self::Square(value) => PolygonTrait::corners(value),
+        }
+    }
+}
+
+impl ShapeTrait for Polygon {
+    fn name(&self) -> &String {
+        match self {
+            Self::Square(value) => ShapeTrait::name(value),
+        }
+    }
+}
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+pub struct Circle {
+    #[yaserde(prefix = "tns", rename = "Radius")]
+    pub radius: f64,
+
+    #[yaserde(prefix = "tns", rename = "Name")]
+    pub name: String,
+}
+
+impl Validate for Circle {}
+
+impl ShapeTrait for Circle {
+    fn name(&self) -> &String {
+        &self.name
+    }
+}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+pub struct Square {
+    #[yaserde(prefix = "tns", rename = "Side")]
+    pub side: f64,
+
+    #[yaserde(prefix = "tns", rename = "Corners")]
+    pub corners: i32,
+
+    #[yaserde(prefix = "tns", rename = "Name")]
+    pub name: String,
+}
+
+impl Validate for Square {}
+
+impl ShapeTrait for Square {
+    fn name(&self) -> &String {
+        &self.name
+    }
+}
+
+impl PolygonTrait for Square {
+    fn corners(&self) -> &i32 {
+        &self.corners
+    }
+}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+pub struct Drawing {
+    #[yaserde(prefix = "tns", rename = "Shape")]
+    pub shape: Shape,
+}
+
+impl Validate for Drawing {}
+
+
+// pub type Drawing = Drawing;
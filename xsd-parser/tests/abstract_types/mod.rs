@@ -0,0 +1,99 @@
+use super::utils;
+
+mod expected {
+    use xsd_macro_utils::*;
+    use xsd_parser::generator::validator::Validate;
+    use yaserde_derive::{YaDeserialize, YaSerialize};
+
+    include!("expected.rs");
+}
+
+use expected::{PolygonTrait, ShapeTrait};
+
+#[test]
+fn deserialization_works() {
+    let ser = include_str!("example.xml");
+
+    let de: expected::Drawing = yaserde::de::from_str(ser).unwrap();
+
+    assert_eq!(
+        de,
+        expected::Drawing {
+            shape: expected::Shape::Square(expected::Square {
+                side: 2.5,
+                corners: 4,
+                name: "square".to_string(),
+            }),
+        }
+    );
+}
+
+#[test]
+fn deserialization_without_xsi_type_uses_first_derivation() {
+    let ser = r#"<tns:Drawing xmlns:tns="http://example.com"><tns:Shape><tns:Radius>1</tns:Radius><tns:Name>circle</tns:Name></tns:Shape></tns:Drawing>"#;
+
+    let de: expected::Drawing = yaserde::de::from_str(ser).unwrap();
+
+    assert_eq!(
+        de.shape,
+        expected::Shape::Circle(expected::Circle { radius: 1.0, name: "circle".to_string() })
+    );
+}
+
+#[test]
+fn deserialization_resolves_xsi_type_prefix() {
+    let ser = r#"<tns:Drawing xmlns:tns="http://example.com" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"><tns:Shape xmlns:ex="http://example.com" xsi:type="ex:Square"><tns:Side>1</tns:Side><tns:Corners>4</tns:Corners><tns:Name>square</tns:Name></tns:Shape></tns:Drawing>"#;
+
+    let de: expected::Drawing = yaserde::de::from_str(ser).unwrap();
+
+    assert!(matches!(de.shape, expected::Shape::Square(_)));
+}
+
+#[test]
+fn serialization_round_trip_writes_xsi_type() {
+    let drawing = expected::Drawing {
+        shape: expected::Shape::Square(expected::Square {
+            side: 2.5,
+            corners: 4,
+            name: "square".to_string(),
+        }),
+    };
+
+    let ser = yaserde::ser::to_string(&drawing).unwrap();
+
+    assert_eq!(
+        ser,
+        r#"<?xml version="1.0" encoding="utf-8"?><tns:Drawing xmlns:tns="http://example.com"><tns:Shape xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:type="tns:Square"><tns:Side>2.5</tns:Side><tns:Corners>4</tns:Corners><tns:Name>square</tns:Name></tns:Shape></tns:Drawing>"#
+    );
+
+    let de: expected::Drawing = yaserde::de::from_str(&ser).unwrap();
+    assert_eq!(de, drawing);
+}
+
+#[test]
+fn traits_give_access_to_base_fields() {
+    fn describe(shape: &impl ShapeTrait) -> String {
+        shape.name().clone()
+    }
+
+    let square = expected::Square { side: 2.5, corners: 4, name: "square".to_string() };
+    assert_eq!(describe(&square), "square");
+    assert_eq!(*square.corners(), 4);
+
+    let polygon = expected::Polygon::Square(square);
+    assert_eq!(describe(&polygon), "square");
+    assert_eq!(*PolygonTrait::corners(&polygon), 4);
+
+    let shape = expected::Shape::Circle(expected::Circle::default());
+    assert_eq!(describe(&shape), "");
+}
+
+#[test]
+fn generator_does_not_panic() {
+    println!("{}", utils::generate(include_str!("input.xsd")))
+}
+
+#[test]
+fn generator_output_has_correct_ast() {
+    utils::ast_test(include_str!("input.xsd"), include_str!("expected.rs"));
+}
@@ -0,0 +1,39 @@
+/// A task of a Vec\<Task\> queue.
+///
+/// Tasks are handled:
+/// - by \[priority\], highest first;
+/// - in the order they were added
+///   otherwise.
+///
+/// See <https://example.com/tasks>.
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+pub struct Task {
+    /// Priority of the task, \`1\` by default.
+    #[yaserde(prefix = "tns", rename = "Priority")]
+    pub priority: Priority,
+}
+
+impl Validate for Task {}
+
+
+/// Priorität einer Aufgabe.
+#[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+
+pub enum Priority {
+    /// L
+    Low,
+    High,
+    __Unknown__(String),
+}
+
+impl Default for Priority {
+    fn default() -> Priority {
+        Self::__Unknown__("No valid variants".into())
+    }
+}
+
+impl Validate for Priority {}
+
+
+
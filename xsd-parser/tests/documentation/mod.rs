@@ -0,0 +1,47 @@
+use xsd_parser::generator::builder::GeneratorBuilder;
+
+use super::utils;
+
+mod expected {
+    use xsd_parser::generator::validator::Validate;
+    use yaserde_derive::{YaDeserialize, YaSerialize};
+
+    include!("expected.rs");
+}
+
+#[test]
+fn deserialization_works() {
+    let ser = include_str!("example.xml");
+
+    let de: expected::Task = yaserde::de::from_str(ser).unwrap();
+
+    assert_eq!(de, expected::Task { priority: expected::Priority::High });
+}
+
+#[test]
+fn all_languages_are_used_by_default() {
+    let code = utils::generate(include_str!("input.xsd"));
+
+    assert!(code.contains("/// Eine Aufgabe einer Warteschlange."));
+    assert!(code.contains("/// A task of a Vec\\<Task\\> queue."));
+}
+
+#[test]
+fn generator_does_not_panic() {
+    println!(
+        "{}",
+        utils::generate_with(
+            include_str!("input.xsd"),
+            GeneratorBuilder::default().with_doc_lang("en")
+        )
+    )
+}
+
+#[test]
+fn generator_output_has_correct_ast() {
+    utils::ast_test_with(
+        include_str!("input.xsd"),
+        include_str!("expected.rs"),
+        GeneratorBuilder::default().with_doc_lang("en"),
+    );
+}
@@ -0,0 +1,26 @@
+#[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+pub enum FooType {
+    #[yaserde(rename = "+")]
+    Plus,
+    #[yaserde(rename = "-")]
+    Minus,
+    #[yaserde(rename = "a.b")]
+    AB,
+    #[yaserde(rename = "a_b")]
+    AB2,
+    #[yaserde(rename = "Größe")]
+    Grosse,
+    #[yaserde(rename = "Self")]
+    _Self,
+    #[yaserde(rename = "type")]
+    Type,
+    __Unknown__(String),
+}
+
+impl Default for FooType {
+    fn default() -> FooType {
+        Self::__Unknown__("No valid variants".into())
+    }
+}
+impl Validate for FooType {}
@@ -0,0 +1,270 @@
+#[derive(PartialEq, Debug, UtilsDefaultSerde)]
+pub enum Priority {
+    /// Low
+    _1,
+    /// High
+    _2,
+    Minus1,
+    __Unknown__(i32),
+}
+
+impl Priority {
+    pub const ALL: &[Priority] = &[Priority::_1, Priority::_2, Priority::Minus1];
+
+    pub fn value(&self) -> i32 {
+        match self {
+            Priority::_1 => 1i32,
+            Priority::_2 => 2i32,
+            Priority::Minus1 => -1i32,
+            Priority::__Unknown__(value) => *value,
+        }
+    }
+}
+
+impl std::convert::TryFrom<i32> for Priority {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        if value == 1i32 {
+            Ok(Priority::_1)
+        } else if value == 2i32 {
+            Ok(Priority::_2)
+        } else if value == -1i32 {
+            Ok(Priority::Minus1)
+        } else {
+            Err(value)
+        }
+    }
+}
+
+impl std::str::FromStr for Priority {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim().parse::<i32>().map_err(|e| e.to_string())?;
+        Ok(<Self as std::convert::TryFrom<i32>>::try_from(value).unwrap_or_else(Self::__Unknown__))
+    }
+}
+
+impl std::fmt::Display for Priority {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Priority::_1 => f.write_str("1"),
+            Priority::_2 => f.write_str("2"),
+            Priority::Minus1 => f.write_str("-1"),
+            Priority::__Unknown__(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl Default for Priority {
+    fn default() -> Priority {
+        Self::__Unknown__(Default::default())
+    }
+}
+
+impl Validate for Priority {}
+
+
+
+#[derive(PartialEq, Debug, UtilsDefaultSerde)]
+pub enum Rate {
+    _05,
+    _10,
+    __Unknown__(xs::Decimal),
+}
+
+impl Rate {
+    pub const ALL: &[Rate] = &[Rate::_05, Rate::_10];
+
+    pub fn value(&self) -> xs::Decimal {
+        match self {
+            Rate::_05 => xs::Decimal::new(5, 1),
+            Rate::_10 => xs::Decimal::new(10, 1),
+            Rate::__Unknown__(value) => value.clone(),
+        }
+    }
+}
+
+impl std::convert::TryFrom<xs::Decimal> for Rate {
+    type Error = xs::Decimal;
+
+    fn try_from(value: xs::Decimal) -> Result<Self, Self::Error> {
+        if value == xs::Decimal::new(5, 1) {
+            Ok(Rate::_05)
+        } else if value == xs::Decimal::new(10, 1) {
+            Ok(Rate::_10)
+        } else {
+            Err(value)
+        }
+    }
+}
+
+impl std::str::FromStr for Rate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim().parse::<xs::Decimal>().map_err(|e| e.to_string())?;
+        Ok(<Self as std::convert::TryFrom<xs::Decimal>>::try_from(value).unwrap_or_else(Self::__Unknown__))
+    }
+}
+
+impl std::fmt::Display for Rate {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Rate::_05 => f.write_str("0.5"),
+            Rate::_10 => f.write_str("1.0"),
+            Rate::__Unknown__(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl Default for Rate {
+    fn default() -> Rate {
+        Self::__Unknown__(Default::default())
+    }
+}
+
+impl Validate for Rate {}
+
+
+
+#[derive(PartialEq, Debug, UtilsDefaultSerde)]
+pub enum Level {
+    _15,
+    NaN,
+    __Unknown__(f64),
+}
+
+impl Level {
+    pub const ALL: &[Level] = &[Level::_15, Level::NaN];
+
+    pub fn value(&self) -> f64 {
+        match self {
+            Level::_15 => 1.5f64,
+            Level::NaN => f64::NAN,
+            Level::__Unknown__(value) => *value,
+        }
+    }
+}
+
+impl std::convert::TryFrom<f64> for Level {
+    type Error = f64;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if value == 1.5f64 {
+            Ok(Level::_15)
+        } else if value.is_nan() {
+            Ok(Level::NaN)
+        } else {
+            Err(value)
+        }
+    }
+}
+
+impl std::str::FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim().parse::<f64>().map_err(|e| e.to_string())?;
+        Ok(<Self as std::convert::TryFrom<f64>>::try_from(value).unwrap_or_else(Self::__Unknown__))
+    }
+}
+
+impl std::fmt::Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Level::_15 => f.write_str("1.5"),
+            Level::NaN => f.write_str("NaN"),
+            Level::__Unknown__(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl Default for Level {
+    fn default() -> Level {
+        Self::__Unknown__(Default::default())
+    }
+}
+
+impl Validate for Level {}
+
+
+
+#[derive(PartialEq, Debug, UtilsQNameSerDe)]
+pub enum Flag {
+    On,
+    Off,
+    __Unknown__(xs::QName),
+}
+
+impl Flag {
+    pub const ALL: &[Flag] = &[Flag::On, Flag::Off];
+
+    pub fn value(&self) -> xs::QName {
+        match self {
+            Flag::On => xs::QName::new(Some("tns"), Some("http://example.com"), "On"),
+            Flag::Off => xs::QName::new(Some("tns"), Some("http://example.com"), "Off"),
+            Flag::__Unknown__(value) => value.clone(),
+        }
+    }
+}
+
+impl std::convert::TryFrom<xs::QName> for Flag {
+    type Error = xs::QName;
+
+    fn try_from(value: xs::QName) -> Result<Self, Self::Error> {
+        if value == xs::QName::new(Some("tns"), Some("http://example.com"), "On") {
+            Ok(Flag::On)
+        } else if value == xs::QName::new(Some("tns"), Some("http://example.com"), "Off") {
+            Ok(Flag::Off)
+        } else {
+            Err(value)
+        }
+    }
+}
+
+impl std::str::FromStr for Flag {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = xs::QName::parse(s, &[("tns", "http://example.com")])?;
+        Ok(<Self as std::convert::TryFrom<xs::QName>>::try_from(value).unwrap_or_else(Self::__Unknown__))
+    }
+}
+
+impl std::fmt::Display for Flag {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Flag::On => f.write_str("tns:On"),
+            Flag::Off => f.write_str("tns:Off"),
+            Flag::__Unknown__(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl Default for Flag {
+    fn default() -> Flag {
+        Self::__Unknown__(Default::default())
+    }
+}
+
+impl Validate for Flag {}
+
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+pub struct Task {
+    #[yaserde(prefix = "tns", rename = "Priority")]
+    pub priority: Priority,
+
+    #[yaserde(prefix = "tns", rename = "Rate")]
+    pub rate: Rate,
+}
+
+impl Validate for Task {}
+
+
+// pub type Task = Task;
+
@@ -0,0 +1,86 @@
+use std::convert::TryFrom;
+
+use super::utils;
+
+mod expected {
+    use std::str::FromStr;
+
+    use xsd_macro_utils::*;
+    use xsd_parser::generator::validator::Validate;
+    use xsd_types::types as xs;
+    use yaserde_derive::{YaDeserialize, YaSerialize};
+
+    include!("expected.rs");
+}
+
+#[test]
+fn deserialization_works() {
+    let ser = include_str!("example.xml");
+
+    let de: expected::Task = yaserde::de::from_str(ser).unwrap();
+
+    assert_eq!(de.priority, expected::Priority::_2);
+    assert_eq!(de.rate, expected::Rate::_10);
+}
+
+#[test]
+fn conversions_work() {
+    use expected::Priority;
+
+    assert_eq!(Priority::ALL, &[Priority::_1, Priority::_2, Priority::Minus1]);
+    assert_eq!(Priority::_2.value(), 2);
+    assert_eq!(Priority::try_from(-1), Ok(Priority::Minus1));
+    assert_eq!(Priority::try_from(3), Err(3));
+    assert_eq!("+1".parse::<Priority>(), Ok(Priority::_1));
+    assert_eq!("7".parse::<Priority>(), Ok(Priority::__Unknown__(7)));
+    assert!("High".parse::<Priority>().is_err());
+    assert_eq!(Priority::Minus1.to_string(), "-1");
+}
+
+#[test]
+fn special_bases_work() {
+    use expected::{Flag, Level};
+
+    assert_eq!("NaN".parse::<Level>(), Ok(Level::NaN));
+    assert_eq!(Level::try_from(f64::NAN), Ok(Level::NaN));
+    assert!(Level::NaN.value().is_nan());
+
+    assert_eq!(Flag::ALL, &[Flag::On, Flag::Off]);
+    assert_eq!(" tns:Off ".parse::<Flag>(), Ok(Flag::Off));
+    assert!("ex:Off".parse::<Flag>().is_err());
+    assert_eq!(Flag::On.to_string(), "tns:On");
+}
+
+#[test]
+fn qname_deserialization_resolves_prefix() {
+    use expected::Flag;
+
+    let ser = r#"<ex:Flag xmlns:ex="http://example.com">ex:On</ex:Flag>"#;
+    assert_eq!(yaserde::de::from_str::<Flag>(ser), Ok(Flag::On));
+
+    let ser = r#"<Flag xmlns="http://example.com">Off</Flag>"#;
+    assert_eq!(yaserde::de::from_str::<Flag>(ser), Ok(Flag::Off));
+
+    let ser = r#"<ex:Flag xmlns:ex="http://example.org">ex:On</ex:Flag>"#;
+    assert!(matches!(yaserde::de::from_str::<Flag>(ser), Ok(Flag::__Unknown__(_))));
+}
+
+#[test]
+fn qname_serialization_declares_prefix() {
+    let ser = yaserde::ser::to_string(&expected::Flag::On).unwrap();
+
+    assert_eq!(
+        ser,
+        r#"<?xml version="1.0" encoding="utf-8"?><Flag xmlns:tns="http://example.com">tns:On</Flag>"#
+    );
+}
+
+#[test]
+fn generator_does_not_panic() {
+    println!("{}", utils::generate(include_str!("input.xsd")))
+}
+
+#[test]
+fn generator_output_has_correct_ast() {
+    utils::ast_test(include_str!("input.xsd"), include_str!("expected.rs"));
+}
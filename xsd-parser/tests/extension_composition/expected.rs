@@ -0,0 +1,149 @@
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+pub struct BarType {
+    #[yaserde(prefix = "tns", rename = "b")]
+    pub b: i32,
+
+    #[yaserde(prefix = "tns", rename = "c")]
+    pub c: String,
+}
+
+impl Validate for BarType {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+pub struct FooType {
+    #[yaserde(flatten)]
+    pub base: BarType,
+
+    #[yaserde(prefix = "tns", rename = "a")]
+    pub a: f64,
+}
+
+impl Validate for FooType {}
+
+impl std::ops::Deref for FooType {
+    type Target = BarType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl std::ops::DerefMut for FooType {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.base
+    }
+}
+
+impl AsRef<BarType> for FooType {
+    fn as_ref(&self) -> &BarType {
+        &self.base
+    }
+}
+
+impl From<FooType> for BarType {
+    fn from(value: FooType) -> Self {
+        value.base
+    }
+}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+pub struct BazType {
+    #[yaserde(flatten)]
+    pub base: BarType,
+
+    #[yaserde(prefix = "tns", rename = "c")]
+    pub c: i32,
+}
+
+impl Validate for BazType {}
+
+impl yaserde::YaDeserialize for BazType {
+    fn deserialize<R: std::io::Read>(
+        reader: &mut yaserde::de::Deserializer<R>,
+    ) -> Result<Self, String> {
+        #[derive(YaDeserialize)]
+        #[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+        struct BazTypeExtension {
+            #[yaserde(prefix = "tns", rename = "c")]
+            pub c: i32,
+        }
+
+        let (base, extension) =
+            xsd_types::utils::yaserde::split_extension(reader, &["c"])?;
+        let extension: BazTypeExtension = yaserde::de::from_str(&extension)?;
+        Ok(Self { base: yaserde::de::from_str(&base)?, c: extension.c })
+    }
+}
+
+impl std::ops::Deref for BazType {
+    type Target = BarType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl std::ops::DerefMut for BazType {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.base
+    }
+}
+
+impl AsRef<BarType> for BazType {
+    fn as_ref(&self) -> &BarType {
+        &self.base
+    }
+}
+
+impl From<BazType> for BarType {
+    fn from(value: BazType) -> Self {
+        value.base
+    }
+}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+pub struct Price {
+    #[yaserde(text)]
+    pub base: String,
+
+    #[yaserde(attribute, rename = "currency")]
+    pub currency: Option<String>,
+}
+
+impl Validate for Price {}
+
+impl std::ops::Deref for Price {
+    type Target = String;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl std::ops::DerefMut for Price {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.base
+    }
+}
+
+impl AsRef<String> for Price {
+    fn as_ref(&self) -> &String {
+        &self.base
+    }
+}
+
+impl From<Price> for String {
+    fn from(value: Price) -> Self {
+        value.base
+    }
+}
+
+
+// pub type Foo = FooType;
@@ -0,0 +1,73 @@
+use xsd_parser::generator::builder::GeneratorBuilder;
+
+use super::utils;
+
+mod expected {
+    use xsd_parser::generator::validator::Validate;
+    use yaserde_derive::{YaDeserialize, YaSerialize};
+
+    include!("expected.rs");
+}
+
+#[test]
+fn deserialization_works() {
+    let ser = include_str!("example.xml");
+
+    let de: expected::FooType = yaserde::de::from_str(ser).unwrap();
+
+    assert_eq!(
+        de,
+        expected::FooType { base: expected::BarType { b: 3, c: "string".to_string() }, a: 150.0 }
+    );
+}
+
+#[test]
+fn simple_content_deserialization_works() {
+    let ser = r#"<exam:Price xmlns:exam="http://example.com" currency="EUR">9.99</exam:Price>"#;
+
+    let de: expected::Price = yaserde::de::from_str(ser).unwrap();
+
+    assert_eq!(de, expected::Price { base: "9.99".to_string(), currency: Some("EUR".to_string()) });
+}
+
+#[test]
+fn derived_types_convert_to_base() {
+    // Fields clashing with the ones of the base are kept.
+    let baz = expected::BazType { base: expected::BarType { b: 3, c: "string".to_string() }, c: 7 };
+
+    assert_eq!(baz.b, 3);
+    assert_eq!(baz.c, 7);
+    assert_eq!(AsRef::<expected::BarType>::as_ref(&baz).c, "string");
+    assert_eq!(expected::BarType::from(baz), expected::BarType { b: 3, c: "string".to_string() });
+}
+
+#[test]
+fn clashing_fields_round_trip() {
+    // The base reads the first `c`, the derived type the second one.
+    let baz = expected::BazType { base: expected::BarType { b: 3, c: "string".to_string() }, c: 7 };
+
+    let ser = yaserde::ser::to_string(&baz).unwrap();
+    let de: expected::BazType = yaserde::de::from_str(&ser).unwrap();
+
+    assert_eq!(de, baz);
+}
+
+#[test]
+fn generator_does_not_panic() {
+    println!(
+        "{}",
+        utils::generate_with(
+            include_str!("input.xsd"),
+            GeneratorBuilder::default().with_composed_extensions()
+        )
+    )
+}
+
+#[test]
+fn generator_output_has_correct_ast() {
+    utils::ast_test_with(
+        include_str!("input.xsd"),
+        include_str!("expected.rs"),
+        GeneratorBuilder::default().with_composed_extensions(),
+    );
+}
@@ -0,0 +1,208 @@
+#[derive(Default, PartialEq, Debug, UtilsTupleIo, UtilsDefaultSerde)]
+#[xsd(whitespace = "collapse")]
+pub struct Code (pub String);
+
+impl Validate for Code {
+    fn validate(&self) -> Result<(), String> { 
+        if self.0.len() > 8 {
+            return Err(format!("MaxLength validation error. \nExpected: 0 length <= 8 \nActual: 0 length == {}", self.0.len()));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default, PartialEq, Debug, UtilsTupleIo, UtilsDefaultSerde)]
+#[xsd(whitespace = "collapse")]
+pub struct ShortCode (pub Code);
+
+impl Validate for ShortCode {
+    fn validate(&self) -> Result<(), String> { 
+        #[allow(clippy::len_zero)]
+        if self.0.0.len() < 2 {
+            return Err(format!("MinLength validation error. \nExpected: 0.0 length >= 2 \nActual: 0.0 length == {}", self.0.0.len()));
+        }
+        if self.0.0.len() > 8 {
+            return Err(format!("MaxLength validation error. \nExpected: 0.0 length <= 8 \nActual: 0.0 length == {}", self.0.0.len()));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default, PartialEq, Debug, UtilsTupleIo, UtilsDefaultSerde)]
+#[xsd(whitespace = "collapse")]
+pub struct Level (pub i32);
+
+impl Validate for Level {
+    fn validate(&self) -> Result<(), String> { 
+        if self.0 < "0".parse::<i32>().unwrap() {
+            return Err(format!("MinInclusive validation error: invalid value of 0! \nExpected: 0 >= 0.\nActual: 0 == {}", self.0));
+        }
+        if self.0 > "10".parse::<i32>().unwrap() {
+            return Err(format!("MaxInclusive validation error: invalid value of 0! \nExpected: 0 <= 10.\nActual: 0 == {}", self.0));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default, PartialEq, Debug, UtilsTupleIo, UtilsDefaultSerde)]
+#[xsd(whitespace = "collapse")]
+pub struct LowLevel (pub Level);
+
+impl Validate for LowLevel {
+    fn validate(&self) -> Result<(), String> { 
+        if self.0.0 > "5".parse::<i32>().unwrap() {
+            return Err(format!("MaxInclusive validation error: invalid value of 0.0! \nExpected: 0.0 <= 5.\nActual: 0.0 == {}", self.0.0));
+        }
+        if self.0.0 < "0".parse::<i32>().unwrap() {
+            return Err(format!("MinInclusive validation error: invalid value of 0.0! \nExpected: 0.0 >= 0.\nActual: 0.0 == {}", self.0.0));
+        }
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Debug, UtilsDefaultSerde)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+    __Unknown__(String),
+}
+
+impl Color {
+    pub const ALL: &[Color] = &[Color::Red, Color::Green, Color::Blue];
+
+    pub fn value(&self) -> String {
+        match self {
+            Color::Red => "Red".to_string(),
+            Color::Green => "Green".to_string(),
+            Color::Blue => "Blue".to_string(),
+            Color::__Unknown__(value) => value.clone(),
+        }
+    }
+}
+
+impl std::convert::TryFrom<String> for Color {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value == "Red" {
+            Ok(Color::Red)
+        } else if value == "Green" {
+            Ok(Color::Green)
+        } else if value == "Blue" {
+            Ok(Color::Blue)
+        } else {
+            Err(value)
+        }
+    }
+}
+
+impl std::str::FromStr for Color {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = xs::whitespace::collapse(s).into_owned();
+        Ok(<Self as std::convert::TryFrom<String>>::try_from(value).unwrap_or_else(Self::__Unknown__))
+    }
+}
+
+impl std::fmt::Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Color::Red => f.write_str("Red"),
+            Color::Green => f.write_str("Green"),
+            Color::Blue => f.write_str("Blue"),
+            Color::__Unknown__(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl Default for Color {
+    fn default() -> Color {
+        Self::__Unknown__(Default::default())
+    }
+}
+
+impl Validate for Color {}
+
+
+
+#[derive(PartialEq, Debug, UtilsDefaultSerde)]
+pub enum PrimaryColor {
+    Red,
+    Blue,
+    __Unknown__(String),
+}
+
+impl PrimaryColor {
+    pub const ALL: &[PrimaryColor] = &[PrimaryColor::Red, PrimaryColor::Blue];
+
+    pub fn value(&self) -> String {
+        match self {
+            PrimaryColor::Red => "Red".to_string(),
+            PrimaryColor::Blue => "Blue".to_string(),
+            PrimaryColor::__Unknown__(value) => value.clone(),
+        }
+    }
+}
+
+impl std::convert::TryFrom<String> for PrimaryColor {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value == "Red" {
+            Ok(PrimaryColor::Red)
+        } else if value == "Blue" {
+            Ok(PrimaryColor::Blue)
+        } else {
+            Err(value)
+        }
+    }
+}
+
+impl std::str::FromStr for PrimaryColor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = xs::whitespace::collapse(s).into_owned();
+        Ok(<Self as std::convert::TryFrom<String>>::try_from(value).unwrap_or_else(Self::__Unknown__))
+    }
+}
+
+impl std::fmt::Display for PrimaryColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PrimaryColor::Red => f.write_str("Red"),
+            PrimaryColor::Blue => f.write_str("Blue"),
+            PrimaryColor::__Unknown__(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl Default for PrimaryColor {
+    fn default() -> PrimaryColor {
+        Self::__Unknown__(Default::default())
+    }
+}
+
+impl Validate for PrimaryColor {}
+
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+pub struct Palette {
+    #[yaserde(prefix = "tns", rename = "Code")]
+    pub code: ShortCode,
+
+    #[yaserde(prefix = "tns", rename = "Level")]
+    pub level: LowLevel,
+
+    #[yaserde(prefix = "tns", rename = "Color")]
+    pub color: PrimaryColor,
+}
+
+impl Validate for Palette {}
+
+
+// pub type Palette = Palette;
@@ -0,0 +1,48 @@
+use xsd_parser::generator::validator::Validate;
+
+use super::utils;
+
+mod expected {
+    use std::str::FromStr;
+
+    use xsd_macro_utils::*;
+    use xsd_parser::generator::validator::Validate;
+    use xsd_types::types as xs;
+    use yaserde_derive::{YaDeserialize, YaSerialize};
+
+    include!("expected.rs");
+}
+
+#[test]
+fn deserialization_works() {
+    let ser = include_str!("example.xml");
+
+    let de: expected::Palette = yaserde::de::from_str(ser).unwrap();
+
+    assert_eq!(de.code, expected::ShortCode(expected::Code("AB".to_string())));
+    assert_eq!(de.level, expected::LowLevel(expected::Level(3)));
+    assert_eq!(de.color, expected::PrimaryColor::Blue);
+}
+
+#[test]
+fn inherited_facets_are_validated() {
+    use expected::*;
+
+    assert!(ShortCode(Code("ABCD".to_string())).validate().is_ok());
+    assert!(ShortCode(Code("A".to_string())).validate().is_err());
+    assert!(ShortCode(Code("ABCDEFGHI".to_string())).validate().is_err());
+
+    assert!(LowLevel(Level(5)).validate().is_ok());
+    assert!(LowLevel(Level(6)).validate().is_err());
+    assert!(LowLevel(Level(-1)).validate().is_err());
+}
+
+#[test]
+fn generator_does_not_panic() {
+    println!("{}", utils::generate(include_str!("input.xsd")))
+}
+
+#[test]
+fn generator_output_has_correct_ast() {
+    utils::ast_test(include_str!("input.xsd"), include_str!("expected.rs"));
+}
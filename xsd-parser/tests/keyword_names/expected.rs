@@ -0,0 +1,18 @@
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+pub struct FooType {
+    #[yaserde(prefix = "tns", rename = "type")]
+    pub r#type: String,
+
+    #[yaserde(prefix = "tns", rename = "self")]
+    pub _self: i32,
+
+    #[yaserde(attribute, rename = "ref")]
+    pub r#ref: Option<String>,
+}
+
+impl Validate for FooType {}
+
+
+// pub type Foo = FooType;
+
@@ -0,0 +1,36 @@
+use super::utils;
+
+mod expected {
+    use xsd_parser::generator::validator::Validate;
+    use yaserde_derive::{YaDeserialize, YaSerialize};
+
+    include!("expected.rs");
+}
+
+#[test]
+fn serialization_works() {
+    let foo = expected::FooType { r#type: "b".to_string(), _self: 1, r#ref: Some("a".to_string()) };
+
+    let ser = yaserde::ser::to_string(&foo).unwrap();
+
+    assert_eq!(ser, include_str!("example.xml").trim_end());
+}
+
+#[test]
+fn deserialization_works() {
+    let de: expected::FooType = yaserde::de::from_str(include_str!("example.xml")).unwrap();
+
+    assert_eq!(de.r#type, "b");
+    assert_eq!(de._self, 1);
+    assert_eq!(de.r#ref.as_deref(), Some("a"));
+}
+
+#[test]
+fn generator_does_not_panic() {
+    println!("{}", utils::generate(include_str!("input.xsd")))
+}
+
+#[test]
+fn generator_output_has_correct_ast() {
+    utils::ast_test(include_str!("input.xsd"), include_str!("expected.rs"));
+}
@@ -1,21 +1,33 @@
 #[macro_use]
 mod utils;
+mod abstract_types;
 mod all;
 mod any;
 mod choice;
 mod complex_type;
 mod complex_type_subtypes_clash;
+mod documentation;
 mod enumeration;
+mod enumeration_symbols;
+mod enumeration_typed;
 mod extension_base;
 mod extension_base_multilayer;
 mod extension_base_two_files;
+mod extension_composition;
+mod facet_inheritance;
+mod keyword_names;
+mod reachable_types;
+mod recursive_types;
 mod ref_to_attribute;
 mod rename_only_where_needed;
 mod restriction_any_type;
+mod root_elements;
 mod simple_type;
+mod split_output;
 mod tuple_with_integer;
 mod tuple_with_string;
 mod tuple_with_vec;
+mod tuple_with_whitespace;
 mod type_name_clash;
 mod union;
 mod xsd_string;
@@ -0,0 +1,32 @@
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+pub struct FooType {
+    #[yaserde(prefix = "tns", rename = "Bar")]
+    pub bar: BarType,
+
+    #[yaserde(prefix = "tns", rename = "Ids")]
+    pub ids: IdList,
+}
+
+impl Validate for FooType {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+pub struct BarType {
+    #[yaserde(attribute, rename = "id")]
+    pub id: Option<Id>,
+}
+
+impl Validate for BarType {}
+
+
+#[derive(Default, PartialEq, Debug, UtilsTupleIo, UtilsDefaultSerde)]
+pub struct IdList (pub Vec<Id>);
+
+impl Validate for IdList {}
+#[derive(Default, PartialEq, Debug, UtilsTupleIo, UtilsDefaultSerde)]
+pub struct Id (pub String);
+
+impl Validate for Id {}
+// pub type Foo = FooType;
@@ -0,0 +1,59 @@
+use xsd_parser::{generator::builder::GeneratorBuilder, parser::parse};
+
+use super::utils;
+
+mod expected {
+    use std::str::FromStr;
+
+    use xsd_macro_utils::*;
+    use xsd_parser::generator::validator::Validate;
+    use yaserde_derive::{YaDeserialize, YaSerialize};
+
+    include!("expected.rs");
+}
+
+#[test]
+fn deserialization_works() {
+    let ser = include_str!("example.xml");
+
+    let de: expected::FooType = yaserde::de::from_str(ser).unwrap();
+
+    assert_eq!(
+        de,
+        expected::FooType {
+            bar: expected::BarType { id: Some(expected::Id("a".to_string())) },
+            ids: expected::IdList(vec![
+                expected::Id("a".to_string()),
+                expected::Id("b".to_string())
+            ]),
+        }
+    );
+}
+
+#[test]
+fn unreachable_types_are_reported() {
+    let gen = GeneratorBuilder::default().with_root("tns:Foo").build();
+    gen.generate_rs_file(&parse(include_str!("input.xsd")).unwrap());
+
+    assert_eq!(*gen.pruned_types.borrow(), vec!["UnusedType", "Unused"]);
+}
+
+#[test]
+fn generator_does_not_panic() {
+    println!(
+        "{}",
+        utils::generate_with(
+            include_str!("input.xsd"),
+            GeneratorBuilder::default().with_root("tns:Foo")
+        )
+    )
+}
+
+#[test]
+fn generator_output_has_correct_ast() {
+    utils::ast_test_with(
+        include_str!("input.xsd"),
+        include_str!("expected.rs"),
+        GeneratorBuilder::default().with_root("tns:Foo"),
+    );
+}
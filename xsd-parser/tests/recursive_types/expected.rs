@@ -0,0 +1,61 @@
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize, UtilsBoxSerDe)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+pub struct Node {
+    #[yaserde(prefix = "tns", rename = "Name")]
+    pub name: String,
+
+    #[yaserde(prefix = "tns", rename = "Node")]
+    pub node: Option<Box<Node>>,
+}
+
+impl Validate for Node {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize, UtilsBoxSerDe)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+pub struct Parent {
+    #[yaserde(prefix = "tns", rename = "Child")]
+    pub child: Child,
+}
+
+impl Validate for Parent {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+pub struct Child {
+    #[yaserde(prefix = "tns", rename = "Parent")]
+    pub parent: Option<Box<Parent>>,
+
+    #[yaserde(prefix = "tns", rename = "Children")]
+    pub children: Vec<Child>,
+}
+
+impl Validate for Child {}
+
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize, UtilsBoxSerDe)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+pub struct Tree {
+    #[yaserde(prefix = "tns", rename = "Branch")]
+    pub branch: Option<tree::BranchType>,
+}
+
+impl Validate for Tree {}
+
+pub mod tree {
+    use super::*;
+    
+    #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+    #[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+    pub struct BranchType {
+        #[yaserde(prefix = "tns", rename = "Tree")]
+        pub tree: Option<Box<Tree>>,
+    }
+
+    impl Validate for BranchType {}
+
+}
+
+// pub type Node = Node;
+
@@ -0,0 +1,58 @@
+use super::utils;
+
+mod expected {
+    use xsd_macro_utils::*;
+    use xsd_parser::generator::validator::Validate;
+    use yaserde_derive::{YaDeserialize, YaSerialize};
+
+    include!("expected.rs");
+}
+
+#[test]
+fn serialization_works() {
+    let node = expected::Node {
+        name: "root".to_string(),
+        node: Some(Box::new(expected::Node { name: "leaf".to_string(), node: None })),
+    };
+
+    let ser = yaserde::ser::to_string(&node).unwrap();
+
+    assert_eq!(ser, include_str!("example.xml").trim_end());
+}
+
+#[test]
+fn nested_serialization_works() {
+    let parent = expected::Parent {
+        child: expected::Child {
+            parent: Some(Box::new(expected::Parent::default())),
+            children: vec![],
+        },
+    };
+
+    let ser = yaserde::ser::to_string(&parent).unwrap();
+
+    assert!(ser.contains("<tns:Child><tns:Parent><tns:Child />"), "{}", ser);
+}
+
+#[test]
+fn nested_type_serialization_works() {
+    let tree = expected::Tree {
+        branch: Some(expected::tree::BranchType {
+            tree: Some(Box::new(expected::Tree { branch: None })),
+        }),
+    };
+
+    let ser = yaserde::ser::to_string(&tree).unwrap();
+
+    assert!(ser.contains("<tns:Branch><tns:Tree />"), "{}", ser);
+}
+
+#[test]
+fn generator_does_not_panic() {
+    println!("{}", utils::generate(include_str!("input.xsd")))
+}
+
+#[test]
+fn generator_output_has_correct_ast() {
+    utils::ast_test(include_str!("input.xsd"), include_str!("expected.rs"));
+}
@@ -0,0 +1,85 @@
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+pub struct FooType {
+    #[yaserde(prefix = "tns", rename = "Min")]
+    pub min: i32,
+
+    #[yaserde(prefix = "tns", rename = "Max")]
+    pub max: i32,
+}
+
+impl Validate for FooType {}
+
+
+// pub type Foo = FooType;
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+pub struct Bar {
+    #[yaserde(prefix = "tns", rename = "Name")]
+    pub name: String,
+}
+
+impl Validate for Bar {}
+
+
+// pub type Name = String;
+
+pub mod elements {
+    use super::*;
+
+    const NAMESPACES: &[(&str, &str)] = &[("tns", "http://example.com")];
+
+    pub mod foo {
+        use super::*;
+
+        const ELEMENT: xsd_types::utils::document::RootElement = xsd_types::utils::document::RootElement {
+            name: "Foo",
+            namespace: Some("http://example.com"),
+            prefix: Some("tns"),
+            namespaces: super::NAMESPACES,
+        };
+
+        pub fn from_xml_str(s: &str) -> Result<FooType, xsd_types::utils::document::DocumentError> {
+            ELEMENT.from_xml_str(s)
+        }
+
+        pub fn from_reader<R: std::io::Read>(reader: R) -> Result<FooType, xsd_types::utils::document::DocumentError> {
+            ELEMENT.from_reader(reader)
+        }
+
+        pub fn to_xml_string(value: &FooType) -> Result<String, xsd_types::utils::document::DocumentError> {
+            ELEMENT.to_xml_string(value)
+        }
+
+        pub fn to_writer<W: std::io::Write>(value: &FooType, writer: W) -> Result<(), xsd_types::utils::document::DocumentError> {
+            ELEMENT.to_writer(value, writer)
+        }
+    }
+
+    pub mod bar {
+        use super::*;
+
+        const ELEMENT: xsd_types::utils::document::RootElement = xsd_types::utils::document::RootElement {
+            name: "Bar",
+            namespace: Some("http://example.com"),
+            prefix: Some("tns"),
+            namespaces: super::NAMESPACES,
+        };
+
+        pub fn from_xml_str(s: &str) -> Result<Bar, xsd_types::utils::document::DocumentError> {
+            ELEMENT.from_xml_str(s)
+        }
+
+        pub fn from_reader<R: std::io::Read>(reader: R) -> Result<Bar, xsd_types::utils::document::DocumentError> {
+            ELEMENT.from_reader(reader)
+        }
+
+        pub fn to_xml_string(value: &Bar) -> Result<String, xsd_types::utils::document::DocumentError> {
+            ELEMENT.to_xml_string(value)
+        }
+
+        pub fn to_writer<W: std::io::Write>(value: &Bar, writer: W) -> Result<(), xsd_types::utils::document::DocumentError> {
+            ELEMENT.to_writer(value, writer)
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use xsd_parser::generator::builder::GeneratorBuilder;
+use xsd_types::utils::document::DocumentError;
+
+use super::utils;
+
+mod expected {
+    use xsd_parser::generator::validator::Validate;
+    use yaserde_derive::{YaDeserialize, YaSerialize};
+
+    include!("expected.rs");
+}
+
+#[test]
+fn deserialization_works() {
+    let ser = include_str!("example.xml");
+
+    let de = expected::elements::foo::from_xml_str(ser).unwrap();
+    assert_eq!(de, expected::FooType { min: 1, max: 2 });
+
+    let de = expected::elements::foo::from_reader(ser.as_bytes()).unwrap();
+    assert_eq!(de, expected::FooType { min: 1, max: 2 });
+}
+
+#[test]
+fn serialization_works() {
+    let value = expected::FooType { min: 1, max: 2 };
+
+    let ser = expected::elements::foo::to_xml_string(&value).unwrap();
+    assert_eq!(ser, include_str!("example.xml"));
+
+    let mut buf = vec![];
+    expected::elements::foo::to_writer(&value, &mut buf).unwrap();
+    assert_eq!(String::from_utf8(buf).unwrap(), ser);
+}
+
+#[test]
+fn anonymous_type_round_trip() {
+    let value = expected::Bar { name: "bar".to_string() };
+
+    let ser = expected::elements::bar::to_xml_string(&value).unwrap();
+    assert!(ser.contains(r#"<tns:Bar xmlns:tns="http://example.com">"#));
+    assert_eq!(expected::elements::bar::from_xml_str(&ser).unwrap(), value);
+}
+
+#[test]
+fn unexpected_root_is_rejected() {
+    let ser = include_str!("example.xml").replace("tns:Foo", "tns:Bar");
+
+    match expected::elements::foo::from_xml_str(&ser) {
+        Err(DocumentError::UnexpectedRoot { expected, found }) => {
+            assert_eq!(expected, "{http://example.com}Foo");
+            assert_eq!(found, "{http://example.com}Bar");
+        }
+        res => panic!("unexpected result: {:?}", res),
+    }
+}
+
+#[test]
+fn generator_does_not_panic() {
+    println!(
+        "{}",
+        utils::generate_with(
+            include_str!("input.xsd"),
+            GeneratorBuilder::default().with_root_elements()
+        )
+    )
+}
+
+#[test]
+fn generator_output_has_correct_ast() {
+    utils::ast_test_with(
+        include_str!("input.xsd"),
+        include_str!("expected.rs"),
+        GeneratorBuilder::default().with_root_elements(),
+    );
+}
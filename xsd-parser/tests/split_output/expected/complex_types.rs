@@ -0,0 +1,13 @@
+use super::*;
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+pub struct FooType {
+    #[yaserde(prefix = "tns", rename = "Id")]
+    pub id: Id,
+
+    #[yaserde(prefix = "tns", rename = "Mode")]
+    pub mode: Mode,
+}
+
+impl Validate for FooType {}
@@ -0,0 +1,20 @@
+use super::*;
+
+#[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+
+pub enum Mode {
+    #[yaserde(rename = "ON")]
+    On,
+    #[yaserde(rename = "OFF")]
+    Off,
+    __Unknown__(String),
+}
+
+impl Default for Mode {
+    fn default() -> Mode {
+        Self::__Unknown__("No valid variants".into())
+    }
+}
+
+impl Validate for Mode {}
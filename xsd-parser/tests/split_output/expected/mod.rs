@@ -0,0 +1,10 @@
+use super::*;
+
+mod complex_types;
+pub use complex_types::*;
+mod simple_types;
+pub use simple_types::*;
+mod enums;
+pub use enums::*;
+
+// pub type Foo = FooType;
@@ -0,0 +1,6 @@
+use super::*;
+
+#[derive(Default, PartialEq, Debug, UtilsTupleIo, UtilsDefaultSerde)]
+pub struct Id(pub String);
+
+impl Validate for Id {}
@@ -0,0 +1,69 @@
+use xsd_parser::{
+    generator::{builder::GeneratorBuilder, split::SplitStrategy},
+    parser::parse,
+};
+
+use super::utils;
+
+mod expected {
+    use std::str::FromStr;
+
+    use xsd_macro_utils::*;
+    use xsd_parser::generator::validator::Validate;
+    use yaserde_derive::{YaDeserialize, YaSerialize};
+
+    #[path = "mod.rs"]
+    pub mod tree;
+}
+
+#[test]
+fn deserialization_works() {
+    use expected::tree::*;
+
+    let ser = include_str!("example.xml");
+
+    let de: FooType = yaserde::de::from_str(ser).unwrap();
+
+    assert_eq!(de, FooType { id: Id("abc".to_string()), mode: Mode::Off });
+}
+
+#[test]
+fn generator_output_has_correct_ast() {
+    let gen = GeneratorBuilder::default().build();
+    let tree =
+        gen.generate_rs_tree(&parse(include_str!("input.xsd")).unwrap(), SplitStrategy::TypeGroup);
+
+    let names = tree.modules.iter().map(|m| m.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["complex_types", "simple_types", "enums"]);
+
+    utils::assert_ast_eq(include_str!("expected/mod.rs"), &tree.mod_rs());
+    utils::assert_ast_eq(include_str!("expected/complex_types.rs"), &tree.modules[0].code);
+    utils::assert_ast_eq(include_str!("expected/simple_types.rs"), &tree.modules[1].code);
+    utils::assert_ast_eq(include_str!("expected/enums.rs"), &tree.modules[2].code);
+}
+
+#[test]
+fn chunks_split_types() {
+    let gen = GeneratorBuilder::default().build();
+    let tree =
+        gen.generate_rs_tree(&parse(include_str!("input.xsd")).unwrap(), SplitStrategy::Chunks(2));
+
+    let names = tree.modules.iter().map(|m| m.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["types_1", "types_2"]);
+    assert!(tree.modules[1].code.contains("pub enum Mode"));
+    assert_eq!(tree.root, "// pub type Foo = FooType;\n");
+}
+
+#[test]
+fn single_file_has_the_same_items() {
+    let gen = GeneratorBuilder::default().build();
+    let tree =
+        gen.generate_rs_tree(&parse(include_str!("input.xsd")).unwrap(), SplitStrategy::Namespace);
+
+    assert_eq!(tree.modules.len(), 1);
+    assert_eq!(tree.modules[0].name, "tns");
+    utils::assert_ast_eq(
+        &format!("use super::*;\n{}", utils::generate(include_str!("input.xsd"))),
+        &format!("{}{}", tree.modules[0].code, tree.root),
+    );
+}
@@ -0,0 +1,7 @@
+#[derive(Default, PartialEq, Debug, UtilsTupleIo, UtilsDefaultSerde)]
+#[xsd(whitespace = "collapse")]
+pub struct FooType (pub xs::Integer);
+
+impl Validate for FooType {}
+// pub type Foo = FooType;
+
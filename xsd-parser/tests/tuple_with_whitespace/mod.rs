@@ -0,0 +1,34 @@
+use num_bigint::ToBigInt;
+use xsd_types::types::Integer;
+
+use super::utils;
+
+mod expected {
+    use std::str::FromStr;
+
+    use xsd_macro_utils::*;
+    use xsd_parser::generator::validator::Validate;
+    use xsd_types::types as xs;
+
+    include!("expected.rs");
+}
+
+#[test]
+fn deserialization_works() {
+    // Integers collapse whitespace, line breaks included.
+    let ser = include_str!("example.xml");
+
+    let de: expected::FooType = yaserde::de::from_str(ser).unwrap();
+
+    assert_eq!(de, expected::FooType(Integer::from_bigint(3.to_bigint().unwrap())));
+}
+
+#[test]
+fn generator_does_not_panic() {
+    println!("{}", utils::generate(include_str!("input.xsd")))
+}
+
+#[test]
+fn generator_output_has_correct_ast() {
+    utils::ast_test(include_str!("input.xsd"), include_str!("expected.rs"));
+}
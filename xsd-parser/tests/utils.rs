@@ -1,8 +1,12 @@
 use xsd_parser::{generator::builder::GeneratorBuilder, parser::parse};
 
 pub fn generate(input: &str) -> String {
+    generate_with(input, GeneratorBuilder::default())
+}
+
+pub fn generate_with<'a>(input: &'a str, builder: GeneratorBuilder<'a>) -> String {
     let f = parse(input).unwrap();
-    let gen = GeneratorBuilder::default().build();
+    let gen = builder.build();
     gen.generate_rs_file(&f)
 }
 
@@ -19,8 +23,12 @@ pub fn assert_ast_eq(expected: &str, actual: &str) {
 }
 
 pub fn ast_test(input_xsd: &str, expected_rs: &str) {
+    ast_test_with(input_xsd, expected_rs, GeneratorBuilder::default())
+}
+
+pub fn ast_test_with<'a>(input_xsd: &'a str, expected_rs: &str, builder: GeneratorBuilder<'a>) {
     let expected = expected_rs;
-    let actual = generate(input_xsd);
+    let actual = generate_with(input_xsd, builder);
 
     println!("=== expected:\n{}", expected);
     println!("=== actual:\n{}", actual);
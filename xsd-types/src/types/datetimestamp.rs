@@ -6,7 +6,7 @@ use xsd_macro_utils::UtilsDefaultSerde;
 use crate::types::datetime::DateTime;
 
 // The only difference from DateTime is that the time zone expression is required at the end of the value.
-#[derive(Default, PartialEq, PartialOrd, Debug, UtilsDefaultSerde)]
+#[derive(Clone, Default, PartialEq, PartialOrd, Eq, Hash, Debug, UtilsDefaultSerde)]
 pub struct DateTimeStamp {
     pub value: DateTime,
 }
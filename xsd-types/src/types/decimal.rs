@@ -3,10 +3,15 @@ use std::{fmt, str::FromStr};
 use bigdecimal::{BigDecimal, ParseBigDecimalError};
 use xsd_macro_utils::UtilsDefaultSerde;
 
-#[derive(Default, PartialEq, PartialOrd, Debug, UtilsDefaultSerde)]
+#[derive(Clone, Default, PartialEq, PartialOrd, Eq, Hash, Debug, UtilsDefaultSerde)]
 pub struct Decimal(pub BigDecimal);
 
 impl Decimal {
+    /// The decimal `digits` × 10^-`scale`, e.g. `Decimal::new(5, 1)` for 0.5.
+    pub fn new(digits: i128, scale: i64) -> Self {
+        Decimal(BigDecimal::new(digits.into(), scale))
+    }
+
     pub fn from_bigdecimal(bigdecimal: BigDecimal) -> Self {
         Decimal(bigdecimal)
     }
@@ -77,4 +82,10 @@ mod tests {
         assert_eq!(i.first.to_bigdecimal(), BigDecimal::new(1234.to_bigint().unwrap(), 5));
         assert_eq!(i.second.to_bigdecimal(), BigDecimal::new((-1234).to_bigint().unwrap(), 2));
     }
+
+    #[test]
+    fn decimal_new_test() {
+        assert_eq!(Decimal::new(-1234, 2), "-12.34".parse().unwrap());
+        assert_eq!(Decimal::new(10, 1), "1".parse().unwrap());
+    }
 }
@@ -5,7 +5,7 @@ use xsd_macro_utils::UtilsDefaultSerde;
 
 use crate::types::utils::parse_timezone;
 
-#[derive(PartialEq, Debug, UtilsDefaultSerde)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, UtilsDefaultSerde)]
 pub struct GDay {
     pub value: i32,
     pub timezone: Option<FixedOffset>,
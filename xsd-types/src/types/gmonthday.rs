@@ -5,7 +5,7 @@ use xsd_macro_utils::UtilsDefaultSerde;
 
 use crate::types::{gday::GDay, gmonth::GMonth, utils::parse_timezone};
 
-#[derive(PartialEq, Debug, UtilsDefaultSerde)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, UtilsDefaultSerde)]
 pub struct GMonthDay {
     pub month: i32,
     pub day: i32,
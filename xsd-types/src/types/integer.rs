@@ -4,7 +4,7 @@ use num_bigint::{BigInt, ParseBigIntError, ToBigInt};
 use xsd_macro_utils::UtilsDefaultSerde;
 
 // https://www.w3.org/TR/xmlschema-2/#integer
-#[derive(Default, PartialEq, PartialOrd, Debug, UtilsDefaultSerde)]
+#[derive(Clone, Default, PartialEq, PartialOrd, Eq, Hash, Debug, UtilsDefaultSerde)]
 pub struct Integer(pub BigInt);
 
 impl Integer {
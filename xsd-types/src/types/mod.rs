@@ -28,7 +28,12 @@ pub mod non_positive_integer;
 pub use non_positive_integer::*;
 pub mod positive_integer;
 pub use positive_integer::*;
+pub mod qname;
+pub use qname::*;
 pub mod time;
 pub use time::*;
 pub mod utils;
 pub use utils::*;
+
+// Normalization of string values, for generated code going through the `xs` alias.
+pub use crate::utils::whitespace;
@@ -0,0 +1,112 @@
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+// https://www.w3.org/TR/xmlschema-2/#QName
+// Names are equal when their namespace and local name are, whatever their prefix.
+#[derive(Clone, Default, Debug)]
+pub struct QName {
+    pub prefix: Option<String>,
+    pub namespace: Option<String>,
+    pub local_name: String,
+}
+
+impl QName {
+    pub fn new(prefix: Option<&str>, namespace: Option<&str>, local_name: &str) -> Self {
+        QName {
+            prefix: prefix.map(String::from),
+            namespace: namespace.map(String::from),
+            local_name: local_name.to_string(),
+        }
+    }
+
+    /// Parses `s`, resolving its prefix with `namespaces`, pairs of a prefix and a namespace,
+    /// where the empty prefix stands for the default namespace.
+    pub fn parse(s: &str, namespaces: &[(&str, &str)]) -> Result<Self, String> {
+        QName::parse_with(s, |prefix| {
+            namespaces.iter().find(|(p, _)| *p == prefix).map(|(_, uri)| uri.to_string())
+        })
+    }
+
+    /// Parses `s`, resolving its prefix, the empty one for the default namespace, with `resolve`.
+    pub fn parse_with(s: &str, resolve: impl Fn(&str) -> Option<String>) -> Result<Self, String> {
+        let s = s.trim_matches(['\t', '\n', '\r', ' ']);
+        match s.split_once(':') {
+            Some((prefix, local_name)) => match resolve(prefix) {
+                Some(namespace) => Ok(QName {
+                    prefix: Some(prefix.to_string()),
+                    namespace: Some(namespace),
+                    local_name: local_name.to_string(),
+                }),
+                None => Err(format!("Unbound namespace prefix of QName: {}", s)),
+            },
+            None => Ok(QName { prefix: None, namespace: resolve(""), local_name: s.to_string() }),
+        }
+    }
+}
+
+impl PartialEq for QName {
+    fn eq(&self, other: &Self) -> bool {
+        self.namespace == other.namespace && self.local_name == other.local_name
+    }
+}
+
+impl Eq for QName {}
+
+impl Hash for QName {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.namespace.hash(state);
+        self.local_name.hash(state);
+    }
+}
+
+impl fmt::Display for QName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.prefix {
+            Some(prefix) => write!(f, "{}:{}", prefix, self.local_name),
+            None => f.write_str(&self.local_name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NAMESPACES: &[(&str, &str)] = &[("a", "http://example.com"), ("", "http://default")];
+
+    #[test]
+    fn qname_parse_test() {
+        assert_eq!(
+            QName::parse(" a:On ", NAMESPACES),
+            Ok(QName::new(Some("a"), Some("http://example.com"), "On"))
+        );
+        assert_eq!(
+            QName::parse("On", NAMESPACES),
+            Ok(QName::new(None, Some("http://default"), "On"))
+        );
+        assert_eq!(QName::parse("On", &[]), Ok(QName::new(None, None, "On")));
+        assert!(QName::parse("b:On", NAMESPACES).is_err());
+    }
+
+    #[test]
+    fn qname_eq_test() {
+        // The prefix does not matter.
+        assert_eq!(
+            QName::new(Some("a"), Some("http://example.com"), "On"),
+            QName::new(Some("b"), Some("http://example.com"), "On")
+        );
+        assert_ne!(
+            QName::new(Some("a"), Some("http://example.com"), "On"),
+            QName::new(Some("a"), Some("http://example.org"), "On")
+        );
+        assert_ne!(QName::new(None, None, "On"), QName::new(None, None, "Off"));
+    }
+
+    #[test]
+    fn qname_display_test() {
+        assert_eq!(QName::new(Some("a"), Some("http://example.com"), "On").to_string(), "a:On");
+        assert_eq!(QName::new(None, Some("http://example.com"), "On").to_string(), "On");
+    }
+}
@@ -0,0 +1,233 @@
+//! Reading and writing whole documents whose root is a global element of a schema.
+
+use std::{
+    borrow::Cow,
+    fmt,
+    io::{Read, Write},
+};
+
+use xml::{
+    attribute::OwnedAttribute,
+    name::Name,
+    namespace::{Namespace, NS_NO_PREFIX},
+    reader::{EventReader, XmlEvent},
+    writer,
+};
+use yaserde::{ser, YaDeserialize, YaSerialize};
+
+/// A global element of a schema, the root of the documents it describes.
+#[derive(Debug, Clone, Copy)]
+pub struct RootElement {
+    pub name: &'static str,
+    pub namespace: Option<&'static str>,
+    /// Prefix of `namespace` used for the root when writing.
+    pub prefix: Option<&'static str>,
+    /// Namespaces declared by the schema, declared on the root of written documents.
+    pub namespaces: &'static [(&'static str, &'static str)],
+}
+
+#[derive(Debug)]
+pub enum DocumentError {
+    Io(std::io::Error),
+    Xml(String),
+    /// The root element of a document is not the expected one. Names are given as
+    /// `{namespace}name`.
+    UnexpectedRoot {
+        expected: String,
+        found: String,
+    },
+}
+
+impl fmt::Display for DocumentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DocumentError::Io(e) => write!(f, "{}", e),
+            DocumentError::Xml(e) => write!(f, "{}", e),
+            DocumentError::UnexpectedRoot { expected, found } => {
+                write!(f, "expected root element {}, found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DocumentError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DocumentError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for DocumentError {
+    fn from(e: std::io::Error) -> Self {
+        DocumentError::Io(e)
+    }
+}
+
+impl RootElement {
+    pub fn from_xml_str<T: YaDeserialize>(&self, s: &str) -> Result<T, DocumentError> {
+        self.check_root(s)?;
+        yaserde::de::from_str(s).map_err(DocumentError::Xml)
+    }
+
+    pub fn from_reader<T: YaDeserialize, R: Read>(
+        &self,
+        mut reader: R,
+    ) -> Result<T, DocumentError> {
+        let mut s = String::new();
+        reader.read_to_string(&mut s)?;
+        self.from_xml_str(&s)
+    }
+
+    pub fn to_xml_string<T: YaSerialize>(&self, value: &T) -> Result<String, DocumentError> {
+        let mut serializer = ser::Serializer::new_from_writer(Vec::new(), &ser::Config::default());
+
+        // The root is written here, with the attributes and namespaces of the value, so the
+        // namespaces of the schema are declared along with them.
+        let (attributes, mut namespace) =
+            value.serialize_attributes(vec![], Namespace::empty()).map_err(DocumentError::Xml)?;
+        self.declare_namespaces(&mut namespace);
+        let name = match self.prefix {
+            Some(prefix) => format!("{}:{}", prefix, self.name),
+            None => self.name.to_string(),
+        };
+        let start = writer::XmlEvent::StartElement {
+            name: Name::from(name.as_str()),
+            attributes: Cow::Owned(attributes.iter().map(OwnedAttribute::borrow).collect()),
+            namespace: Cow::Owned(namespace),
+        };
+        serializer.write(start).map_err(|e| DocumentError::Xml(e.to_string()))?;
+
+        serializer.set_skip_start_end(true);
+        value.serialize(&mut serializer).map_err(DocumentError::Xml)?;
+
+        serializer
+            .write(writer::XmlEvent::end_element())
+            .map_err(|e| DocumentError::Xml(e.to_string()))?;
+
+        String::from_utf8(serializer.into_inner()).map_err(|e| DocumentError::Xml(e.to_string()))
+    }
+
+    pub fn to_writer<T: YaSerialize, W: Write>(
+        &self,
+        value: &T,
+        mut writer: W,
+    ) -> Result<(), DocumentError> {
+        writer.write_all(self.to_xml_string(value)?.as_bytes())?;
+        Ok(())
+    }
+
+    fn check_root(&self, s: &str) -> Result<(), DocumentError> {
+        let expected = qualified_name(self.namespace, self.name);
+        for event in EventReader::from_str(s) {
+            match event.map_err(|e| DocumentError::Xml(e.to_string()))? {
+                XmlEvent::StartElement { name, .. } => {
+                    if name.local_name == self.name && name.namespace.as_deref() == self.namespace {
+                        return Ok(());
+                    }
+                    let found = qualified_name(name.namespace.as_deref(), &name.local_name);
+                    return Err(DocumentError::UnexpectedRoot { expected, found });
+                }
+                XmlEvent::EndDocument => break,
+                _ => {}
+            }
+        }
+        Err(DocumentError::UnexpectedRoot { expected, found: "nothing".to_string() })
+    }
+
+    // Adds the namespace of the root and the ones of the schema to `namespace`, keeping the
+    // prefixes it binds already.
+    fn declare_namespaces(&self, namespace: &mut Namespace) {
+        match (self.prefix, self.namespace) {
+            (Some(prefix), Some(uri)) => namespace.put(prefix, uri),
+            (None, Some(uri)) => namespace.put(NS_NO_PREFIX, uri),
+            _ => true,
+        };
+        for (prefix, uri) in self.namespaces {
+            namespace.put(*prefix, *uri);
+        }
+    }
+}
+
+fn qualified_name(namespace: Option<&str>, name: &str) -> String {
+    match namespace {
+        Some(ns) => format!("{{{}}}{}", ns, name),
+        None => name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use yaserde_derive::{YaDeserialize, YaSerialize};
+
+    use super::*;
+
+    #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+    #[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+    pub struct FooType {
+        #[yaserde(prefix = "tns", rename = "Bar")]
+        pub bar: String,
+    }
+
+    #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+    #[yaserde(prefix = "tns", namespace = "tns: http://example.com")]
+    pub struct BazType {
+        #[yaserde(attribute, rename = "id")]
+        pub id: String,
+    }
+
+    const FOO: RootElement = RootElement {
+        name: "Foo",
+        namespace: Some("http://example.com"),
+        prefix: Some("tns"),
+        namespaces: &[("tns", "http://example.com"), ("other", "http://example.com/other")],
+    };
+
+    #[test]
+    fn from_xml_str_test() {
+        let foo: FooType = FOO
+            .from_xml_str(r#"<t:Foo xmlns:t="http://example.com"><t:Bar>bar</t:Bar></t:Foo>"#)
+            .unwrap();
+        assert_eq!(foo, FooType { bar: "bar".to_string() });
+
+        let foo = FOO.from_reader::<FooType, _>(
+            r#"<t:Foo xmlns:t="http://example.com"><t:Bar>bar</t:Bar></t:Foo>"#.as_bytes(),
+        );
+        assert!(foo.is_ok());
+    }
+
+    #[test]
+    fn unexpected_root_test() {
+        match FOO.from_xml_str::<FooType>(r#"<Foo><Bar>bar</Bar></Foo>"#) {
+            Err(DocumentError::UnexpectedRoot { expected, found }) => {
+                assert_eq!(expected, "{http://example.com}Foo");
+                assert_eq!(found, "Foo");
+            }
+            res => panic!("unexpected result: {:?}", res),
+        }
+    }
+
+    #[test]
+    fn to_xml_string_test() {
+        let xml = FOO.to_xml_string(&FooType { bar: "bar".to_string() }).unwrap();
+        assert_eq!(
+            xml,
+            r#"<?xml version="1.0" encoding="utf-8"?><tns:Foo xmlns:other="http://example.com/other" xmlns:tns="http://example.com"><tns:Bar>bar</tns:Bar></tns:Foo>"#
+        );
+
+        let mut buf = vec![];
+        FOO.to_writer(&FooType { bar: "bar".to_string() }, &mut buf).unwrap();
+        assert_eq!(String::from_utf8(buf).unwrap(), xml);
+    }
+
+    #[test]
+    fn to_xml_string_attributes_test() {
+        let xml = FOO.to_xml_string(&BazType { id: "1".to_string() }).unwrap();
+        assert_eq!(
+            xml,
+            r#"<?xml version="1.0" encoding="utf-8"?><tns:Foo xmlns:other="http://example.com/other" xmlns:tns="http://example.com" id="1" />"#
+        );
+        assert_eq!(FOO.from_xml_str::<BazType>(&xml).unwrap(), BazType { id: "1".to_string() });
+    }
+}
@@ -1,3 +1,5 @@
+pub mod document;
+pub mod whitespace;
 #[cfg(test)]
 pub mod xml_eq;
 pub mod yaserde;
@@ -0,0 +1,42 @@
+//! Whitespace normalization of the `whiteSpace` facet.
+
+use std::borrow::Cow;
+
+/// Replaces tabs, line feeds and carriage returns with spaces.
+pub fn replace(s: &str) -> Cow<'_, str> {
+    if s.contains(['\t', '\n', '\r']) {
+        s.replace(['\t', '\n', '\r'], " ").into()
+    } else {
+        s.into()
+    }
+}
+
+/// Replaces sequences of whitespace with a single space and trims leading and trailing ones.
+pub fn collapse(s: &str) -> Cow<'_, str> {
+    let words = s.split(['\t', '\n', '\r', ' ']).filter(|w| !w.is_empty()).collect::<Vec<&str>>();
+    match words.as_slice() {
+        [] => "".into(),
+        [word] => (*word).into(),
+        _ => words.join(" ").into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replace_test() {
+        assert_eq!(replace("a\tb\r\nc "), "a b  c ");
+        assert_eq!(replace("a b"), "a b");
+    }
+
+    #[test]
+    fn collapse_test() {
+        assert_eq!(collapse("  a \t b\n\nc "), "a b c");
+        assert_eq!(collapse(" a "), "a");
+        assert_eq!(collapse(" \n "), "");
+        // Form feeds are not whitespace in XML.
+        assert_eq!(collapse("a\u{c}b \u{c}"), "a\u{c}b \u{c}");
+    }
+}
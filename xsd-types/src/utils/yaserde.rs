@@ -1,7 +1,12 @@
-use std::io::{Read, Write};
+use std::{
+    collections::HashSet,
+    io::{Read, Write},
+};
 
 use yaserde::{de, ser};
 
+use crate::types::QName;
+
 pub fn serialize<S, W: Write>(
     self_bypass: &S,
     default_name: &str,
@@ -45,3 +50,104 @@ pub fn deserialize<S, R: Read>(
         de_fn("")
     }
 }
+
+/// Serializes `name`, declaring the namespace of its prefix on the element.
+pub fn serialize_qname<W: Write>(
+    name: &QName,
+    default_name: &str,
+    writer: &mut ser::Serializer<W>,
+) -> Result<(), String> {
+    let element = writer.get_start_event_name().unwrap_or_else(|| default_name.to_string());
+
+    if !writer.skip_start_end() {
+        let start = xml::writer::XmlEvent::start_element(element.as_str());
+        let start = match (&name.prefix, &name.namespace) {
+            (Some(prefix), Some(namespace)) => start.ns(prefix.as_str(), namespace.as_str()),
+            _ => start,
+        };
+        writer.write(start).map_err(|_e| "Start element write failed".to_string())?;
+    }
+
+    writer
+        .write(xml::writer::XmlEvent::characters(name.to_string().as_str()))
+        .map_err(|_e| "Element value write failed".to_string())?;
+
+    if !writer.skip_start_end() {
+        writer
+            .write(xml::writer::XmlEvent::end_element())
+            .map_err(|_e| "End element write failed".to_string())?;
+    }
+
+    Ok(())
+}
+
+/// Deserializes a QName, resolving its prefix with the namespaces in scope of the element.
+pub fn deserialize_qname<S, R: Read>(
+    reader: &mut de::Deserializer<R>,
+    de_fn: impl FnOnce(QName) -> Result<S, String>,
+) -> Result<S, String> {
+    let namespace = match reader.peek() {
+        Ok(xml::reader::XmlEvent::StartElement { namespace, .. }) => namespace.clone(),
+        _ => return Err("Start element not found".to_string()),
+    };
+    reader.next_event()?;
+
+    let text = match reader.peek() {
+        Ok(xml::reader::XmlEvent::Characters(text)) => text.clone(),
+        _ => String::new(),
+    };
+    // Elements out of any default namespace bind the empty prefix to the empty URI.
+    let resolve =
+        |prefix: &str| namespace.get(prefix).filter(|uri| !uri.is_empty()).map(String::from);
+    de_fn(QName::parse_with(&text, resolve)?)
+}
+
+/// Reads the element at `reader` as two documents, for a type extending a base which declares
+/// the elements named `shadowed` too: the first one, for the base, has the first child element
+/// of each of these names and not the next ones, the second one, for the extension, has all
+/// children but them.
+pub fn split_extension<R: Read>(
+    reader: &mut de::Deserializer<R>,
+    shadowed: &[&str],
+) -> Result<(String, String), String> {
+    let mut base = xml::writer::EventWriter::new(vec![]);
+    let mut extension = xml::writer::EventWriter::new(vec![]);
+    let mut read = HashSet::new();
+    // Whether the events of the current child go to the base and to the extension.
+    let mut targets = (true, true);
+    let mut depth = 0;
+    loop {
+        let event = reader.next_event()?;
+        match &event {
+            xml::reader::XmlEvent::StartElement { name, .. } => {
+                if depth == 1 && shadowed.contains(&name.local_name.as_str()) {
+                    let first = read.insert(name.local_name.clone());
+                    targets = (first, !first);
+                }
+                depth += 1;
+            }
+            xml::reader::XmlEvent::EndElement { .. } => depth -= 1,
+            _ => {}
+        }
+
+        if let Some(event) = event.as_writer_event() {
+            if targets.0 {
+                base.write(event.clone()).map_err(|e| e.to_string())?;
+            }
+            if targets.1 {
+                extension.write(event).map_err(|e| e.to_string())?;
+            }
+        }
+
+        match depth {
+            0 => break,
+            1 => targets = (true, true),
+            _ => {}
+        }
+    }
+
+    let text = |writer: xml::writer::EventWriter<Vec<u8>>| {
+        String::from_utf8(writer.into_inner()).map_err(|e| e.to_string())
+    };
+    Ok((text(base)?, text(extension)?))
+}